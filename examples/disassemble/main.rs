@@ -0,0 +1,13 @@
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::Read;
+use spirq::SpirvBinary;
+
+fn main() {
+    let mut buf = Vec::new();
+    File::open("assets/effects/spirv-spec/referential.frag.spv")
+        .and_then(|mut x| x.read_to_end(&mut buf))
+        .unwrap();
+    let spv = SpirvBinary::try_from(buf).unwrap();
+    print!("{}", spv.disassemble().unwrap());
+}
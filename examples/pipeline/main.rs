@@ -24,7 +24,7 @@ impl TryFrom<&[EntryPoint]> for Pipeline {
                 panic!("pipeline cannot have two stages of the same execution model");
             }
         }
-        return Ok(Pipeline { manifest: manifest });
+        Ok(Pipeline { manifest })
     }
 }
 impl Deref for Pipeline {
@@ -83,7 +83,13 @@ fn collect_spirv_binaries<P: AsRef<Path>>(path: P) -> HashMap<String, SpirvBinar
                 buf.len() & 3 != 0 {
                 return None;
             }
-            let spv = buf.into();
+            let spv = match SpirvBinary::try_from(buf) {
+                Ok(spv) => spv,
+                Err(err) => {
+                    warn!("cannot load spirv binary {}: {}", x.display(), err);
+                    return None;
+                },
+            };
             let name = x.file_stem()
                 .and_then(OsStr::to_str)
                 .map(ToOwned::to_owned)
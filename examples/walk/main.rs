@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use spirq::SpirvBinary;
 use log::info;
 use std::path::Path;
@@ -50,7 +51,13 @@ fn collect_spirv_binaries<P: AsRef<Path>>(path: P) -> HashMap<String, SpirvBinar
                 buf.len() & 3 != 0 {
                 return None;
             }
-            let spv = buf.into();
+            let spv = match SpirvBinary::try_from(buf) {
+                Ok(spv) => spv,
+                Err(err) => {
+                    warn!("cannot load spirv binary {}: {}", x.display(), err);
+                    return None;
+                },
+            };
             let name = x.file_stem()
                 .and_then(OsStr::to_str)
                 .map(ToOwned::to_owned)
@@ -0,0 +1,472 @@
+//! Assembly of `spirv-as`-style SPIR-V text into binaries.
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use crate::{SpirvBinary, SpirvHeader};
+use crate::consts::*;
+use crate::grammar::*;
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Str(String),
+}
+
+/// Numeric types needed to encode context-dependent literals.
+#[derive(Clone, Copy)]
+enum NumType {
+    Int(u32, bool),
+    Float(u32),
+}
+
+fn tokenize(line: &str) -> std::result::Result<Vec<Token<'_>>, &'static str> {
+    let mut tokens = Vec::new();
+    let mut rem = line;
+    loop {
+        rem = rem.trim_start();
+        if rem.is_empty() || rem.starts_with(';') { break; }
+        if let Some(quoted) = rem.strip_prefix('"') {
+            let mut string = String::new();
+            let mut chars = quoted.char_indices();
+            let end = loop {
+                match chars.next() {
+                    Some((_, '\\')) => {
+                        let (_, c) = chars.next().ok_or("unterminated string")?;
+                        string.push(c);
+                    },
+                    Some((i, '"')) => break i + 1,
+                    Some((_, c)) => string.push(c),
+                    None => return Err("unterminated string"),
+                }
+            };
+            tokens.push(Token::Str(string));
+            rem = &quoted[end..];
+        } else {
+            let end = rem.find(char::is_whitespace).unwrap_or(rem.len());
+            tokens.push(Token::Word(&rem[..end]));
+            rem = &rem[end..];
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_int(literal: &str) -> Option<u64> {
+    let (neg, literal) = match literal.strip_prefix('-') {
+        Some(x) => (true, x),
+        None => (false, literal),
+    };
+    let x = if let Some(hex) = literal.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16).ok()?
+    } else {
+        u64::from_str(literal).ok()?
+    };
+    if neg {
+        if x > 1 << 63 { return None; }
+        Some(x.wrapping_neg())
+    } else {
+        Some(x)
+    }
+}
+/// Parse an integer literal of an integer type of the width and signedness.
+/// Hexadecimal literals are the raw bits of the value. The value is
+/// sign-extended if the type is signed.
+fn parse_int_of(literal: &str, nbit: u32, is_signed: bool) -> std::result::Result<i128, &'static str> {
+    if nbit == 0 || nbit > 64 { return Err("unsupported integer width"); }
+    let x = parse_int(literal).ok_or("invalid integer literal")?;
+    let x = if literal.starts_with('-') { i128::from(x as i64) } else { i128::from(x) };
+    let (min, max) = if is_signed && !literal.starts_with("0x") {
+        (-(1i128 << (nbit - 1)), (1i128 << (nbit - 1)) - 1)
+    } else {
+        (0, (1i128 << nbit) - 1)
+    };
+    if x < min || x > max { return Err("integer literal out of range"); }
+    if is_signed {
+        // Sign-extend the raw bits.
+        let shift = 128 - nbit;
+        Ok((x << shift) >> shift)
+    } else {
+        Ok(x)
+    }
+}
+/// Parse an integer literal that fits in a single word, either as an unsigned
+/// or a signed integer.
+fn parse_word(literal: &str) -> Option<u32> {
+    let x = parse_int(literal)?;
+    let fits = if literal.starts_with('-') {
+        x as i64 >= i64::from(i32::MIN)
+    } else {
+        x <= u64::from(u32::MAX)
+    };
+    if fits { Some(x as u32) } else { None }
+}
+
+struct Assembler<'a> {
+    tokens: std::vec::IntoIter<Token<'a>>,
+    id_map: HashMap<&'a str, u32>,
+    reserved_ids: HashSet<u32>,
+    next_id: u32,
+    num_tys: HashMap<u32, NumType>,
+}
+impl<'a> Assembler<'a> {
+    fn next_word(&mut self) -> std::result::Result<&'a str, &'static str> {
+        match self.tokens.next() {
+            Some(Token::Word(word)) => Ok(word),
+            Some(Token::Str(_)) => Err("unexpected string literal"),
+            None => Err("missing operand"),
+        }
+    }
+    fn is_empty(&self) -> bool { self.tokens.as_slice().is_empty() }
+    /// Whether the next token can be an operand of the kind. Optional
+    /// operands are skipped if it can't, e.g. the optional id of the source
+    /// file in `OpSource GLSL 450 "..."`.
+    fn is_next_of_kind(&self, kind: OperandKind) -> bool {
+        use OperandKind::*;
+        match self.tokens.as_slice().first() {
+            Some(Token::Str(_)) => kind == LiteralString,
+            Some(Token::Word(word)) => match kind {
+                LiteralString => false,
+                IdResultType | IdResult | IdRef | IdScope | IdMemorySemantics |
+                PairIdRefLiteralInteger | PairIdRefIdRef => word.starts_with('%'),
+                _ => !word.starts_with('%'),
+            },
+            None => false,
+        }
+    }
+    fn id(&mut self, literal: &'a str) -> std::result::Result<u32, &'static str> {
+        let name = literal.strip_prefix('%').ok_or("expected an id")?;
+        if name.is_empty() { return Err("expected an id"); }
+        if let Ok(id) = u32::from_str(name) {
+            if id == 0 { return Err("id 0 is invalid"); }
+            return Ok(id);
+        }
+        if let Some(&id) = self.id_map.get(name) { return Ok(id); }
+        // Named ids take the smallest ids not used numerically.
+        while self.reserved_ids.contains(&self.next_id) { self.next_id += 1; }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.id_map.insert(name, id);
+        Ok(id)
+    }
+    fn encode_str(string: &str, out: &mut Vec<u32>) {
+        // Strings are nul-terminated and padded to whole words.
+        let mut bytes = string.as_bytes().to_owned();
+        bytes.resize((bytes.len() / 4 + 1) * 4, 0);
+        out.extend(bytes.chunks_exact(4).map(|x| u32::from_le_bytes([x[0], x[1], x[2], x[3]])));
+    }
+    fn encode_num(&self, ty_id: Option<u32>, literal: &str, out: &mut Vec<u32>) -> std::result::Result<(), &'static str> {
+        let num_ty = ty_id.and_then(|x| self.num_tys.get(&x)).copied();
+        let is_hex = literal.starts_with("0x");
+        match num_ty {
+            Some(NumType::Int(nbit, is_signed)) => {
+                let x = parse_int_of(literal, nbit, is_signed)?;
+                out.push(x as u32);
+                if nbit > 32 { out.push((x >> 32) as u32); }
+            },
+            // Hexadecimal literals of floats are the raw bits.
+            Some(NumType::Float(16)) if is_hex => {
+                out.push(parse_int_of(literal, 16, false)? as u32);
+            },
+            Some(NumType::Float(16)) => {
+                let x = f32::from_str(literal).map_err(|_| "invalid float literal")?;
+                out.push(u32::from(crate::constant::f32_to_f16(x)));
+            },
+            Some(NumType::Float(32)) if is_hex => {
+                out.push(parse_int_of(literal, 32, false)? as u32);
+            },
+            Some(NumType::Float(32)) => {
+                out.push(f32::from_str(literal).map_err(|_| "invalid float literal")?.to_bits());
+            },
+            Some(NumType::Float(64)) => {
+                let bits = if is_hex {
+                    parse_int_of(literal, 64, false)? as u64
+                } else {
+                    f64::from_str(literal).map_err(|_| "invalid float literal")?.to_bits()
+                };
+                out.push(bits as u32);
+                out.push((bits >> 32) as u32);
+            },
+            Some(NumType::Float(_)) => return Err("unsupported float width"),
+            None => out.push(parse_word(literal).ok_or("invalid integer literal")?),
+        }
+        Ok(())
+    }
+    fn encode_enum(&mut self, kind: OperandKind, literal: &'a str, out: &mut Vec<u32>) -> std::result::Result<(), &'static str> {
+        let enumerants = enumerants(kind).unwrap();
+        let mut value = 0;
+        let mut params = Vec::new();
+        let names = if is_bit_enum(kind) { literal.split('|').collect() } else { vec![literal] };
+        for name in names {
+            if let Some(x) = parse_word(name) {
+                value |= x;
+            } else {
+                let e = enumerants.iter()
+                    .find(|e| e.name == name)
+                    .ok_or("unknown enumerant")?;
+                value |= e.value;
+                params.push(e);
+            }
+        }
+        out.push(value);
+        // Parameters follow in the order of enumerant values.
+        params.sort_by_key(|e| e.value);
+        for e in params {
+            self.encode_operands(e.params, None, out)?;
+        }
+        Ok(())
+    }
+    fn encode_operand(&mut self, kind: OperandKind, ty_id: Option<u32>, out: &mut Vec<u32>) -> std::result::Result<(), &'static str> {
+        use OperandKind::*;
+        match kind {
+            IdResultType | IdResult | IdRef | IdScope | IdMemorySemantics => {
+                let word = self.next_word()?;
+                out.push(self.id(word)?);
+            },
+            LiteralInteger | LiteralExtInstInteger => {
+                let word = self.next_word()?;
+                out.push(parse_word(word).ok_or("invalid integer literal")?);
+            },
+            LiteralString => match self.tokens.next() {
+                Some(Token::Str(string)) => Self::encode_str(&string, out),
+                _ => return Err("expected a string literal"),
+            },
+            LiteralContextDependentNumber => {
+                let word = self.next_word()?;
+                self.encode_num(ty_id, word, out)?;
+            },
+            LiteralSpecConstantOpInteger => {
+                let word = self.next_word()?;
+                let grammar = instr_by_name(&format!("Op{}", word))
+                    .ok_or("unknown opcode")?;
+                out.push(grammar.opcode);
+                let params = grammar.operands.iter()
+                    .skip_while(|x| x.0 == IdResultType || x.0 == IdResult)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                self.encode_operands(&params, ty_id, out)?;
+            },
+            PairLiteralIntegerIdRef => {
+                self.encode_operand(LiteralInteger, None, out)?;
+                self.encode_operand(IdRef, None, out)?;
+            },
+            PairIdRefLiteralInteger => {
+                self.encode_operand(IdRef, None, out)?;
+                self.encode_operand(LiteralInteger, None, out)?;
+            },
+            PairIdRefIdRef => {
+                self.encode_operand(IdRef, None, out)?;
+                self.encode_operand(IdRef, None, out)?;
+            },
+            _ => {
+                let word = self.next_word()?;
+                self.encode_enum(kind, word, out)?;
+            },
+        }
+        Ok(())
+    }
+    fn encode_operands(&mut self, grammar: &[OperandGrammar], ty_id: Option<u32>, out: &mut Vec<u32>) -> std::result::Result<(), &'static str> {
+        for &(kind, quant) in grammar {
+            match quant {
+                Quantifier::One => self.encode_operand(kind, ty_id, out)?,
+                Quantifier::Optional => if self.is_next_of_kind(kind) {
+                    self.encode_operand(kind, ty_id, out)?;
+                },
+                Quantifier::Variadic => while !self.is_empty() {
+                    self.encode_operand(kind, ty_id, out)?;
+                },
+            }
+        }
+        Ok(())
+    }
+    fn encode_instr(&mut self, tokens: Vec<Token<'a>>, out: &mut Vec<u32>) -> std::result::Result<(), &'static str> {
+        self.tokens = tokens.into_iter();
+        let mut result_id = None;
+        let mut word = self.next_word()?;
+        if word.starts_with('%') {
+            result_id = Some(self.id(word)?);
+            if self.next_word()? != "=" { return Err("expected `=` after result id"); }
+            word = self.next_word()?;
+        }
+        let grammar = instr_by_name(word).ok_or("unknown opcode")?;
+        let begin = out.len();
+        out.push(0);
+        let mut ty_id = None;
+        for &(kind, quant) in grammar.operands {
+            match kind {
+                OperandKind::IdResult => {
+                    out.push(result_id.ok_or("missing result id")?);
+                    result_id = None;
+                },
+                OperandKind::IdResultType => {
+                    let word = self.next_word()?;
+                    let id = self.id(word)?;
+                    ty_id = Some(id);
+                    out.push(id);
+                },
+                _ => self.encode_operands(&[(kind, quant)], ty_id, out)?,
+            }
+        }
+        if result_id.is_some() { return Err("instruction doesn't have a result"); }
+        if !self.is_empty() { return Err("too many operands"); }
+        match grammar.opcode {
+            OP_TYPE_INT => {
+                let is_signed = out[begin + 3] != 0;
+                self.num_tys.insert(out[begin + 1], NumType::Int(out[begin + 2], is_signed));
+            },
+            OP_TYPE_FLOAT => {
+                self.num_tys.insert(out[begin + 1], NumType::Float(out[begin + 2]));
+            },
+            _ => {},
+        }
+        let nword = out.len() - begin;
+        if nword > 0xFFFF { return Err("instruction is too long"); }
+        out[begin] = (nword as u32) << 16 | grammar.opcode;
+        Ok(())
+    }
+}
+
+fn parse_version(line: &str) -> Option<(u32, u32)> {
+    let version = line.trim().strip_prefix(';')?.trim().strip_prefix("Version:")?;
+    let mut segs = version.trim().splitn(2, '.');
+    let major = u32::from_str(segs.next()?).ok()?;
+    let minor = u32::from_str(segs.next()?).ok()?;
+    Some((major, minor))
+}
+
+pub(crate) fn assemble(src: &str) -> Result<SpirvBinary> {
+    let mut lines = Vec::new();
+    let mut reserved_ids = HashSet::new();
+    // SPIR-V 1.0 is assumed unless a `; Version: x.y` comment says otherwise,
+    // as `spirv-dis` prints.
+    let mut version = (1, 0);
+    for (i, line) in src.lines().enumerate() {
+        if let Some(x) = parse_version(line) { version = x; }
+        let tokens = tokenize(line)
+            .map_err(|msg| Error::InvalidAssembly(i + 1, msg.to_owned()))?;
+        for token in tokens.iter() {
+            if let Token::Word(word) = token {
+                if let Some(Ok(id)) = word.strip_prefix('%').map(u32::from_str) {
+                    reserved_ids.insert(id);
+                }
+            }
+        }
+        if !tokens.is_empty() { lines.push((i + 1, tokens)); }
+    }
+    let mut asm = Assembler {
+        tokens: Vec::new().into_iter(),
+        id_map: HashMap::new(),
+        reserved_ids,
+        next_id: 1,
+        num_tys: HashMap::new(),
+    };
+    let mut words = vec![0; SpirvHeader::WORD_COUNT];
+    for (line, tokens) in lines {
+        asm.encode_instr(tokens, &mut words)
+            .map_err(|msg| Error::InvalidAssembly(line, msg.to_owned()))?;
+    }
+    let bound = asm.reserved_ids.iter()
+        .chain(asm.id_map.values())
+        .max()
+        .map_or(1, |x| x + 1);
+    let header = SpirvHeader {
+        magic: spirv_headers::MAGIC_NUMBER,
+        major_version: version.0 as u8,
+        minor_version: version.1 as u8,
+        generator_id: 0,
+        generator_version: 0,
+        bound,
+        schema: 0,
+    };
+    words[..SpirvHeader::WORD_COUNT].copy_from_slice(&header.to_words());
+    Ok(words.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SpirvBinary, Error};
+    use spirv_headers::Op;
+
+    const HEAD: &str = r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+"#;
+    const MAIN: &str = r#"
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+"#;
+    const MAIN_END: &str = r#"
+               OpReturn
+               OpFunctionEnd
+"#;
+
+    /// Assemble a compute shader with the declarations and the statements of
+    /// its entry point function.
+    fn assemble(decls: &str, stmts: &str) -> crate::Result<SpirvBinary> {
+        SpirvBinary::assemble(&format!("{}{}{}{}{}", HEAD, decls, MAIN, stmts, MAIN_END))
+    }
+
+    #[test]
+    fn test_source_without_file() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpSource GLSL 450 "void main() {}"
+"#).unwrap();
+        let instr = spv.instrs().nth(2).unwrap();
+        // Opcode, language, version and the 4-word string.
+        assert_eq!(instr.word_count(), 7);
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+       %file = OpString "a.glsl"
+               OpSource GLSL 450 %file "void main() {}"
+"#).unwrap();
+        let instr = spv.instrs().nth(3).unwrap();
+        assert_eq!(instr.word_count(), 8);
+    }
+
+    #[test]
+    fn test_invalid_literals() {
+        let err = assemble("OpDecorate %x Location 4294967296\n%x = OpUndef %x", "").unwrap_err();
+        assert!(matches!(err, Error::InvalidAssembly(6, _)), "{:?}", err);
+        let err = assemble("%int = OpTypeInt 32 1\n%x = OpConstant %int -2147483649", "").unwrap_err();
+        assert!(matches!(err, Error::InvalidAssembly(7, _)), "{:?}", err);
+        let err = assemble("%int = OpTypeInt 64 1\n%x = OpConstant %int -9223372036854775809", "").unwrap_err();
+        assert!(matches!(err, Error::InvalidAssembly(7, _)), "{:?}", err);
+        let out_of_range = [
+            "%u8 = OpTypeInt 8 0\n%x = OpConstant %u8 300",
+            "%i8 = OpTypeInt 8 1\n%x = OpConstant %i8 200",
+            "%i8 = OpTypeInt 8 1\n%x = OpConstant %i8 -129",
+            "%int = OpTypeInt 32 1\n%x = OpConstant %int 4294967295",
+            "%uint = OpTypeInt 32 0\n%x = OpConstant %uint -1",
+            "%half = OpTypeFloat 16\n%x = OpConstant %half 0x10000",
+        ];
+        for text in out_of_range.iter() {
+            let err = assemble(text, "").unwrap_err();
+            assert!(matches!(&err, Error::InvalidAssembly(7, x) if x == "integer literal out of range"), "{:?}", err);
+        }
+        // Narrow signed integers are sign-extended, and hexadecimal literals
+        // are the raw bits.
+        let const_values = |spv: SpirvBinary| {
+            spv.instrs()
+                .filter(|x| x.op() == Some(Op::Constant))
+                .map(|x| x.operand_words()[2])
+                .collect::<Vec<_>>()
+        };
+        let spv = assemble("%i8 = OpTypeInt 8 1\n%x = OpConstant %i8 -56\n%y = OpConstant %i8 0xc8", "").unwrap();
+        assert_eq!(const_values(spv), vec![0xffff_ffc8, 0xffff_ffc8]);
+        let spv = assemble("%half = OpTypeFloat 16\n%x = OpConstant %half 1.5\n%y = OpConstant %half 0x3e00", "").unwrap();
+        assert_eq!(const_values(spv), vec![0x3e00, 0x3e00]);
+        assemble("%int = OpTypeInt 32 1\n%x = OpConstant %int -2147483648", "").unwrap();
+        assemble("%int = OpTypeInt 32 0\n%x = OpConstant %int 4294967295", "").unwrap();
+    }
+
+    #[test]
+    fn test_zero_id() {
+        let err = assemble("%0 = OpTypeFloat 32", "").unwrap_err();
+        assert!(matches!(err, Error::InvalidAssembly(6, _)), "{:?}", err);
+    }
+}
@@ -0,0 +1,209 @@
+//! Constant values.
+use std::convert::TryInto;
+use spirv_headers::{SamplerAddressingMode, SamplerFilterMode};
+use crate::ty::{ScalarType, Type, VectorType};
+
+/// Value of a constant or a specialization constant.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConstantValue {
+    Bool(bool),
+    I32(i32),
+    U32(u32),
+    F32(f32),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    /// Value of a vector, matrix, array or struct constant, made of the values
+    /// of its constituents.
+    Composite(Vec<ConstantValue>),
+    /// Sampler declared by `OpConstantSampler`.
+    Sampler {
+        addr_mode: SamplerAddressingMode,
+        normalized: bool,
+        filter_mode: SamplerFilterMode,
+    },
+    /// Null value of a type that has no other representation, like a pointer.
+    /// Null values of numeric and composite types are represented as zeros.
+    Null,
+}
+impl ConstantValue {
+    /// Make a scalar value of the type from raw bits. Integers narrower than
+    /// 32 bits are widened to 32 bits; 16-bit floats are widened to `f32`.
+    pub(crate) fn from_bits(scalar_ty: &ScalarType, bits: u64) -> Option<ConstantValue> {
+        let value = match *scalar_ty {
+            ScalarType::Boolean => ConstantValue::Bool(bits != 0),
+            ScalarType::Signed(nbyte @ 1) | ScalarType::Signed(nbyte @ 2) |
+            ScalarType::Signed(nbyte @ 4) => {
+                let nshift = 64 - nbyte * 8;
+                ConstantValue::I32((((bits << nshift) as i64) >> nshift) as i32)
+            },
+            ScalarType::Signed(8) => ConstantValue::I64(bits as i64),
+            ScalarType::Unsigned(nbyte @ 1) | ScalarType::Unsigned(nbyte @ 2) |
+            ScalarType::Unsigned(nbyte @ 4) => {
+                ConstantValue::U32(bits as u32 & (u32::MAX >> (32 - nbyte * 8)))
+            },
+            ScalarType::Unsigned(8) => ConstantValue::U64(bits),
+            ScalarType::Float(2) => ConstantValue::F32(f16_to_f32(bits as u16)),
+            ScalarType::Float(4) => ConstantValue::F32(f32::from_bits(bits as u32)),
+            ScalarType::Float(8) => ConstantValue::F64(f64::from_bits(bits)),
+            _ => return None,
+        };
+        Some(value)
+    }
+    /// Get the value `OpConstantNull` declares for the type. Numeric and
+    /// composite types are filled with zeros.
+    pub(crate) fn zero(ty: &Type) -> ConstantValue {
+        let zero_vec = |vec_ty: &VectorType| {
+            let x = ConstantValue::zero(&Type::Scalar(vec_ty.scalar_ty.clone()));
+            ConstantValue::Composite(vec![x; vec_ty.nscalar as usize])
+        };
+        match ty {
+            Type::Scalar(scalar_ty) => ConstantValue::from_bits(scalar_ty, 0)
+                .unwrap_or(ConstantValue::Null),
+            Type::Vector(vec_ty) => zero_vec(vec_ty),
+            Type::Matrix(mat_ty) => {
+                let x = zero_vec(&mat_ty.vec_ty);
+                ConstantValue::Composite(vec![x; mat_ty.nvec as usize])
+            },
+            Type::Array(arr_ty) => match arr_ty.nrepeat() {
+                Some(nrepeat) => {
+                    let x = ConstantValue::zero(arr_ty.proto_ty());
+                    ConstantValue::Composite(vec![x; nrepeat as usize])
+                },
+                None => ConstantValue::Null,
+            },
+            Type::Struct(struct_ty) => {
+                let xs = (0..struct_ty.nmember())
+                    .map(|i| ConstantValue::zero(&struct_ty.get_member(i).unwrap().ty))
+                    .collect();
+                ConstantValue::Composite(xs)
+            },
+            _ => ConstantValue::Null,
+        }
+    }
+    /// Decode a scalar value from the literal words of a constant.
+    pub(crate) fn from_words(scalar_ty: &ScalarType, words: &[u32]) -> Option<ConstantValue> {
+        // Wide literals are stored low-order word first.
+        let bits = match (scalar_ty.nbyte(), words) {
+            (8, [lo, hi, ..]) => (*hi as u64) << 32 | *lo as u64,
+            (8, _) => return None,
+            (_, [x, ..]) => *x as u64,
+            _ => return None,
+        };
+        ConstantValue::from_bits(scalar_ty, bits)
+    }
+    /// Convert a user-provided value to a value of the scalar type. Integers
+    /// are accepted as long as the value is representable in the type.
+    pub(crate) fn cast(&self, scalar_ty: &ScalarType) -> Option<ConstantValue> {
+        let value = match (self, scalar_ty) {
+            (ConstantValue::Bool(_), ScalarType::Boolean) |
+            (ConstantValue::F32(_), ScalarType::Float(4)) |
+            (ConstantValue::F64(_), ScalarType::Float(8)) => self.clone(),
+            (_, ScalarType::Signed(nbyte)) => {
+                let x = self.to_i64()?;
+                let nbit = nbyte * 8;
+                if nbit < 64 && (x < -(1 << (nbit - 1)) || x >= 1 << (nbit - 1)) { return None; }
+                ConstantValue::from_bits(scalar_ty, x as u64)?
+            },
+            (_, ScalarType::Unsigned(nbyte)) => {
+                let x = self.to_u64()?;
+                let nbit = nbyte * 8;
+                if nbit < 64 && x >> nbit != 0 { return None; }
+                ConstantValue::from_bits(scalar_ty, x)?
+            },
+            // Half floats are rounded to the nearest representable value.
+            (ConstantValue::F32(x), ScalarType::Float(2)) => {
+                ConstantValue::F32(f16_to_f32(f32_to_f16(*x)))
+            },
+            _ => return None,
+        };
+        Some(value)
+    }
+    /// Encode a scalar value of the scalar type into the literal words of a
+    /// constant. Narrow integers are sign-extended or zero-extended to a whole
+    /// word as the specification requires.
+    pub(crate) fn to_words(&self, scalar_ty: &ScalarType) -> Option<Vec<u32>> {
+        let words = match *self {
+            ConstantValue::F32(x) if matches!(scalar_ty, ScalarType::Float(2)) => {
+                vec![f32_to_f16(x) as u32]
+            },
+            ConstantValue::Bool(x) => vec![x as u32],
+            ConstantValue::I32(x) => vec![x as u32],
+            ConstantValue::U32(x) => vec![x],
+            ConstantValue::F32(x) => vec![x.to_bits()],
+            ConstantValue::I64(x) => vec![x as u32, (x >> 32) as u32],
+            ConstantValue::U64(x) => vec![x as u32, (x >> 32) as u32],
+            ConstantValue::F64(x) => {
+                let x = x.to_bits();
+                vec![x as u32, (x >> 32) as u32]
+            },
+            _ => return None,
+        };
+        Some(words)
+    }
+    /// Get the value as an unsigned integer, if it's a non-negative integer.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self {
+            ConstantValue::I32(x) => (*x).try_into().ok(),
+            ConstantValue::U32(x) => Some(*x as u64),
+            ConstantValue::I64(x) => (*x).try_into().ok(),
+            ConstantValue::U64(x) => Some(*x),
+            _ => None,
+        }
+    }
+    /// Get the value as a signed integer, if it's an integer in the range of
+    /// `i64`.
+    pub fn to_i64(&self) -> Option<i64> {
+        match self {
+            ConstantValue::I32(x) => Some(*x as i64),
+            ConstantValue::U32(x) => Some(*x as i64),
+            ConstantValue::I64(x) => Some(*x),
+            ConstantValue::U64(x) => (*x).try_into().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Convert to a half float, rounding to the nearest, ties to even.
+pub(crate) fn f32_to_f16(x: f32) -> u16 {
+    let bits = x.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xFF) as i32;
+    let mant = bits & 0x7F_FFFF;
+    if exp == 0xFF {
+        // NaNs are kept quiet.
+        let nan = if mant != 0 { 0x200 | (mant >> 13) as u16 } else { 0 };
+        return sign | 0x7C00 | nan;
+    }
+    let exp = exp - 112;
+    if exp >= 0x1F { return sign | 0x7C00; }
+    let (half, rem, halfway) = if exp <= 0 {
+        // Subnormal numbers in `f16`, including the implicit leading bit.
+        if exp < -10 { return sign; }
+        let mant = mant | 0x80_0000;
+        let shift = (14 - exp) as u32;
+        (mant >> shift, mant & ((1 << shift) - 1), 1 << (shift - 1))
+    } else {
+        ((exp as u32) << 10 | mant >> 13, mant & 0x1FFF, 0x1000)
+    };
+    // Carries into the exponent are correct, up to infinity.
+    let round = rem > halfway || (rem == halfway && half & 1 != 0);
+    sign | (half + round as u32) as u16
+}
+fn f16_to_f32(x: u16) -> f32 {
+    let sign = ((x >> 15) as u32) << 31;
+    let exp = ((x >> 10) & 0x1F) as u32;
+    let mant = (x & 0x3FF) as u32;
+    let bits = match (exp, mant) {
+        (0, 0) => sign,
+        // Subnormal numbers are normalized in `f32`.
+        (0, _) => {
+            let shift = mant.leading_zeros() - 21;
+            let mant = (mant << shift) & 0x3FF;
+            sign | (113 - shift) << 23 | mant << 13
+        },
+        (0x1F, _) => sign | 0xFF << 23 | mant << 13,
+        _ => sign | (exp + 112) << 23 | mant << 13,
+    };
+    f32::from_bits(bits)
+}
@@ -2,16 +2,41 @@ use std::ops::RangeInclusive;
 use spirv_headers::Op;
 
 pub type OpCode = u32;
+pub const OP_CAPABILITY: OpCode = Op::Capability as u32;
+pub const OP_EXTENSION: OpCode = Op::Extension as u32;
+pub const OP_EXT_INST_IMPORT: OpCode = Op::ExtInstImport as u32;
+pub const OP_EXT_INST: OpCode = Op::ExtInst as u32;
+pub const OP_MEMORY_MODEL: OpCode = Op::MemoryModel as u32;
+pub const OP_EXECUTION_MODE: OpCode = Op::ExecutionMode as u32;
+pub const OP_EXECUTION_MODE_ID: OpCode = Op::ExecutionModeId as u32;
+
+pub const DEBUG_OPS: &[OpCode] = &[
+    Op::SourceContinued as u32,
+    Op::Source as u32,
+    Op::SourceExtension as u32,
+    Op::Name as u32,
+    Op::MemberName as u32,
+    Op::String as u32,
+    Op::ModuleProcessed as u32,
+];
+pub const ANNOTATION_OPS: &[OpCode] = &[
+    Op::Decorate as u32,
+    Op::MemberDecorate as u32,
+    Op::DecorationGroup as u32,
+    Op::GroupDecorate as u32,
+    Op::GroupMemberDecorate as u32,
+    Op::DecorateId as u32,
+    Op::DecorateString as u32,
+    Op::MemberDecorateString as u32,
+];
+
 pub const OP_ENTRY_POINT: OpCode = Op::EntryPoint as u32;
-pub const ENTRY_POINT_RANGE: RangeInclusive<OpCode> = OP_ENTRY_POINT..=OP_ENTRY_POINT;
 
 pub const OP_NAME: OpCode = Op::Name as u32;
 pub const OP_MEMBER_NAME: OpCode = Op::MemberName as u32;
-pub const NAME_RANGE: RangeInclusive<OpCode> = OP_NAME..=OP_MEMBER_NAME;
 
 pub const OP_DECORATE: OpCode = 71;
 pub const OP_MEMBER_DECORATE: OpCode = 72;
-pub const DECO_RANGE: RangeInclusive<OpCode> = OP_DECORATE..=OP_MEMBER_DECORATE;
 
 // Don't need this: Not a resource type. But kept for the range.
 pub const OP_TYPE_VOID: OpCode = Op::TypeVoid as u32;
@@ -32,17 +57,17 @@ pub const OP_TYPE_FUNCTION: OpCode = Op::TypeFunction as u32;
 pub const TYPE_RANGE: RangeInclusive<OpCode> = OP_TYPE_VOID..=OP_TYPE_FUNCTION;
 
 pub const OP_CONSTANT_TRUE: OpCode = Op::ConstantTrue as u32;
-// pub const OP_CONSTANT_FALSE: OpCode = Op::ConstantFalse as u32;
+pub const OP_CONSTANT_FALSE: OpCode = Op::ConstantFalse as u32;
 pub const OP_CONSTANT: OpCode = Op::Constant as u32;
-// pub const OP_CONSTANT_COMPOSITE: OpCode = Op::ConstantComposite as u32;
-// pub const OP_CONSTANT_SAMPLER: OpCode = Op::ConstantSampler as u32;
+pub const OP_CONSTANT_COMPOSITE: OpCode = Op::ConstantComposite as u32;
+pub const OP_CONSTANT_SAMPLER: OpCode = Op::ConstantSampler as u32;
 pub const OP_CONSTANT_NULL: OpCode = Op::ConstantNull as u32;
 pub const CONST_RANGE: RangeInclusive<OpCode> = OP_CONSTANT_TRUE..=OP_CONSTANT_NULL;
 
 pub const OP_SPEC_CONSTANT_TRUE: OpCode = Op::SpecConstantTrue as u32;
-// pub const OP_SPEC_CONSTANT_FALSE: OpCode = Op::SpecConstantFalse as u32;
-// pub const OP_SPEC_CONSTANT: OpCode = Op::SpecConstant as u32;
-// pub const OP_SPEC_CONSTANT_COMPOSITE: OpCode = Op::SpecConstantComposite as u32;
+pub const OP_SPEC_CONSTANT_FALSE: OpCode = Op::SpecConstantFalse as u32;
+pub const OP_SPEC_CONSTANT: OpCode = Op::SpecConstant as u32;
+pub const OP_SPEC_CONSTANT_COMPOSITE: OpCode = Op::SpecConstantComposite as u32;
 pub const OP_SPEC_CONSTANT_OP: OpCode = Op::SpecConstantOp as u32;
 pub const SPEC_CONST_RANGE: RangeInclusive<OpCode> = OP_SPEC_CONSTANT_TRUE..=OP_SPEC_CONSTANT_OP;
 
@@ -50,8 +75,71 @@ pub const OP_VARIABLE: OpCode = Op::Variable as u32;
 
 pub const OP_FUNCTION: OpCode = Op::Function as u32;
 pub const OP_FUNCTION_END: OpCode = Op::FunctionEnd as u32;
+pub const OP_FUNCTION_PARAMETER: OpCode = Op::FunctionParameter as u32;
 pub const OP_FUNCTION_CALL: OpCode = Op::FunctionCall as u32;
-pub const OP_ACCESS_CHAIN: OpCode = Op::AccessChain as u32;
 pub const OP_LOAD: OpCode = Op::Load as u32;
 pub const OP_STORE: OpCode = Op::Store as u32;
-// pub const OP_IN_BOUNDS_ACCESS_CHAIN: OpCode = Op::InBoundsAccessChain as u32;
+pub const OP_COPY_MEMORY: OpCode = Op::CopyMemory as u32;
+pub const OP_COPY_MEMORY_SIZED: OpCode = Op::CopyMemorySized as u32;
+pub const OP_COPY_OBJECT: OpCode = Op::CopyObject as u32;
+pub const OP_IMAGE_TEXEL_POINTER: OpCode = Op::ImageTexelPointer as u32;
+pub const OP_PHI: OpCode = Op::Phi as u32;
+pub const OP_SELECT: OpCode = Op::Select as u32;
+pub const OP_ARRAY_LENGTH: OpCode = Op::ArrayLength as u32;
+pub const OP_PTR_ACCESS_CHAIN: OpCode = Op::PtrAccessChain as u32;
+pub const OP_IN_BOUNDS_PTR_ACCESS_CHAIN: OpCode = Op::InBoundsPtrAccessChain as u32;
+pub const ACCESS_CHAIN_OPS: &[OpCode] = &[
+    Op::AccessChain as u32,
+    Op::InBoundsAccessChain as u32,
+    Op::PtrAccessChain as u32,
+    Op::InBoundsPtrAccessChain as u32,
+];
+pub const OP_SAMPLED_IMAGE: OpCode = Op::SampledImage as u32;
+pub const OP_IMAGE: OpCode = Op::Image as u32;
+pub const OP_IMAGE_READ: OpCode = Op::ImageRead as u32;
+pub const OP_IMAGE_WRITE: OpCode = Op::ImageWrite as u32;
+// Instructions reading texels from the image right after the result id.
+pub const IMAGE_READ_OPS: &[OpCode] = &[
+    Op::ImageSampleImplicitLod as u32,
+    Op::ImageSampleExplicitLod as u32,
+    Op::ImageSampleDrefImplicitLod as u32,
+    Op::ImageSampleDrefExplicitLod as u32,
+    Op::ImageSampleProjImplicitLod as u32,
+    Op::ImageSampleProjExplicitLod as u32,
+    Op::ImageSampleProjDrefImplicitLod as u32,
+    Op::ImageSampleProjDrefExplicitLod as u32,
+    Op::ImageFetch as u32,
+    Op::ImageGather as u32,
+    Op::ImageDrefGather as u32,
+    Op::ImageRead as u32,
+    Op::ImageSparseSampleImplicitLod as u32,
+    Op::ImageSparseSampleExplicitLod as u32,
+    Op::ImageSparseSampleDrefImplicitLod as u32,
+    Op::ImageSparseSampleDrefExplicitLod as u32,
+    Op::ImageSparseFetch as u32,
+    Op::ImageSparseGather as u32,
+    Op::ImageSparseDrefGather as u32,
+    Op::ImageSparseRead as u32,
+];
+pub const OP_ATOMIC_LOAD: OpCode = Op::AtomicLoad as u32;
+pub const OP_ATOMIC_STORE: OpCode = Op::AtomicStore as u32;
+pub const OP_ATOMIC_FLAG_CLEAR: OpCode = Op::AtomicFlagClear as u32;
+// Atomic instructions taking the pointer right after the result id.
+pub const ATOMIC_OPS: &[OpCode] = &[
+    Op::AtomicLoad as u32,
+    Op::AtomicExchange as u32,
+    Op::AtomicCompareExchange as u32,
+    Op::AtomicCompareExchangeWeak as u32,
+    Op::AtomicIIncrement as u32,
+    Op::AtomicIDecrement as u32,
+    Op::AtomicIAdd as u32,
+    Op::AtomicISub as u32,
+    Op::AtomicSMin as u32,
+    Op::AtomicUMin as u32,
+    Op::AtomicSMax as u32,
+    Op::AtomicUMax as u32,
+    Op::AtomicAnd as u32,
+    Op::AtomicOr as u32,
+    Op::AtomicXor as u32,
+    Op::AtomicFlagTestAndSet as u32,
+];
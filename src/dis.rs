@@ -0,0 +1,305 @@
+//! Textual disassembly of SPIR-V modules, in the same format as `spirv-dis`.
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use crate::{SpirvModule, SpirvHeader};
+use crate::parse::{Instr, Operands};
+use crate::consts::*;
+use crate::grammar::*;
+use crate::error::{Error, Result};
+
+/// Numeric types needed to decode context-dependent literals.
+#[derive(Clone, Copy)]
+enum NumType {
+    Int(u32, bool),
+    Float(u32),
+}
+
+struct Disassembler {
+    names: HashMap<u32, String>,
+    num_tys: HashMap<u32, NumType>,
+}
+impl Disassembler {
+    fn new(module: &SpirvModule, friendly_names: bool) -> Result<Disassembler> {
+        let mut names = HashMap::new();
+        if friendly_names {
+            // Names are sanitized to be valid identifiers and suffixed if
+            // they collide with each other.
+            let mut used_names = HashSet::new();
+            for instr in module.instrs() {
+                if instr.opcode() != OP_NAME { continue; }
+                let mut operands = instr.operands();
+                let id = operands.read_u32()?;
+                let name = operands.read_str()?;
+                if name.is_empty() || names.contains_key(&id) { continue; }
+                let mut name = name.chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect::<String>();
+                if name.starts_with(|c: char| c.is_ascii_digit()) {
+                    name.insert(0, '_');
+                }
+                let mut unique_name = name.clone();
+                let mut i = 0;
+                while !used_names.insert(unique_name.clone()) {
+                    unique_name = format!("{}_{}", name, i);
+                    i += 1;
+                }
+                names.insert(id, unique_name);
+            }
+        }
+        let dis = Disassembler { names, num_tys: HashMap::new() };
+        Ok(dis)
+    }
+    fn fmt_id(&self, id: u32) -> String {
+        if let Some(name) = self.names.get(&id) {
+            format!("%{}", name)
+        } else {
+            format!("%{}", id)
+        }
+    }
+    fn fmt_str(x: &str) -> String {
+        let mut rv = String::with_capacity(x.len() + 2);
+        rv.push('"');
+        for c in x.chars() {
+            if c == '"' || c == '\\' { rv.push('\\'); }
+            rv.push(c);
+        }
+        rv.push('"');
+        rv
+    }
+    fn fmt_num(&self, ty_id: Option<u32>, operands: &mut Operands) -> Result<String> {
+        let num_ty = ty_id.and_then(|x| self.num_tys.get(&x));
+        let rv = match num_ty {
+            Some(NumType::Int(64, is_signed)) => {
+                let lo = operands.read_u32()? as u64;
+                let hi = operands.read_u32()? as u64;
+                let x = hi << 32 | lo;
+                if *is_signed { (x as i64).to_string() } else { x.to_string() }
+            },
+            Some(NumType::Int(nbit, is_signed)) => {
+                let x = operands.read_u32()?;
+                if *is_signed {
+                    // Sign-extend integers narrower than a word.
+                    let shift = 32 - (*nbit).min(32);
+                    (((x << shift) as i32) >> shift).to_string()
+                } else { x.to_string() }
+            },
+            Some(NumType::Float(64)) => {
+                let lo = operands.read_u32()? as u64;
+                let hi = operands.read_u32()? as u64;
+                f64::from_bits(hi << 32 | lo).to_string()
+            },
+            Some(NumType::Float(32)) => {
+                f32::from_bits(operands.read_u32()?).to_string()
+            },
+            // Floats of other widths are printed as raw bits.
+            _ => format!("0x{:x}", operands.read_u32()?),
+        };
+        Ok(rv)
+    }
+    fn fmt_enum(&self, kind: OperandKind, operands: &mut Operands, out: &mut Vec<String>) -> Result<()> {
+        let enumerants = enumerants(kind).unwrap();
+        let value = operands.read_u32()?;
+        let mut params = Vec::new();
+        if is_bit_enum(kind) {
+            let mut names = Vec::new();
+            let mut rem = value;
+            for e in enumerants.iter() {
+                if e.value != 0 && rem & e.value == e.value {
+                    names.push(e.name.to_owned());
+                    params.push(e.params);
+                    rem &= !e.value;
+                }
+            }
+            if rem != 0 || value == 0 {
+                match enumerants.iter().find(|e| e.value == 0) {
+                    Some(e) if rem == 0 => names.push(e.name.to_owned()),
+                    _ => names.push(format!("0x{:x}", rem)),
+                }
+            }
+            out.push(names.join("|"));
+        } else if let Some(e) = enumerants.iter().find(|e| e.value == value) {
+            out.push(e.name.to_owned());
+            params.push(e.params);
+        } else {
+            out.push(value.to_string());
+        }
+        for param in params {
+            self.fmt_operands(param, None, operands, out)?;
+        }
+        Ok(())
+    }
+    fn fmt_operand(&self, kind: OperandKind, ty_id: Option<u32>, operands: &mut Operands, out: &mut Vec<String>) -> Result<()> {
+        use OperandKind::*;
+        match kind {
+            IdResultType | IdResult | IdRef | IdScope | IdMemorySemantics => {
+                out.push(self.fmt_id(operands.read_u32()?));
+            },
+            LiteralInteger | LiteralExtInstInteger => {
+                out.push(operands.read_u32()?.to_string());
+            },
+            LiteralString => out.push(Self::fmt_str(operands.read_str()?)),
+            LiteralContextDependentNumber => out.push(self.fmt_num(ty_id, operands)?),
+            LiteralSpecConstantOpInteger => {
+                // The operation is followed by the operands of the specified
+                // opcode, without result type and result id.
+                let opcode = operands.read_u32()?;
+                let grammar = instr_by_opcode(opcode).ok_or(Error::UNENCODED_ENUM)?;
+                out.push(grammar.name()[2..].to_owned());
+                let params = grammar.operands.iter()
+                    .skip_while(|x| x.0 == IdResultType || x.0 == IdResult)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                self.fmt_operands(&params, ty_id, operands, out)?;
+            },
+            PairLiteralIntegerIdRef => {
+                out.push(operands.read_u32()?.to_string());
+                out.push(self.fmt_id(operands.read_u32()?));
+            },
+            PairIdRefLiteralInteger => {
+                out.push(self.fmt_id(operands.read_u32()?));
+                out.push(operands.read_u32()?.to_string());
+            },
+            PairIdRefIdRef => {
+                out.push(self.fmt_id(operands.read_u32()?));
+                out.push(self.fmt_id(operands.read_u32()?));
+            },
+            _ => self.fmt_enum(kind, operands, out)?,
+        }
+        Ok(())
+    }
+    fn fmt_operands(&self, grammar: &[OperandGrammar], ty_id: Option<u32>, operands: &mut Operands, out: &mut Vec<String>) -> Result<()> {
+        for &(kind, quant) in grammar {
+            match quant {
+                Quantifier::One => self.fmt_operand(kind, ty_id, operands, out)?,
+                Quantifier::Optional => if !operands.is_empty() {
+                    self.fmt_operand(kind, ty_id, operands, out)?;
+                },
+                Quantifier::Variadic => while !operands.is_empty() {
+                    self.fmt_operand(kind, ty_id, operands, out)?;
+                },
+            }
+        }
+        Ok(())
+    }
+    fn fmt_instr(&mut self, instr: &Instr) -> Result<String> {
+        let mut operands = instr.operands();
+        let grammar = if let Some(x) = instr_by_opcode(instr.opcode()) { x } else {
+            // Keep unknown instructions visible as comments.
+            let mut line = format!("; <opcode {}>", instr.opcode());
+            while let Ok(word) = operands.read_u32() {
+                write!(line, " 0x{:08x}", word).unwrap();
+            }
+            return Ok(line);
+        };
+        let mut ty_id = None;
+        let mut result_id = None;
+        let mut out = Vec::new();
+        for &(kind, quant) in grammar.operands {
+            match kind {
+                OperandKind::IdResultType => {
+                    let id = operands.read_u32()?;
+                    ty_id = Some(id);
+                    out.push(self.fmt_id(id));
+                },
+                OperandKind::IdResult => result_id = Some(operands.read_u32()?),
+                _ => self.fmt_operands(&[(kind, quant)], ty_id, &mut operands, &mut out)?,
+            }
+        }
+        // Trailing words not described by the grammar are kept as literals.
+        while let Ok(word) = operands.read_u32() {
+            out.push(word.to_string());
+        }
+        match (instr.opcode(), result_id) {
+            (OP_TYPE_INT, Some(id)) => {
+                let mut operands = instr.operands();
+                operands.read_u32()?;
+                let nbit = operands.read_u32()?;
+                let is_signed = operands.read_bool()?;
+                self.num_tys.insert(id, NumType::Int(nbit, is_signed));
+            },
+            (OP_TYPE_FLOAT, Some(id)) => {
+                let mut operands = instr.operands();
+                operands.read_u32()?;
+                let nbit = operands.read_u32()?;
+                self.num_tys.insert(id, NumType::Float(nbit));
+            },
+            _ => {},
+        }
+        let mut line = if let Some(result_id) = result_id {
+            format!("{:>12} = {}", self.fmt_id(result_id), grammar.name())
+        } else {
+            format!("{:>15}{}", "", grammar.name())
+        };
+        for x in out {
+            line.push(' ');
+            line.push_str(&x);
+        }
+        Ok(line)
+    }
+}
+
+pub(crate) fn disassemble(module: &SpirvModule, friendly_names: bool) -> Result<String> {
+    let header: SpirvHeader = module.header()?;
+    let mut dis = Disassembler::new(module, friendly_names)?;
+    let mut out = String::new();
+    writeln!(out, "; SPIR-V").unwrap();
+    writeln!(out, "; Version: {}.{}", header.major_version, header.minor_version).unwrap();
+    writeln!(out, "; Generator: {}; {}", header.generator_id, header.generator_version).unwrap();
+    writeln!(out, "; Bound: {}", header.bound).unwrap();
+    writeln!(out, "; Schema: {}", header.schema).unwrap();
+    for instr in module.instrs() {
+        let line = dis.fmt_instr(&instr)?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SpirvBinary;
+
+    #[test]
+    fn test_round_trip() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability Float16
+               OpCapability Float64
+               OpCapability Int64
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %color
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %color "color"
+               OpDecorate %color Location 0
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+        %f16 = OpTypeFloat 16
+        %f32 = OpTypeFloat 32
+        %f64 = OpTypeFloat 64
+        %i32 = OpTypeInt 32 1
+        %u64 = OpTypeInt 64 0
+       %vec4 = OpTypeVector %f32 4
+   %vec4_out = OpTypePointer Output %vec4
+      %color = OpVariable %vec4_out Output
+      %half_ = OpConstant %f16 -1.5
+     %float_ = OpConstant %f32 0.25
+    %double_ = OpConstant %f64 1e+100
+       %int_ = OpConstant %i32 -7
+      %long_ = OpConstant %u64 18446744073709551615
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+       %sqrt = OpExtInst %f32 %1 31 %float_
+        %vec = OpCompositeConstruct %vec4 %sqrt %sqrt %sqrt %float_
+               OpStore %color %vec Aligned 16
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        for text in [spv.disassemble().unwrap(), spv.disassemble_raw_ids().unwrap()] {
+            let spv2 = SpirvBinary::assemble(&text).unwrap();
+            assert_eq!(spv2.words(), spv.words(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Editing and re-encoding of SPIR-V modules.
+//!
+//! A [`ModuleEditor`] decodes a module into a list of owned instructions that
+//! can be freely inserted, removed and replaced. Word counts are recomputed and
+//! the id bound is raised to cover new ids when the module is encoded back into
+//! a binary.
+//!
+//! [`ModuleEditor`]: struct.ModuleEditor.html
+use num_traits::FromPrimitive;
+use spirv_headers::Op;
+use crate::grammar::OperandKind;
+use crate::parse::{self, Instr, Operands};
+use crate::error::{Error, Result, InstrLocation};
+use crate::{SpirvBinary, SpirvHeader, SpirvModule};
+
+/// An owned SPIR-V instruction.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InstrBuf {
+    opcode: u32,
+    operands: Vec<u32>,
+}
+impl InstrBuf {
+    /// Create an instruction without any operand.
+    pub fn new(op: Op) -> InstrBuf { InstrBuf::from_opcode(op as u32) }
+    /// Create an instruction without any operand from a raw opcode.
+    pub fn from_opcode(opcode: u32) -> InstrBuf {
+        InstrBuf { opcode, operands: Vec::new() }
+    }
+    /// Get the opcode of the instruction.
+    pub fn opcode(&self) -> u32 { self.opcode }
+    /// Get the opcode of the instruction as an enumeration, if it's known to
+    /// `spirv_headers`.
+    pub fn op(&self) -> Option<Op> { Op::from_u32(self.opcode) }
+    /// Get the word count of the instruction, including the first word
+    /// containing the word count and opcode.
+    pub fn word_count(&self) -> usize { self.operands.len() + 1 }
+    /// Get the result type id of the instruction, if it has one.
+    pub fn result_type_id(&self) -> Option<u32> {
+        parse::id_operand(self.opcode, &self.operands, OperandKind::IdResultType)
+    }
+    /// Get the result id of the instruction, if it has one.
+    pub fn result_id(&self) -> Option<u32> {
+        parse::id_operand(self.opcode, &self.operands, OperandKind::IdResult)
+    }
+    /// Get the raw operand words of the instruction.
+    pub fn operand_words(&self) -> &[u32] { &self.operands }
+    /// Get the raw operand words of the instruction for modification.
+    pub fn operand_words_mut(&mut self) -> &mut Vec<u32> { &mut self.operands }
+    /// Get an instruction operand reader.
+    pub fn operands(&self) -> Operands<'_> { Operands::new(&self.operands) }
+    /// Append a word to the operands.
+    pub fn push_u32(&mut self, x: u32) -> &mut InstrBuf {
+        self.operands.push(x);
+        self
+    }
+    /// Append a nul-terminated literal string to the operands.
+    pub fn push_str(&mut self, x: &str) -> &mut InstrBuf {
+        // Strings are nul-terminated and padded to whole words.
+        let mut bytes = x.as_bytes().to_owned();
+        bytes.resize((bytes.len() / 4 + 1) * 4, 0);
+        let words = bytes.chunks_exact(4)
+            .map(|x| u32::from_le_bytes([x[0], x[1], x[2], x[3]]));
+        self.operands.extend(words);
+        self
+    }
+    /// Append words to the operands.
+    pub fn push_list(&mut self, x: &[u32]) -> &mut InstrBuf {
+        self.operands.extend_from_slice(x);
+        self
+    }
+}
+impl<'a> From<&Instr<'a>> for InstrBuf {
+    fn from(x: &Instr<'a>) -> InstrBuf {
+        InstrBuf { opcode: x.opcode(), operands: x.operand_words().to_owned() }
+    }
+}
+
+/// A mutable list of instructions decoded from a module.
+///
+/// The id bound in the header is raised on encoding to cover all result ids, so
+/// added ids don't have to be tracked manually. It's never lowered because the
+/// result ids of instructions unknown to SPIR-Q can't be located.
+#[derive(Debug, Clone)]
+pub struct ModuleEditor {
+    header: SpirvHeader,
+    instrs: Vec<InstrBuf>,
+}
+impl ModuleEditor {
+    /// Decode the module for editing.
+    pub fn new(module: &SpirvModule) -> Result<ModuleEditor> {
+        let header = module.header()?;
+        let mut instrs = module.instrs();
+        let instr_bufs = instrs.by_ref()
+            .map(|instr| InstrBuf::from(&instr))
+            .collect();
+        if !instrs.remaining().is_empty() { return Err(Error::INSTR_TOO_SHORT); }
+        Ok(ModuleEditor { header, instrs: instr_bufs })
+    }
+    /// Get the module header. The id bound in the header is not updated until
+    /// the module is encoded.
+    pub fn header(&self) -> &SpirvHeader { &self.header }
+    /// Get the module header for modification, e.g., to change the SPIR-V
+    /// version. The id bound is raised on encoding if it doesn't cover all
+    /// result ids.
+    pub fn header_mut(&mut self) -> &mut SpirvHeader { &mut self.header }
+    /// Get the instructions in the module.
+    pub fn instrs(&self) -> &[InstrBuf] { &self.instrs }
+    /// Get the instructions in the module for modification.
+    pub fn instrs_mut(&mut self) -> &mut [InstrBuf] { &mut self.instrs }
+    /// Find the index of the first instruction satisfying the predicate.
+    pub fn position<F: FnMut(&InstrBuf) -> bool>(&self, f: F) -> Option<usize> {
+        self.instrs.iter().position(f)
+    }
+    /// Insert an instruction at index `i`, shifting all the instructions after
+    /// it.
+    pub fn insert(&mut self, i: usize, instr: InstrBuf) {
+        self.instrs.insert(i, instr);
+    }
+    /// Remove the instruction at index `i`.
+    pub fn remove(&mut self, i: usize) -> InstrBuf {
+        self.instrs.remove(i)
+    }
+    /// Replace the instruction at index `i`, returning the old one.
+    pub fn replace(&mut self, i: usize, instr: InstrBuf) -> InstrBuf {
+        std::mem::replace(&mut self.instrs[i], instr)
+    }
+    /// Only keep the instructions satisfying the predicate.
+    pub fn retain<F: FnMut(&InstrBuf) -> bool>(&mut self, f: F) {
+        self.instrs.retain(f);
+    }
+    /// Allocate a new id that has never been used in the module.
+    pub fn alloc_id(&mut self) -> u32 {
+        let id = self.header.bound.max(self.bound());
+        self.header.bound = id + 1;
+        id
+    }
+    /// Compute the id bound of the module, i.e., the largest result id plus
+    /// one.
+    pub fn bound(&self) -> u32 {
+        self.instrs.iter()
+            .filter_map(InstrBuf::result_id)
+            .max()
+            .map_or(1, |x| x + 1)
+    }
+    /// Encode the module into a binary.
+    pub fn encode(&self) -> Result<SpirvBinary> {
+        let header = SpirvHeader {
+            bound: self.header.bound.max(self.bound()),
+            ..self.header
+        };
+        let mut words = header.to_words().to_vec();
+        for instr in self.instrs.iter() {
+            let word_count = instr.word_count();
+            if word_count > 0xFFFF {
+                let loc = InstrLocation {
+                    offset: words.len(),
+                    opcode: instr.opcode,
+                    id: instr.result_id(),
+                    name: None,
+                };
+                return Err(Error::INSTR_TOO_LONG.at(loc));
+            }
+            words.push((word_count as u32) << 16 | instr.opcode);
+            words.extend_from_slice(&instr.operands);
+        }
+        Ok(words.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SpirvBinary;
+    use super::InstrBuf;
+
+    #[test]
+    fn test_unknown_result_id_bound() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+        %int = OpTypeInt 32 1
+"#).unwrap();
+        let mut editor = spv.edit().unwrap();
+        let ty_id = editor.instrs()[2].result_id().unwrap();
+        // The result id of an opcode unknown to SPIR-Q can't be recognized.
+        let id = editor.alloc_id();
+        let mut instr = InstrBuf::from_opcode(0xFFFF);
+        instr.push_u32(ty_id).push_u32(id);
+        editor.insert(3, instr);
+        let spv = editor.encode().unwrap();
+        assert_eq!(spv.header().unwrap().bound, id + 1);
+        // The bound survives another round trip.
+        let spv = spv.edit().unwrap().encode().unwrap();
+        assert_eq!(spv.header().unwrap().bound, id + 1);
+        assert_eq!(spv.edit().unwrap().alloc_id(), id + 1);
+    }
+}
@@ -1,15 +1,101 @@
 //! Error and result reported by SPIR-Q procedures.
 use std::fmt;
 use std::error;
+use spirv_headers::ExecutionModel;
+use crate::DescriptorBinding;
 
-#[derive(Debug)]
+/// The instruction where an error occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstrLocation {
+    /// Offset of the instruction in words, from the beginning of module,
+    /// including the header.
+    pub offset: usize,
+    /// Opcode of the instruction.
+    pub opcode: u32,
+    /// The offending id. It's the result id of the instruction, or the target
+    /// id of a debug instruction or decoration.
+    pub id: Option<u32>,
+    /// Name of the offending object given by `OpName`, if any.
+    pub name: Option<String>,
+}
+impl fmt::Display for InstrLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "word {}, ", self.offset)?;
+        if let Some(instr_grammar) = crate::grammar::instr_by_opcode(self.opcode) {
+            write!(f, "{}", instr_grammar.name())?;
+        } else {
+            write!(f, "<opcode {}>", self.opcode)?;
+        }
+        if let Some(id) = self.id { write!(f, " %{}", id)?; }
+        if let Some(name) = self.name.as_ref() { write!(f, " \"{}\"", name)?; }
+        Ok(())
+    }
+}
+
+/// The record that conflicted in a manifest merge.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManifestConflict {
+    /// Descriptors bound to the same binding point have different types.
+    Descriptor(DescriptorBinding),
+    /// Push constant members of the same name have different types or
+    /// offsets.
+    PushConstantMember(String),
+    /// A name refers to different resources.
+    Name(String),
+}
+impl fmt::Display for ManifestConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ManifestConflict::*;
+        match self {
+            Descriptor(desc_bind) => write!(f, "descriptor at {}", desc_bind),
+            PushConstantMember(name) => write!(f, "push constant member \"{}\"", name),
+            Name(name) => write!(f, "name \"{}\"", name),
+        }
+    }
+}
+
+/// Details of a failed manifest merge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestMismatch {
+    /// The conflicting record.
+    pub conflict: ManifestConflict,
+    /// Execution models of the entry points already merged into the manifest.
+    pub dst_stages: Vec<ExecutionModel>,
+    /// Execution models of the entry points in the manifest being merged.
+    pub src_stages: Vec<ExecutionModel>,
+}
+impl fmt::Display for ManifestMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} conflicts between {:?} and {:?}", self.conflict,
+            self.dst_stages, self.src_stages)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Error {
     CorruptedSpirv(&'static str),
     UnsupportedSpirv(&'static str),
-    MismatchedManifest,
+    MismatchedManifest(ManifestMismatch),
+    /// The value given to specialize a constant doesn't fit the type of the
+    /// constant. The `SpecId` is attached.
+    MismatchedSpecValue(u32),
+    /// SPIR-V assembly text cannot be assembled. The line number (1-based)
+    /// and the reason are attached.
+    InvalidAssembly(usize, String),
+    /// Name sidecar text cannot be parsed. The line number (1-based) and the
+    /// reason are attached.
+    InvalidSidecar(usize, String),
+    /// An error occurred at a specific instruction. The location and the
+    /// underlying error are attached.
+    AtInstr(InstrLocation, Box<Error>),
 }
 impl Error {
+    pub const HEADER_TOO_SHORT: Self = Self::CorruptedSpirv("binary is too short to contain a header");
+    pub const UNALIGNED_BINARY: Self = Self::CorruptedSpirv("binary size is not a multiple of 4");
+    pub const MAGIC_MISMATCH: Self = Self::CorruptedSpirv("magic number mismatched");
+
     pub const INSTR_TOO_SHORT: Self = Self::CorruptedSpirv("instruction is too short");
+    pub const INSTR_TOO_LONG: Self = Self::CorruptedSpirv("instruction has more words than a word count can hold");
     pub const STR_NOT_TERMINATED: Self = Self::CorruptedSpirv("instruction has a string operand that is not terminated by nul");
     pub const UNENCODED_ENUM: Self = Self::CorruptedSpirv("instruction has a unencoded enumeration value");
 
@@ -17,13 +103,40 @@ impl Error {
     pub const NAME_COLLISION: Self = Self::CorruptedSpirv("item can only be named once");
     pub const DECO_COLLISION: Self = Self::CorruptedSpirv("item can only be decorated of a kind once");
     pub const MISSING_DECO: Self = Self::CorruptedSpirv("missing decoration");
+    pub const MISSING_MEMORY_MODEL: Self = Self::CorruptedSpirv("missing memory model");
     pub const TY_NOT_FOUND: Self = Self::CorruptedSpirv("cannot find a suitable type");
     pub const CONST_NOT_FOUND: Self = Self::CorruptedSpirv("cannot find a suitable constant");
+    pub const LOCATION_NOT_FOUND: Self = Self::CorruptedSpirv("cannot find a location for an interface variable");
     pub const UNDECLARED_VAR: Self = Self::CorruptedSpirv("accessing undeclared variable");
     pub const DESC_BIND_COLLISION: Self = Self::CorruptedSpirv("descriptor binding cannot be shared");
 
+    pub const UNSUPPORTED_VERSION: Self = Self::UnsupportedSpirv("unsupported spirv version");
     pub const UNSUPPORTED_TY: Self = Self::UnsupportedSpirv("unsupported type");
     pub const UNSUPPORTED_IMG_CFG: Self = Self::UnsupportedSpirv("unsupport image configuration");
+
+    /// Attach the instruction location to the error. Errors already located
+    /// are kept as-is.
+    pub(crate) fn at(self, loc: InstrLocation) -> Self {
+        match self {
+            Error::AtInstr(..) => self,
+            _ => Error::AtInstr(loc, Box::new(self)),
+        }
+    }
+    /// Get the underlying error without the instruction location.
+    pub fn kind(&self) -> &Error {
+        match self {
+            Error::AtInstr(_, err) => err.kind(),
+            _ => self,
+        }
+    }
+    /// Get the location of the instruction where the error occurred, if
+    /// known.
+    pub fn location(&self) -> Option<&InstrLocation> {
+        match self {
+            Error::AtInstr(loc, _) => Some(loc),
+            _ => None,
+        }
+    }
 }
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -31,7 +144,11 @@ impl fmt::Display for Error {
         match self {
             CorruptedSpirv(msg) => write!(f, "spirv binary is corrupted: {}", msg),
             UnsupportedSpirv(msg) => write!(f, "spirv binary used unsupported feature: {}", msg),
-            MismatchedManifest => write!(f, "mismatched manifest cannot be merged"),
+            MismatchedManifest(mismatch) => write!(f, "mismatched manifest cannot be merged: {}", mismatch),
+            MismatchedSpecValue(spec_id) => write!(f, "specialization value of spec id {} doesn't fit the constant type", spec_id),
+            InvalidAssembly(line, msg) => write!(f, "invalid spirv assembly at line {}: {}", line, msg),
+            InvalidSidecar(line, msg) => write!(f, "invalid name sidecar at line {}: {}", line, msg),
+            AtInstr(loc, err) => write!(f, "{} (at {})", err, loc),
         }
     }
 }
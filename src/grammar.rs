@@ -0,0 +1,1466 @@
+//! SPIR-V instruction grammar used to encode and decode instructions in their
+//! textual form.
+//!
+//! GENERATED by `tools/gen_grammar.py` from `spirv.core.grammar.json` of
+//! SPIR-V 1.6 revision 1, restricted to the instructions and enumerants
+//! defined by `spirv_headers` (SPIR-V 1.5 revision 3). DO NOT EDIT.
+use num_traits::FromPrimitive;
+use spirv_headers::Op;
+
+// Some kinds, like `Scope`, are only used as values of id operands.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum OperandKind {
+    ImageOperands,
+    FPFastMathMode,
+    SelectionControl,
+    LoopControl,
+    FunctionControl,
+    MemorySemantics,
+    MemoryAccess,
+    KernelProfilingInfo,
+    RayFlags,
+    SourceLanguage,
+    ExecutionModel,
+    AddressingModel,
+    MemoryModel,
+    ExecutionMode,
+    StorageClass,
+    Dim,
+    SamplerAddressingMode,
+    SamplerFilterMode,
+    ImageFormat,
+    ImageChannelOrder,
+    ImageChannelDataType,
+    FPRoundingMode,
+    LinkageType,
+    AccessQualifier,
+    FunctionParameterAttribute,
+    Decoration,
+    BuiltIn,
+    Scope,
+    GroupOperation,
+    KernelEnqueueFlags,
+    Capability,
+    RayQueryIntersection,
+    RayQueryCommittedIntersectionType,
+    RayQueryCandidateIntersectionType,
+    IdResultType,
+    IdResult,
+    IdMemorySemantics,
+    IdScope,
+    IdRef,
+    LiteralInteger,
+    LiteralString,
+    LiteralContextDependentNumber,
+    LiteralExtInstInteger,
+    LiteralSpecConstantOpInteger,
+    PairLiteralIntegerIdRef,
+    PairIdRefLiteralInteger,
+    PairIdRefIdRef,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Quantifier {
+    One,
+    Optional,
+    Variadic,
+}
+
+/// Kind and quantifier of an operand.
+pub(crate) type OperandGrammar = (OperandKind, Quantifier);
+
+pub(crate) struct InstrGrammar {
+    pub opcode: u32,
+    pub operands: &'static [OperandGrammar],
+}
+impl InstrGrammar {
+    /// Name of the instruction from `spirv_headers`, e.g. `OpTypeInt`.
+    pub fn name(&self) -> String { op_name(self.opcode) }
+}
+pub(crate) struct Enumerant {
+    pub name: &'static str,
+    pub value: u32,
+    pub params: &'static [OperandGrammar],
+}
+
+use OperandKind::*;
+use Quantifier::*;
+
+const INSTRS: &[InstrGrammar] = &[
+    InstrGrammar { opcode: 0, operands: &[] },
+    InstrGrammar { opcode: 1, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 2, operands: &[(LiteralString, One)] },
+    InstrGrammar { opcode: 3, operands: &[(SourceLanguage, One), (LiteralInteger, One), (IdRef, Optional), (LiteralString, Optional)] },
+    InstrGrammar { opcode: 4, operands: &[(LiteralString, One)] },
+    InstrGrammar { opcode: 5, operands: &[(IdRef, One), (LiteralString, One)] },
+    InstrGrammar { opcode: 6, operands: &[(IdRef, One), (LiteralInteger, One), (LiteralString, One)] },
+    InstrGrammar { opcode: 7, operands: &[(IdResult, One), (LiteralString, One)] },
+    InstrGrammar { opcode: 8, operands: &[(IdRef, One), (LiteralInteger, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 10, operands: &[(LiteralString, One)] },
+    InstrGrammar { opcode: 11, operands: &[(IdResult, One), (LiteralString, One)] },
+    InstrGrammar { opcode: 12, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (LiteralExtInstInteger, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 14, operands: &[(AddressingModel, One), (MemoryModel, One)] },
+    InstrGrammar { opcode: 15, operands: &[(ExecutionModel, One), (IdRef, One), (LiteralString, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 16, operands: &[(IdRef, One), (ExecutionMode, One)] },
+    InstrGrammar { opcode: 17, operands: &[(Capability, One)] },
+    InstrGrammar { opcode: 19, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 20, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 21, operands: &[(IdResult, One), (LiteralInteger, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 22, operands: &[(IdResult, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 23, operands: &[(IdResult, One), (IdRef, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 24, operands: &[(IdResult, One), (IdRef, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 25, operands: &[(IdResult, One), (IdRef, One), (Dim, One), (LiteralInteger, One), (LiteralInteger, One), (LiteralInteger, One), (LiteralInteger, One), (ImageFormat, One), (AccessQualifier, Optional)] },
+    InstrGrammar { opcode: 26, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 27, operands: &[(IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 28, operands: &[(IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 29, operands: &[(IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 30, operands: &[(IdResult, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 31, operands: &[(IdResult, One), (LiteralString, One)] },
+    InstrGrammar { opcode: 32, operands: &[(IdResult, One), (StorageClass, One), (IdRef, One)] },
+    InstrGrammar { opcode: 33, operands: &[(IdResult, One), (IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 34, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 35, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 36, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 37, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 38, operands: &[(IdResult, One), (AccessQualifier, One)] },
+    InstrGrammar { opcode: 39, operands: &[(IdRef, One), (StorageClass, One)] },
+    InstrGrammar { opcode: 41, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 42, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 43, operands: &[(IdResultType, One), (IdResult, One), (LiteralContextDependentNumber, One)] },
+    InstrGrammar { opcode: 44, operands: &[(IdResultType, One), (IdResult, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 45, operands: &[(IdResultType, One), (IdResult, One), (SamplerAddressingMode, One), (LiteralInteger, One), (SamplerFilterMode, One)] },
+    InstrGrammar { opcode: 46, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 48, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 49, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 50, operands: &[(IdResultType, One), (IdResult, One), (LiteralContextDependentNumber, One)] },
+    InstrGrammar { opcode: 51, operands: &[(IdResultType, One), (IdResult, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 52, operands: &[(IdResultType, One), (IdResult, One), (LiteralSpecConstantOpInteger, One)] },
+    InstrGrammar { opcode: 54, operands: &[(IdResultType, One), (IdResult, One), (FunctionControl, One), (IdRef, One)] },
+    InstrGrammar { opcode: 55, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 56, operands: &[] },
+    InstrGrammar { opcode: 57, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 59, operands: &[(IdResultType, One), (IdResult, One), (StorageClass, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 60, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 61, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (MemoryAccess, Optional)] },
+    InstrGrammar { opcode: 62, operands: &[(IdRef, One), (IdRef, One), (MemoryAccess, Optional)] },
+    InstrGrammar { opcode: 63, operands: &[(IdRef, One), (IdRef, One), (MemoryAccess, Optional), (MemoryAccess, Optional)] },
+    InstrGrammar { opcode: 64, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (MemoryAccess, Optional), (MemoryAccess, Optional)] },
+    InstrGrammar { opcode: 65, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 66, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 67, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 68, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 69, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 70, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 71, operands: &[(IdRef, One), (Decoration, One)] },
+    InstrGrammar { opcode: 72, operands: &[(IdRef, One), (LiteralInteger, One), (Decoration, One)] },
+    InstrGrammar { opcode: 73, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 74, operands: &[(IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 75, operands: &[(IdRef, One), (PairIdRefLiteralInteger, Variadic)] },
+    InstrGrammar { opcode: 77, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 78, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 79, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (LiteralInteger, Variadic)] },
+    InstrGrammar { opcode: 80, operands: &[(IdResultType, One), (IdResult, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 81, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (LiteralInteger, Variadic)] },
+    InstrGrammar { opcode: 82, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (LiteralInteger, Variadic)] },
+    InstrGrammar { opcode: 83, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 84, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 86, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 87, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 88, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 89, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 90, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 91, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 92, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 93, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 94, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 95, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 96, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 97, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 98, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 99, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 100, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 101, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 102, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 103, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 104, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 105, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 106, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 107, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 109, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 110, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 111, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 112, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 113, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 114, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 115, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 116, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 117, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 118, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 119, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 120, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 121, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 122, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 123, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (StorageClass, One)] },
+    InstrGrammar { opcode: 124, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 126, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 127, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 128, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 129, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 130, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 131, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 132, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 133, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 134, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 135, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 136, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 137, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 138, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 139, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 140, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 141, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 142, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 143, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 144, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 145, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 146, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 147, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 148, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 149, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 150, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 151, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 152, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 154, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 155, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 156, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 157, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 158, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 159, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 160, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 161, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 162, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 163, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 164, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 165, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 166, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 167, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 168, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 169, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 170, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 171, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 172, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 173, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 174, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 175, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 176, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 177, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 178, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 179, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 180, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 181, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 182, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 183, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 184, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 185, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 186, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 187, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 188, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 189, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 190, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 191, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 194, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 195, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 196, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 197, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 198, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 199, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 200, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 201, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 202, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 203, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 204, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 205, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 207, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 208, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 209, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 210, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 211, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 212, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 213, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 214, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 215, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 218, operands: &[] },
+    InstrGrammar { opcode: 219, operands: &[] },
+    InstrGrammar { opcode: 220, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 221, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 224, operands: &[(IdScope, One), (IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 225, operands: &[(IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 227, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 228, operands: &[(IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 229, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 230, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdMemorySemantics, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 231, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdMemorySemantics, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 232, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 233, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 234, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 235, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 236, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 237, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 238, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 239, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 240, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 241, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 242, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One), (IdRef, One)] },
+    InstrGrammar { opcode: 245, operands: &[(IdResultType, One), (IdResult, One), (PairIdRefIdRef, Variadic)] },
+    InstrGrammar { opcode: 246, operands: &[(IdRef, One), (IdRef, One), (LoopControl, One)] },
+    InstrGrammar { opcode: 247, operands: &[(IdRef, One), (SelectionControl, One)] },
+    InstrGrammar { opcode: 248, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 249, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 250, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (LiteralInteger, Variadic)] },
+    InstrGrammar { opcode: 251, operands: &[(IdRef, One), (IdRef, One), (PairLiteralIntegerIdRef, Variadic)] },
+    InstrGrammar { opcode: 252, operands: &[] },
+    InstrGrammar { opcode: 253, operands: &[] },
+    InstrGrammar { opcode: 254, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 255, operands: &[] },
+    InstrGrammar { opcode: 256, operands: &[(IdRef, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 257, operands: &[(IdRef, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 259, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 260, operands: &[(IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 261, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 262, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 263, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 264, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 265, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 266, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 267, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 268, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 269, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 270, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 271, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 274, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 275, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 276, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 277, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 278, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 279, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 280, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 281, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 282, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 283, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 284, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 285, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 286, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 287, operands: &[(IdScope, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 288, operands: &[(IdScope, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 291, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 292, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, Variadic)] },
+    InstrGrammar { opcode: 293, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 294, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 295, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 296, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 297, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 298, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 299, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 300, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 301, operands: &[(IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 302, operands: &[(IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 303, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 304, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 305, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 306, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 307, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 308, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 309, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 310, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 311, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 312, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, One)] },
+    InstrGrammar { opcode: 313, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 314, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 315, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 316, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 317, operands: &[] },
+    InstrGrammar { opcode: 318, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 319, operands: &[(IdRef, One), (IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 320, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 321, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 322, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 323, operands: &[(IdResultType, One), (IdResult, One), (LiteralInteger, One), (LiteralInteger, One), (LiteralInteger, One)] },
+    InstrGrammar { opcode: 324, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 325, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 326, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 327, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 328, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 329, operands: &[(IdRef, One), (IdScope, One), (IdMemorySemantics, One)] },
+    InstrGrammar { opcode: 330, operands: &[(LiteralString, One)] },
+    InstrGrammar { opcode: 331, operands: &[(IdRef, One), (ExecutionMode, One)] },
+    InstrGrammar { opcode: 332, operands: &[(IdRef, One), (Decoration, One)] },
+    InstrGrammar { opcode: 333, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One)] },
+    InstrGrammar { opcode: 334, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 335, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 336, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 337, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 338, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 339, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 340, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 341, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 342, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 343, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 344, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One)] },
+    InstrGrammar { opcode: 345, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 346, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 347, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 348, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 349, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 350, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 351, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 352, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 353, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 354, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 355, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 356, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 357, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 358, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 359, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 360, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 361, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 362, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 363, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 364, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One), (IdRef, Optional)] },
+    InstrGrammar { opcode: 365, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 366, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 400, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 401, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 402, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 403, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4421, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4422, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4428, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4429, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4430, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4432, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4472, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 4473, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4474, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 4475, operands: &[(IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4476, operands: &[(IdRef, One)] },
+    InstrGrammar { opcode: 4477, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 4479, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5000, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5001, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5002, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5003, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5004, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5005, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5006, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5007, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One), (GroupOperation, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5011, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5012, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5056, operands: &[(IdResultType, One), (IdResult, One), (IdScope, One)] },
+    InstrGrammar { opcode: 5283, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (ImageOperands, Optional)] },
+    InstrGrammar { opcode: 5296, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5299, operands: &[(IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5334, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5335, operands: &[] },
+    InstrGrammar { opcode: 5336, operands: &[] },
+    InstrGrammar { opcode: 5337, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5341, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5344, operands: &[(IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5358, operands: &[(IdResult, One), (IdRef, One), (IdScope, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5359, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (MemoryAccess, Optional)] },
+    InstrGrammar { opcode: 5360, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (MemoryAccess, Optional)] },
+    InstrGrammar { opcode: 5361, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5362, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5364, operands: &[] },
+    InstrGrammar { opcode: 5365, operands: &[] },
+    InstrGrammar { opcode: 5380, operands: &[] },
+    InstrGrammar { opcode: 5381, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 5571, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5572, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5573, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5574, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5575, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5576, operands: &[(IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5577, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5578, operands: &[(IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5580, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5581, operands: &[(IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5585, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5586, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5587, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5588, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5589, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5590, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5591, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5592, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5593, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5594, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5595, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5596, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5597, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5598, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5632, operands: &[(IdRef, One), (Decoration, One)] },
+    InstrGrammar { opcode: 5633, operands: &[(IdRef, One), (LiteralInteger, One), (Decoration, One)] },
+    InstrGrammar { opcode: 5699, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5700, operands: &[(IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5701, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5702, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5703, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5704, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5705, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5706, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5707, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5708, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5709, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5710, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5711, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5712, operands: &[(IdResult, One)] },
+    InstrGrammar { opcode: 5713, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5714, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5715, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5716, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5717, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5718, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5719, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5720, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5721, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 5722, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 5723, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 5724, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5725, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5726, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 5727, operands: &[(IdResultType, One), (IdResult, One)] },
+    InstrGrammar { opcode: 5728, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5729, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5730, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5731, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5732, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5733, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5734, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5735, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5736, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5737, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5738, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5739, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5740, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5741, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5742, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5743, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5744, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5745, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5746, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5747, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5748, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5749, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5750, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5751, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5752, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5753, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5754, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5755, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5756, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5757, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5758, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5759, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5760, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5761, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5762, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5763, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5764, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5765, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5766, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5767, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5768, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5769, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5770, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5771, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5772, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5773, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5774, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5775, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5776, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5777, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5778, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5779, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5780, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5781, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5782, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5783, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5784, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5785, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5786, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5787, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5788, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5789, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5790, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5791, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5792, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5793, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5794, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5795, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5796, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5797, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5798, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5799, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5800, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5801, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5802, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5803, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5804, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5805, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5806, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5807, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5808, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5809, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5810, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5811, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5812, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5813, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5814, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5815, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 5816, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6016, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6017, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6018, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6019, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6020, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6021, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6022, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6023, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6024, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6025, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6026, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6027, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6028, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6029, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6030, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6031, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+    InstrGrammar { opcode: 6032, operands: &[(IdResultType, One), (IdResult, One), (IdRef, One), (IdRef, One)] },
+];
+
+const IMAGE_OPERANDS: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "Bias", value: 0x0001, params: &[(IdRef, One)] },
+    Enumerant { name: "Lod", value: 0x0002, params: &[(IdRef, One)] },
+    Enumerant { name: "Grad", value: 0x0004, params: &[(IdRef, One), (IdRef, One)] },
+    Enumerant { name: "ConstOffset", value: 0x0008, params: &[(IdRef, One)] },
+    Enumerant { name: "Offset", value: 0x0010, params: &[(IdRef, One)] },
+    Enumerant { name: "ConstOffsets", value: 0x0020, params: &[(IdRef, One)] },
+    Enumerant { name: "Sample", value: 0x0040, params: &[(IdRef, One)] },
+    Enumerant { name: "MinLod", value: 0x0080, params: &[(IdRef, One)] },
+    Enumerant { name: "MakeTexelAvailable", value: 0x0100, params: &[(IdScope, One)] },
+    Enumerant { name: "MakeTexelAvailableKHR", value: 0x0100, params: &[(IdScope, One)] },
+    Enumerant { name: "MakeTexelVisible", value: 0x0200, params: &[(IdScope, One)] },
+    Enumerant { name: "MakeTexelVisibleKHR", value: 0x0200, params: &[(IdScope, One)] },
+    Enumerant { name: "NonPrivateTexel", value: 0x0400, params: &[] },
+    Enumerant { name: "NonPrivateTexelKHR", value: 0x0400, params: &[] },
+    Enumerant { name: "VolatileTexel", value: 0x0800, params: &[] },
+    Enumerant { name: "VolatileTexelKHR", value: 0x0800, params: &[] },
+    Enumerant { name: "SignExtend", value: 0x1000, params: &[] },
+    Enumerant { name: "ZeroExtend", value: 0x2000, params: &[] },
+];
+const FPFAST_MATH_MODE: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "NotNaN", value: 0x0001, params: &[] },
+    Enumerant { name: "NotInf", value: 0x0002, params: &[] },
+    Enumerant { name: "NSZ", value: 0x0004, params: &[] },
+    Enumerant { name: "AllowRecip", value: 0x0008, params: &[] },
+    Enumerant { name: "Fast", value: 0x0010, params: &[] },
+];
+const SELECTION_CONTROL: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "Flatten", value: 0x0001, params: &[] },
+    Enumerant { name: "DontFlatten", value: 0x0002, params: &[] },
+];
+const LOOP_CONTROL: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "Unroll", value: 0x0001, params: &[] },
+    Enumerant { name: "DontUnroll", value: 0x0002, params: &[] },
+    Enumerant { name: "DependencyInfinite", value: 0x0004, params: &[] },
+    Enumerant { name: "DependencyLength", value: 0x0008, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "MinIterations", value: 0x0010, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "MaxIterations", value: 0x0020, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "IterationMultiple", value: 0x0040, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "PeelCount", value: 0x0080, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "PartialCount", value: 0x0100, params: &[(LiteralInteger, One)] },
+];
+const FUNCTION_CONTROL: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "Inline", value: 0x0001, params: &[] },
+    Enumerant { name: "DontInline", value: 0x0002, params: &[] },
+    Enumerant { name: "Pure", value: 0x0004, params: &[] },
+    Enumerant { name: "Const", value: 0x0008, params: &[] },
+];
+const MEMORY_SEMANTICS: &[Enumerant] = &[
+    Enumerant { name: "Relaxed", value: 0x0000, params: &[] },
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "Acquire", value: 0x0002, params: &[] },
+    Enumerant { name: "Release", value: 0x0004, params: &[] },
+    Enumerant { name: "AcquireRelease", value: 0x0008, params: &[] },
+    Enumerant { name: "SequentiallyConsistent", value: 0x0010, params: &[] },
+    Enumerant { name: "UniformMemory", value: 0x0040, params: &[] },
+    Enumerant { name: "SubgroupMemory", value: 0x0080, params: &[] },
+    Enumerant { name: "WorkgroupMemory", value: 0x0100, params: &[] },
+    Enumerant { name: "CrossWorkgroupMemory", value: 0x0200, params: &[] },
+    Enumerant { name: "AtomicCounterMemory", value: 0x0400, params: &[] },
+    Enumerant { name: "ImageMemory", value: 0x0800, params: &[] },
+    Enumerant { name: "OutputMemory", value: 0x1000, params: &[] },
+    Enumerant { name: "OutputMemoryKHR", value: 0x1000, params: &[] },
+    Enumerant { name: "MakeAvailable", value: 0x2000, params: &[] },
+    Enumerant { name: "MakeAvailableKHR", value: 0x2000, params: &[] },
+    Enumerant { name: "MakeVisible", value: 0x4000, params: &[] },
+    Enumerant { name: "MakeVisibleKHR", value: 0x4000, params: &[] },
+    Enumerant { name: "Volatile", value: 0x8000, params: &[] },
+];
+const MEMORY_ACCESS: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "Volatile", value: 0x0001, params: &[] },
+    Enumerant { name: "Aligned", value: 0x0002, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Nontemporal", value: 0x0004, params: &[] },
+    Enumerant { name: "MakePointerAvailable", value: 0x0008, params: &[(IdScope, One)] },
+    Enumerant { name: "MakePointerAvailableKHR", value: 0x0008, params: &[(IdScope, One)] },
+    Enumerant { name: "MakePointerVisible", value: 0x0010, params: &[(IdScope, One)] },
+    Enumerant { name: "MakePointerVisibleKHR", value: 0x0010, params: &[(IdScope, One)] },
+    Enumerant { name: "NonPrivatePointer", value: 0x0020, params: &[] },
+    Enumerant { name: "NonPrivatePointerKHR", value: 0x0020, params: &[] },
+];
+const KERNEL_PROFILING_INFO: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0x0000, params: &[] },
+    Enumerant { name: "CmdExecTime", value: 0x0001, params: &[] },
+];
+const RAY_FLAGS: &[Enumerant] = &[
+    Enumerant { name: "NoneKHR", value: 0x0000, params: &[] },
+    Enumerant { name: "OpaqueKHR", value: 0x0001, params: &[] },
+    Enumerant { name: "NoOpaqueKHR", value: 0x0002, params: &[] },
+    Enumerant { name: "TerminateOnFirstHitKHR", value: 0x0004, params: &[] },
+    Enumerant { name: "SkipClosestHitShaderKHR", value: 0x0008, params: &[] },
+    Enumerant { name: "CullBackFacingTrianglesKHR", value: 0x0010, params: &[] },
+    Enumerant { name: "CullFrontFacingTrianglesKHR", value: 0x0020, params: &[] },
+    Enumerant { name: "CullOpaqueKHR", value: 0x0040, params: &[] },
+    Enumerant { name: "CullNoOpaqueKHR", value: 0x0080, params: &[] },
+    Enumerant { name: "SkipTrianglesKHR", value: 0x0100, params: &[] },
+    Enumerant { name: "SkipAABBsKHR", value: 0x0200, params: &[] },
+];
+const SOURCE_LANGUAGE: &[Enumerant] = &[
+    Enumerant { name: "Unknown", value: 0, params: &[] },
+    Enumerant { name: "ESSL", value: 1, params: &[] },
+    Enumerant { name: "GLSL", value: 2, params: &[] },
+    Enumerant { name: "OpenCL_C", value: 3, params: &[] },
+    Enumerant { name: "OpenCL_CPP", value: 4, params: &[] },
+    Enumerant { name: "HLSL", value: 5, params: &[] },
+];
+const EXECUTION_MODEL: &[Enumerant] = &[
+    Enumerant { name: "Vertex", value: 0, params: &[] },
+    Enumerant { name: "TessellationControl", value: 1, params: &[] },
+    Enumerant { name: "TessellationEvaluation", value: 2, params: &[] },
+    Enumerant { name: "Geometry", value: 3, params: &[] },
+    Enumerant { name: "Fragment", value: 4, params: &[] },
+    Enumerant { name: "GLCompute", value: 5, params: &[] },
+    Enumerant { name: "Kernel", value: 6, params: &[] },
+    Enumerant { name: "TaskNV", value: 5267, params: &[] },
+    Enumerant { name: "MeshNV", value: 5268, params: &[] },
+    Enumerant { name: "RayGenerationNV", value: 5313, params: &[] },
+    Enumerant { name: "RayGenerationKHR", value: 5313, params: &[] },
+    Enumerant { name: "IntersectionNV", value: 5314, params: &[] },
+    Enumerant { name: "IntersectionKHR", value: 5314, params: &[] },
+    Enumerant { name: "AnyHitNV", value: 5315, params: &[] },
+    Enumerant { name: "AnyHitKHR", value: 5315, params: &[] },
+    Enumerant { name: "ClosestHitNV", value: 5316, params: &[] },
+    Enumerant { name: "ClosestHitKHR", value: 5316, params: &[] },
+    Enumerant { name: "MissNV", value: 5317, params: &[] },
+    Enumerant { name: "MissKHR", value: 5317, params: &[] },
+    Enumerant { name: "CallableNV", value: 5318, params: &[] },
+    Enumerant { name: "CallableKHR", value: 5318, params: &[] },
+];
+const ADDRESSING_MODEL: &[Enumerant] = &[
+    Enumerant { name: "Logical", value: 0, params: &[] },
+    Enumerant { name: "Physical32", value: 1, params: &[] },
+    Enumerant { name: "Physical64", value: 2, params: &[] },
+    Enumerant { name: "PhysicalStorageBuffer64", value: 5348, params: &[] },
+    Enumerant { name: "PhysicalStorageBuffer64EXT", value: 5348, params: &[] },
+];
+const MEMORY_MODEL: &[Enumerant] = &[
+    Enumerant { name: "Simple", value: 0, params: &[] },
+    Enumerant { name: "GLSL450", value: 1, params: &[] },
+    Enumerant { name: "OpenCL", value: 2, params: &[] },
+    Enumerant { name: "Vulkan", value: 3, params: &[] },
+    Enumerant { name: "VulkanKHR", value: 3, params: &[] },
+];
+const EXECUTION_MODE: &[Enumerant] = &[
+    Enumerant { name: "Invocations", value: 0, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "SpacingEqual", value: 1, params: &[] },
+    Enumerant { name: "SpacingFractionalEven", value: 2, params: &[] },
+    Enumerant { name: "SpacingFractionalOdd", value: 3, params: &[] },
+    Enumerant { name: "VertexOrderCw", value: 4, params: &[] },
+    Enumerant { name: "VertexOrderCcw", value: 5, params: &[] },
+    Enumerant { name: "PixelCenterInteger", value: 6, params: &[] },
+    Enumerant { name: "OriginUpperLeft", value: 7, params: &[] },
+    Enumerant { name: "OriginLowerLeft", value: 8, params: &[] },
+    Enumerant { name: "EarlyFragmentTests", value: 9, params: &[] },
+    Enumerant { name: "PointMode", value: 10, params: &[] },
+    Enumerant { name: "Xfb", value: 11, params: &[] },
+    Enumerant { name: "DepthReplacing", value: 12, params: &[] },
+    Enumerant { name: "DepthGreater", value: 14, params: &[] },
+    Enumerant { name: "DepthLess", value: 15, params: &[] },
+    Enumerant { name: "DepthUnchanged", value: 16, params: &[] },
+    Enumerant { name: "LocalSize", value: 17, params: &[(LiteralInteger, One), (LiteralInteger, One), (LiteralInteger, One)] },
+    Enumerant { name: "LocalSizeHint", value: 18, params: &[(LiteralInteger, One), (LiteralInteger, One), (LiteralInteger, One)] },
+    Enumerant { name: "InputPoints", value: 19, params: &[] },
+    Enumerant { name: "InputLines", value: 20, params: &[] },
+    Enumerant { name: "InputLinesAdjacency", value: 21, params: &[] },
+    Enumerant { name: "Triangles", value: 22, params: &[] },
+    Enumerant { name: "InputTrianglesAdjacency", value: 23, params: &[] },
+    Enumerant { name: "Quads", value: 24, params: &[] },
+    Enumerant { name: "Isolines", value: 25, params: &[] },
+    Enumerant { name: "OutputVertices", value: 26, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "OutputPoints", value: 27, params: &[] },
+    Enumerant { name: "OutputLineStrip", value: 28, params: &[] },
+    Enumerant { name: "OutputTriangleStrip", value: 29, params: &[] },
+    Enumerant { name: "VecTypeHint", value: 30, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "ContractionOff", value: 31, params: &[] },
+    Enumerant { name: "Initializer", value: 33, params: &[] },
+    Enumerant { name: "Finalizer", value: 34, params: &[] },
+    Enumerant { name: "SubgroupSize", value: 35, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "SubgroupsPerWorkgroup", value: 36, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "SubgroupsPerWorkgroupId", value: 37, params: &[(IdRef, One)] },
+    Enumerant { name: "LocalSizeId", value: 38, params: &[(IdRef, One), (IdRef, One), (IdRef, One)] },
+    Enumerant { name: "LocalSizeHintId", value: 39, params: &[(IdRef, One), (IdRef, One), (IdRef, One)] },
+    Enumerant { name: "PostDepthCoverage", value: 4446, params: &[] },
+    Enumerant { name: "DenormPreserve", value: 4459, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "DenormFlushToZero", value: 4460, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "SignedZeroInfNanPreserve", value: 4461, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "RoundingModeRTE", value: 4462, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "RoundingModeRTZ", value: 4463, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "StencilRefReplacingEXT", value: 5027, params: &[] },
+    Enumerant { name: "OutputLinesNV", value: 5269, params: &[] },
+    Enumerant { name: "OutputLinesEXT", value: 5269, params: &[] },
+    Enumerant { name: "OutputPrimitivesNV", value: 5270, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "OutputPrimitivesEXT", value: 5270, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "DerivativeGroupQuadsNV", value: 5289, params: &[] },
+    Enumerant { name: "DerivativeGroupLinearNV", value: 5290, params: &[] },
+    Enumerant { name: "OutputTrianglesNV", value: 5298, params: &[] },
+    Enumerant { name: "OutputTrianglesEXT", value: 5298, params: &[] },
+    Enumerant { name: "PixelInterlockOrderedEXT", value: 5366, params: &[] },
+    Enumerant { name: "PixelInterlockUnorderedEXT", value: 5367, params: &[] },
+    Enumerant { name: "SampleInterlockOrderedEXT", value: 5368, params: &[] },
+    Enumerant { name: "SampleInterlockUnorderedEXT", value: 5369, params: &[] },
+    Enumerant { name: "ShadingRateInterlockOrderedEXT", value: 5370, params: &[] },
+    Enumerant { name: "ShadingRateInterlockUnorderedEXT", value: 5371, params: &[] },
+];
+const STORAGE_CLASS: &[Enumerant] = &[
+    Enumerant { name: "UniformConstant", value: 0, params: &[] },
+    Enumerant { name: "Input", value: 1, params: &[] },
+    Enumerant { name: "Uniform", value: 2, params: &[] },
+    Enumerant { name: "Output", value: 3, params: &[] },
+    Enumerant { name: "Workgroup", value: 4, params: &[] },
+    Enumerant { name: "CrossWorkgroup", value: 5, params: &[] },
+    Enumerant { name: "Private", value: 6, params: &[] },
+    Enumerant { name: "Function", value: 7, params: &[] },
+    Enumerant { name: "Generic", value: 8, params: &[] },
+    Enumerant { name: "PushConstant", value: 9, params: &[] },
+    Enumerant { name: "AtomicCounter", value: 10, params: &[] },
+    Enumerant { name: "Image", value: 11, params: &[] },
+    Enumerant { name: "StorageBuffer", value: 12, params: &[] },
+    Enumerant { name: "CallableDataNV", value: 5328, params: &[] },
+    Enumerant { name: "CallableDataKHR", value: 5328, params: &[] },
+    Enumerant { name: "IncomingCallableDataNV", value: 5329, params: &[] },
+    Enumerant { name: "IncomingCallableDataKHR", value: 5329, params: &[] },
+    Enumerant { name: "RayPayloadNV", value: 5338, params: &[] },
+    Enumerant { name: "RayPayloadKHR", value: 5338, params: &[] },
+    Enumerant { name: "HitAttributeNV", value: 5339, params: &[] },
+    Enumerant { name: "HitAttributeKHR", value: 5339, params: &[] },
+    Enumerant { name: "IncomingRayPayloadNV", value: 5342, params: &[] },
+    Enumerant { name: "IncomingRayPayloadKHR", value: 5342, params: &[] },
+    Enumerant { name: "ShaderRecordBufferNV", value: 5343, params: &[] },
+    Enumerant { name: "ShaderRecordBufferKHR", value: 5343, params: &[] },
+    Enumerant { name: "PhysicalStorageBuffer", value: 5349, params: &[] },
+    Enumerant { name: "PhysicalStorageBufferEXT", value: 5349, params: &[] },
+];
+const DIM: &[Enumerant] = &[
+    Enumerant { name: "1D", value: 0, params: &[] },
+    Enumerant { name: "2D", value: 1, params: &[] },
+    Enumerant { name: "3D", value: 2, params: &[] },
+    Enumerant { name: "Cube", value: 3, params: &[] },
+    Enumerant { name: "Rect", value: 4, params: &[] },
+    Enumerant { name: "Buffer", value: 5, params: &[] },
+    Enumerant { name: "SubpassData", value: 6, params: &[] },
+];
+const SAMPLER_ADDRESSING_MODE: &[Enumerant] = &[
+    Enumerant { name: "None", value: 0, params: &[] },
+    Enumerant { name: "ClampToEdge", value: 1, params: &[] },
+    Enumerant { name: "Clamp", value: 2, params: &[] },
+    Enumerant { name: "Repeat", value: 3, params: &[] },
+    Enumerant { name: "RepeatMirrored", value: 4, params: &[] },
+];
+const SAMPLER_FILTER_MODE: &[Enumerant] = &[
+    Enumerant { name: "Nearest", value: 0, params: &[] },
+    Enumerant { name: "Linear", value: 1, params: &[] },
+];
+const IMAGE_FORMAT: &[Enumerant] = &[
+    Enumerant { name: "Unknown", value: 0, params: &[] },
+    Enumerant { name: "Rgba32f", value: 1, params: &[] },
+    Enumerant { name: "Rgba16f", value: 2, params: &[] },
+    Enumerant { name: "R32f", value: 3, params: &[] },
+    Enumerant { name: "Rgba8", value: 4, params: &[] },
+    Enumerant { name: "Rgba8Snorm", value: 5, params: &[] },
+    Enumerant { name: "Rg32f", value: 6, params: &[] },
+    Enumerant { name: "Rg16f", value: 7, params: &[] },
+    Enumerant { name: "R11fG11fB10f", value: 8, params: &[] },
+    Enumerant { name: "R16f", value: 9, params: &[] },
+    Enumerant { name: "Rgba16", value: 10, params: &[] },
+    Enumerant { name: "Rgb10A2", value: 11, params: &[] },
+    Enumerant { name: "Rg16", value: 12, params: &[] },
+    Enumerant { name: "Rg8", value: 13, params: &[] },
+    Enumerant { name: "R16", value: 14, params: &[] },
+    Enumerant { name: "R8", value: 15, params: &[] },
+    Enumerant { name: "Rgba16Snorm", value: 16, params: &[] },
+    Enumerant { name: "Rg16Snorm", value: 17, params: &[] },
+    Enumerant { name: "Rg8Snorm", value: 18, params: &[] },
+    Enumerant { name: "R16Snorm", value: 19, params: &[] },
+    Enumerant { name: "R8Snorm", value: 20, params: &[] },
+    Enumerant { name: "Rgba32i", value: 21, params: &[] },
+    Enumerant { name: "Rgba16i", value: 22, params: &[] },
+    Enumerant { name: "Rgba8i", value: 23, params: &[] },
+    Enumerant { name: "R32i", value: 24, params: &[] },
+    Enumerant { name: "Rg32i", value: 25, params: &[] },
+    Enumerant { name: "Rg16i", value: 26, params: &[] },
+    Enumerant { name: "Rg8i", value: 27, params: &[] },
+    Enumerant { name: "R16i", value: 28, params: &[] },
+    Enumerant { name: "R8i", value: 29, params: &[] },
+    Enumerant { name: "Rgba32ui", value: 30, params: &[] },
+    Enumerant { name: "Rgba16ui", value: 31, params: &[] },
+    Enumerant { name: "Rgba8ui", value: 32, params: &[] },
+    Enumerant { name: "R32ui", value: 33, params: &[] },
+    Enumerant { name: "Rgb10a2ui", value: 34, params: &[] },
+    Enumerant { name: "Rg32ui", value: 35, params: &[] },
+    Enumerant { name: "Rg16ui", value: 36, params: &[] },
+    Enumerant { name: "Rg8ui", value: 37, params: &[] },
+    Enumerant { name: "R16ui", value: 38, params: &[] },
+    Enumerant { name: "R8ui", value: 39, params: &[] },
+];
+const IMAGE_CHANNEL_ORDER: &[Enumerant] = &[
+    Enumerant { name: "R", value: 0, params: &[] },
+    Enumerant { name: "A", value: 1, params: &[] },
+    Enumerant { name: "RG", value: 2, params: &[] },
+    Enumerant { name: "RA", value: 3, params: &[] },
+    Enumerant { name: "RGB", value: 4, params: &[] },
+    Enumerant { name: "RGBA", value: 5, params: &[] },
+    Enumerant { name: "BGRA", value: 6, params: &[] },
+    Enumerant { name: "ARGB", value: 7, params: &[] },
+    Enumerant { name: "Intensity", value: 8, params: &[] },
+    Enumerant { name: "Luminance", value: 9, params: &[] },
+    Enumerant { name: "Rx", value: 10, params: &[] },
+    Enumerant { name: "RGx", value: 11, params: &[] },
+    Enumerant { name: "RGBx", value: 12, params: &[] },
+    Enumerant { name: "Depth", value: 13, params: &[] },
+    Enumerant { name: "DepthStencil", value: 14, params: &[] },
+    Enumerant { name: "sRGB", value: 15, params: &[] },
+    Enumerant { name: "sRGBx", value: 16, params: &[] },
+    Enumerant { name: "sRGBA", value: 17, params: &[] },
+    Enumerant { name: "sBGRA", value: 18, params: &[] },
+    Enumerant { name: "ABGR", value: 19, params: &[] },
+];
+const IMAGE_CHANNEL_DATA_TYPE: &[Enumerant] = &[
+    Enumerant { name: "SnormInt8", value: 0, params: &[] },
+    Enumerant { name: "SnormInt16", value: 1, params: &[] },
+    Enumerant { name: "UnormInt8", value: 2, params: &[] },
+    Enumerant { name: "UnormInt16", value: 3, params: &[] },
+    Enumerant { name: "UnormShort565", value: 4, params: &[] },
+    Enumerant { name: "UnormShort555", value: 5, params: &[] },
+    Enumerant { name: "UnormInt101010", value: 6, params: &[] },
+    Enumerant { name: "SignedInt8", value: 7, params: &[] },
+    Enumerant { name: "SignedInt16", value: 8, params: &[] },
+    Enumerant { name: "SignedInt32", value: 9, params: &[] },
+    Enumerant { name: "UnsignedInt8", value: 10, params: &[] },
+    Enumerant { name: "UnsignedInt16", value: 11, params: &[] },
+    Enumerant { name: "UnsignedInt32", value: 12, params: &[] },
+    Enumerant { name: "HalfFloat", value: 13, params: &[] },
+    Enumerant { name: "Float", value: 14, params: &[] },
+    Enumerant { name: "UnormInt24", value: 15, params: &[] },
+    Enumerant { name: "UnormInt101010_2", value: 16, params: &[] },
+];
+const FPROUNDING_MODE: &[Enumerant] = &[
+    Enumerant { name: "RTE", value: 0, params: &[] },
+    Enumerant { name: "RTZ", value: 1, params: &[] },
+    Enumerant { name: "RTP", value: 2, params: &[] },
+    Enumerant { name: "RTN", value: 3, params: &[] },
+];
+const LINKAGE_TYPE: &[Enumerant] = &[
+    Enumerant { name: "Export", value: 0, params: &[] },
+    Enumerant { name: "Import", value: 1, params: &[] },
+];
+const ACCESS_QUALIFIER: &[Enumerant] = &[
+    Enumerant { name: "ReadOnly", value: 0, params: &[] },
+    Enumerant { name: "WriteOnly", value: 1, params: &[] },
+    Enumerant { name: "ReadWrite", value: 2, params: &[] },
+];
+const FUNCTION_PARAMETER_ATTRIBUTE: &[Enumerant] = &[
+    Enumerant { name: "Zext", value: 0, params: &[] },
+    Enumerant { name: "Sext", value: 1, params: &[] },
+    Enumerant { name: "ByVal", value: 2, params: &[] },
+    Enumerant { name: "Sret", value: 3, params: &[] },
+    Enumerant { name: "NoAlias", value: 4, params: &[] },
+    Enumerant { name: "NoCapture", value: 5, params: &[] },
+    Enumerant { name: "NoWrite", value: 6, params: &[] },
+    Enumerant { name: "NoReadWrite", value: 7, params: &[] },
+];
+const DECORATION: &[Enumerant] = &[
+    Enumerant { name: "RelaxedPrecision", value: 0, params: &[] },
+    Enumerant { name: "SpecId", value: 1, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Block", value: 2, params: &[] },
+    Enumerant { name: "BufferBlock", value: 3, params: &[] },
+    Enumerant { name: "RowMajor", value: 4, params: &[] },
+    Enumerant { name: "ColMajor", value: 5, params: &[] },
+    Enumerant { name: "ArrayStride", value: 6, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "MatrixStride", value: 7, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "GLSLShared", value: 8, params: &[] },
+    Enumerant { name: "GLSLPacked", value: 9, params: &[] },
+    Enumerant { name: "CPacked", value: 10, params: &[] },
+    Enumerant { name: "BuiltIn", value: 11, params: &[(BuiltIn, One)] },
+    Enumerant { name: "NoPerspective", value: 13, params: &[] },
+    Enumerant { name: "Flat", value: 14, params: &[] },
+    Enumerant { name: "Patch", value: 15, params: &[] },
+    Enumerant { name: "Centroid", value: 16, params: &[] },
+    Enumerant { name: "Sample", value: 17, params: &[] },
+    Enumerant { name: "Invariant", value: 18, params: &[] },
+    Enumerant { name: "Restrict", value: 19, params: &[] },
+    Enumerant { name: "Aliased", value: 20, params: &[] },
+    Enumerant { name: "Volatile", value: 21, params: &[] },
+    Enumerant { name: "Constant", value: 22, params: &[] },
+    Enumerant { name: "Coherent", value: 23, params: &[] },
+    Enumerant { name: "NonWritable", value: 24, params: &[] },
+    Enumerant { name: "NonReadable", value: 25, params: &[] },
+    Enumerant { name: "Uniform", value: 26, params: &[] },
+    Enumerant { name: "UniformId", value: 27, params: &[(IdScope, One)] },
+    Enumerant { name: "SaturatedConversion", value: 28, params: &[] },
+    Enumerant { name: "Stream", value: 29, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Location", value: 30, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Component", value: 31, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Index", value: 32, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Binding", value: 33, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "DescriptorSet", value: 34, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Offset", value: 35, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "XfbBuffer", value: 36, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "XfbStride", value: 37, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "FuncParamAttr", value: 38, params: &[(FunctionParameterAttribute, One)] },
+    Enumerant { name: "FPRoundingMode", value: 39, params: &[(FPRoundingMode, One)] },
+    Enumerant { name: "FPFastMathMode", value: 40, params: &[(FPFastMathMode, One)] },
+    Enumerant { name: "LinkageAttributes", value: 41, params: &[(LiteralString, One), (LinkageType, One)] },
+    Enumerant { name: "NoContraction", value: 42, params: &[] },
+    Enumerant { name: "InputAttachmentIndex", value: 43, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "Alignment", value: 44, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "MaxByteOffset", value: 45, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "AlignmentId", value: 46, params: &[(IdRef, One)] },
+    Enumerant { name: "MaxByteOffsetId", value: 47, params: &[(IdRef, One)] },
+    Enumerant { name: "NoSignedWrap", value: 4469, params: &[] },
+    Enumerant { name: "NoUnsignedWrap", value: 4470, params: &[] },
+    Enumerant { name: "ExplicitInterpAMD", value: 4999, params: &[] },
+    Enumerant { name: "OverrideCoverageNV", value: 5248, params: &[] },
+    Enumerant { name: "PassthroughNV", value: 5250, params: &[] },
+    Enumerant { name: "ViewportRelativeNV", value: 5252, params: &[] },
+    Enumerant { name: "SecondaryViewportRelativeNV", value: 5256, params: &[(LiteralInteger, One)] },
+    Enumerant { name: "PerPrimitiveNV", value: 5271, params: &[] },
+    Enumerant { name: "PerPrimitiveEXT", value: 5271, params: &[] },
+    Enumerant { name: "PerViewNV", value: 5272, params: &[] },
+    Enumerant { name: "PerTaskNV", value: 5273, params: &[] },
+    Enumerant { name: "PerVertexKHR", value: 5285, params: &[] },
+    Enumerant { name: "PerVertexNV", value: 5285, params: &[] },
+    Enumerant { name: "NonUniform", value: 5300, params: &[] },
+    Enumerant { name: "NonUniformEXT", value: 5300, params: &[] },
+    Enumerant { name: "RestrictPointer", value: 5355, params: &[] },
+    Enumerant { name: "RestrictPointerEXT", value: 5355, params: &[] },
+    Enumerant { name: "AliasedPointer", value: 5356, params: &[] },
+    Enumerant { name: "AliasedPointerEXT", value: 5356, params: &[] },
+    Enumerant { name: "CounterBuffer", value: 5634, params: &[(IdRef, One)] },
+    Enumerant { name: "HlslCounterBufferGOOGLE", value: 5634, params: &[(IdRef, One)] },
+    Enumerant { name: "UserSemantic", value: 5635, params: &[(LiteralString, One)] },
+    Enumerant { name: "HlslSemanticGOOGLE", value: 5635, params: &[(LiteralString, One)] },
+    Enumerant { name: "UserTypeGOOGLE", value: 5636, params: &[(LiteralString, One)] },
+];
+const BUILT_IN: &[Enumerant] = &[
+    Enumerant { name: "Position", value: 0, params: &[] },
+    Enumerant { name: "PointSize", value: 1, params: &[] },
+    Enumerant { name: "ClipDistance", value: 3, params: &[] },
+    Enumerant { name: "CullDistance", value: 4, params: &[] },
+    Enumerant { name: "VertexId", value: 5, params: &[] },
+    Enumerant { name: "InstanceId", value: 6, params: &[] },
+    Enumerant { name: "PrimitiveId", value: 7, params: &[] },
+    Enumerant { name: "InvocationId", value: 8, params: &[] },
+    Enumerant { name: "Layer", value: 9, params: &[] },
+    Enumerant { name: "ViewportIndex", value: 10, params: &[] },
+    Enumerant { name: "TessLevelOuter", value: 11, params: &[] },
+    Enumerant { name: "TessLevelInner", value: 12, params: &[] },
+    Enumerant { name: "TessCoord", value: 13, params: &[] },
+    Enumerant { name: "PatchVertices", value: 14, params: &[] },
+    Enumerant { name: "FragCoord", value: 15, params: &[] },
+    Enumerant { name: "PointCoord", value: 16, params: &[] },
+    Enumerant { name: "FrontFacing", value: 17, params: &[] },
+    Enumerant { name: "SampleId", value: 18, params: &[] },
+    Enumerant { name: "SamplePosition", value: 19, params: &[] },
+    Enumerant { name: "SampleMask", value: 20, params: &[] },
+    Enumerant { name: "FragDepth", value: 22, params: &[] },
+    Enumerant { name: "HelperInvocation", value: 23, params: &[] },
+    Enumerant { name: "NumWorkgroups", value: 24, params: &[] },
+    Enumerant { name: "WorkgroupSize", value: 25, params: &[] },
+    Enumerant { name: "WorkgroupId", value: 26, params: &[] },
+    Enumerant { name: "LocalInvocationId", value: 27, params: &[] },
+    Enumerant { name: "GlobalInvocationId", value: 28, params: &[] },
+    Enumerant { name: "LocalInvocationIndex", value: 29, params: &[] },
+    Enumerant { name: "WorkDim", value: 30, params: &[] },
+    Enumerant { name: "GlobalSize", value: 31, params: &[] },
+    Enumerant { name: "EnqueuedWorkgroupSize", value: 32, params: &[] },
+    Enumerant { name: "GlobalOffset", value: 33, params: &[] },
+    Enumerant { name: "GlobalLinearId", value: 34, params: &[] },
+    Enumerant { name: "SubgroupSize", value: 36, params: &[] },
+    Enumerant { name: "SubgroupMaxSize", value: 37, params: &[] },
+    Enumerant { name: "NumSubgroups", value: 38, params: &[] },
+    Enumerant { name: "NumEnqueuedSubgroups", value: 39, params: &[] },
+    Enumerant { name: "SubgroupId", value: 40, params: &[] },
+    Enumerant { name: "SubgroupLocalInvocationId", value: 41, params: &[] },
+    Enumerant { name: "VertexIndex", value: 42, params: &[] },
+    Enumerant { name: "InstanceIndex", value: 43, params: &[] },
+    Enumerant { name: "SubgroupEqMask", value: 4416, params: &[] },
+    Enumerant { name: "SubgroupEqMaskKHR", value: 4416, params: &[] },
+    Enumerant { name: "SubgroupGeMask", value: 4417, params: &[] },
+    Enumerant { name: "SubgroupGeMaskKHR", value: 4417, params: &[] },
+    Enumerant { name: "SubgroupGtMask", value: 4418, params: &[] },
+    Enumerant { name: "SubgroupGtMaskKHR", value: 4418, params: &[] },
+    Enumerant { name: "SubgroupLeMask", value: 4419, params: &[] },
+    Enumerant { name: "SubgroupLeMaskKHR", value: 4419, params: &[] },
+    Enumerant { name: "SubgroupLtMask", value: 4420, params: &[] },
+    Enumerant { name: "SubgroupLtMaskKHR", value: 4420, params: &[] },
+    Enumerant { name: "BaseVertex", value: 4424, params: &[] },
+    Enumerant { name: "BaseInstance", value: 4425, params: &[] },
+    Enumerant { name: "DrawIndex", value: 4426, params: &[] },
+    Enumerant { name: "DeviceIndex", value: 4438, params: &[] },
+    Enumerant { name: "ViewIndex", value: 4440, params: &[] },
+    Enumerant { name: "BaryCoordNoPerspAMD", value: 4992, params: &[] },
+    Enumerant { name: "BaryCoordNoPerspCentroidAMD", value: 4993, params: &[] },
+    Enumerant { name: "BaryCoordNoPerspSampleAMD", value: 4994, params: &[] },
+    Enumerant { name: "BaryCoordSmoothAMD", value: 4995, params: &[] },
+    Enumerant { name: "BaryCoordSmoothCentroidAMD", value: 4996, params: &[] },
+    Enumerant { name: "BaryCoordSmoothSampleAMD", value: 4997, params: &[] },
+    Enumerant { name: "BaryCoordPullModelAMD", value: 4998, params: &[] },
+    Enumerant { name: "FragStencilRefEXT", value: 5014, params: &[] },
+    Enumerant { name: "ViewportMaskNV", value: 5253, params: &[] },
+    Enumerant { name: "SecondaryPositionNV", value: 5257, params: &[] },
+    Enumerant { name: "SecondaryViewportMaskNV", value: 5258, params: &[] },
+    Enumerant { name: "PositionPerViewNV", value: 5261, params: &[] },
+    Enumerant { name: "ViewportMaskPerViewNV", value: 5262, params: &[] },
+    Enumerant { name: "FullyCoveredEXT", value: 5264, params: &[] },
+    Enumerant { name: "TaskCountNV", value: 5274, params: &[] },
+    Enumerant { name: "PrimitiveCountNV", value: 5275, params: &[] },
+    Enumerant { name: "PrimitiveIndicesNV", value: 5276, params: &[] },
+    Enumerant { name: "ClipDistancePerViewNV", value: 5277, params: &[] },
+    Enumerant { name: "CullDistancePerViewNV", value: 5278, params: &[] },
+    Enumerant { name: "LayerPerViewNV", value: 5279, params: &[] },
+    Enumerant { name: "MeshViewCountNV", value: 5280, params: &[] },
+    Enumerant { name: "MeshViewIndicesNV", value: 5281, params: &[] },
+    Enumerant { name: "BaryCoordKHR", value: 5286, params: &[] },
+    Enumerant { name: "BaryCoordNV", value: 5286, params: &[] },
+    Enumerant { name: "BaryCoordNoPerspKHR", value: 5287, params: &[] },
+    Enumerant { name: "BaryCoordNoPerspNV", value: 5287, params: &[] },
+    Enumerant { name: "FragSizeEXT", value: 5292, params: &[] },
+    Enumerant { name: "FragmentSizeNV", value: 5292, params: &[] },
+    Enumerant { name: "FragInvocationCountEXT", value: 5293, params: &[] },
+    Enumerant { name: "InvocationsPerPixelNV", value: 5293, params: &[] },
+    Enumerant { name: "LaunchIdNV", value: 5319, params: &[] },
+    Enumerant { name: "LaunchIdKHR", value: 5319, params: &[] },
+    Enumerant { name: "LaunchSizeNV", value: 5320, params: &[] },
+    Enumerant { name: "LaunchSizeKHR", value: 5320, params: &[] },
+    Enumerant { name: "WorldRayOriginNV", value: 5321, params: &[] },
+    Enumerant { name: "WorldRayOriginKHR", value: 5321, params: &[] },
+    Enumerant { name: "WorldRayDirectionNV", value: 5322, params: &[] },
+    Enumerant { name: "WorldRayDirectionKHR", value: 5322, params: &[] },
+    Enumerant { name: "ObjectRayOriginNV", value: 5323, params: &[] },
+    Enumerant { name: "ObjectRayOriginKHR", value: 5323, params: &[] },
+    Enumerant { name: "ObjectRayDirectionNV", value: 5324, params: &[] },
+    Enumerant { name: "ObjectRayDirectionKHR", value: 5324, params: &[] },
+    Enumerant { name: "RayTminNV", value: 5325, params: &[] },
+    Enumerant { name: "RayTminKHR", value: 5325, params: &[] },
+    Enumerant { name: "RayTmaxNV", value: 5326, params: &[] },
+    Enumerant { name: "RayTmaxKHR", value: 5326, params: &[] },
+    Enumerant { name: "InstanceCustomIndexNV", value: 5327, params: &[] },
+    Enumerant { name: "InstanceCustomIndexKHR", value: 5327, params: &[] },
+    Enumerant { name: "ObjectToWorldNV", value: 5330, params: &[] },
+    Enumerant { name: "ObjectToWorldKHR", value: 5330, params: &[] },
+    Enumerant { name: "WorldToObjectNV", value: 5331, params: &[] },
+    Enumerant { name: "WorldToObjectKHR", value: 5331, params: &[] },
+    Enumerant { name: "HitTNV", value: 5332, params: &[] },
+    Enumerant { name: "HitKindNV", value: 5333, params: &[] },
+    Enumerant { name: "HitKindKHR", value: 5333, params: &[] },
+    Enumerant { name: "IncomingRayFlagsNV", value: 5351, params: &[] },
+    Enumerant { name: "IncomingRayFlagsKHR", value: 5351, params: &[] },
+    Enumerant { name: "RayGeometryIndexKHR", value: 5352, params: &[] },
+    Enumerant { name: "WarpsPerSMNV", value: 5374, params: &[] },
+    Enumerant { name: "SMCountNV", value: 5375, params: &[] },
+    Enumerant { name: "WarpIDNV", value: 5376, params: &[] },
+    Enumerant { name: "SMIDNV", value: 5377, params: &[] },
+];
+const SCOPE: &[Enumerant] = &[
+    Enumerant { name: "CrossDevice", value: 0, params: &[] },
+    Enumerant { name: "Device", value: 1, params: &[] },
+    Enumerant { name: "Workgroup", value: 2, params: &[] },
+    Enumerant { name: "Subgroup", value: 3, params: &[] },
+    Enumerant { name: "Invocation", value: 4, params: &[] },
+    Enumerant { name: "QueueFamily", value: 5, params: &[] },
+    Enumerant { name: "QueueFamilyKHR", value: 5, params: &[] },
+    Enumerant { name: "ShaderCallKHR", value: 6, params: &[] },
+];
+const GROUP_OPERATION: &[Enumerant] = &[
+    Enumerant { name: "Reduce", value: 0, params: &[] },
+    Enumerant { name: "InclusiveScan", value: 1, params: &[] },
+    Enumerant { name: "ExclusiveScan", value: 2, params: &[] },
+    Enumerant { name: "ClusteredReduce", value: 3, params: &[] },
+    Enumerant { name: "PartitionedReduceNV", value: 6, params: &[] },
+    Enumerant { name: "PartitionedInclusiveScanNV", value: 7, params: &[] },
+    Enumerant { name: "PartitionedExclusiveScanNV", value: 8, params: &[] },
+];
+const KERNEL_ENQUEUE_FLAGS: &[Enumerant] = &[
+    Enumerant { name: "NoWait", value: 0, params: &[] },
+    Enumerant { name: "WaitKernel", value: 1, params: &[] },
+    Enumerant { name: "WaitWorkGroup", value: 2, params: &[] },
+];
+const CAPABILITY: &[Enumerant] = &[
+    Enumerant { name: "Matrix", value: 0, params: &[] },
+    Enumerant { name: "Shader", value: 1, params: &[] },
+    Enumerant { name: "Geometry", value: 2, params: &[] },
+    Enumerant { name: "Tessellation", value: 3, params: &[] },
+    Enumerant { name: "Addresses", value: 4, params: &[] },
+    Enumerant { name: "Linkage", value: 5, params: &[] },
+    Enumerant { name: "Kernel", value: 6, params: &[] },
+    Enumerant { name: "Vector16", value: 7, params: &[] },
+    Enumerant { name: "Float16Buffer", value: 8, params: &[] },
+    Enumerant { name: "Float16", value: 9, params: &[] },
+    Enumerant { name: "Float64", value: 10, params: &[] },
+    Enumerant { name: "Int64", value: 11, params: &[] },
+    Enumerant { name: "Int64Atomics", value: 12, params: &[] },
+    Enumerant { name: "ImageBasic", value: 13, params: &[] },
+    Enumerant { name: "ImageReadWrite", value: 14, params: &[] },
+    Enumerant { name: "ImageMipmap", value: 15, params: &[] },
+    Enumerant { name: "Pipes", value: 17, params: &[] },
+    Enumerant { name: "Groups", value: 18, params: &[] },
+    Enumerant { name: "DeviceEnqueue", value: 19, params: &[] },
+    Enumerant { name: "LiteralSampler", value: 20, params: &[] },
+    Enumerant { name: "AtomicStorage", value: 21, params: &[] },
+    Enumerant { name: "Int16", value: 22, params: &[] },
+    Enumerant { name: "TessellationPointSize", value: 23, params: &[] },
+    Enumerant { name: "GeometryPointSize", value: 24, params: &[] },
+    Enumerant { name: "ImageGatherExtended", value: 25, params: &[] },
+    Enumerant { name: "StorageImageMultisample", value: 27, params: &[] },
+    Enumerant { name: "UniformBufferArrayDynamicIndexing", value: 28, params: &[] },
+    Enumerant { name: "SampledImageArrayDynamicIndexing", value: 29, params: &[] },
+    Enumerant { name: "StorageBufferArrayDynamicIndexing", value: 30, params: &[] },
+    Enumerant { name: "StorageImageArrayDynamicIndexing", value: 31, params: &[] },
+    Enumerant { name: "ClipDistance", value: 32, params: &[] },
+    Enumerant { name: "CullDistance", value: 33, params: &[] },
+    Enumerant { name: "ImageCubeArray", value: 34, params: &[] },
+    Enumerant { name: "SampleRateShading", value: 35, params: &[] },
+    Enumerant { name: "ImageRect", value: 36, params: &[] },
+    Enumerant { name: "SampledRect", value: 37, params: &[] },
+    Enumerant { name: "GenericPointer", value: 38, params: &[] },
+    Enumerant { name: "Int8", value: 39, params: &[] },
+    Enumerant { name: "InputAttachment", value: 40, params: &[] },
+    Enumerant { name: "SparseResidency", value: 41, params: &[] },
+    Enumerant { name: "MinLod", value: 42, params: &[] },
+    Enumerant { name: "Sampled1D", value: 43, params: &[] },
+    Enumerant { name: "Image1D", value: 44, params: &[] },
+    Enumerant { name: "SampledCubeArray", value: 45, params: &[] },
+    Enumerant { name: "SampledBuffer", value: 46, params: &[] },
+    Enumerant { name: "ImageBuffer", value: 47, params: &[] },
+    Enumerant { name: "ImageMSArray", value: 48, params: &[] },
+    Enumerant { name: "StorageImageExtendedFormats", value: 49, params: &[] },
+    Enumerant { name: "ImageQuery", value: 50, params: &[] },
+    Enumerant { name: "DerivativeControl", value: 51, params: &[] },
+    Enumerant { name: "InterpolationFunction", value: 52, params: &[] },
+    Enumerant { name: "TransformFeedback", value: 53, params: &[] },
+    Enumerant { name: "GeometryStreams", value: 54, params: &[] },
+    Enumerant { name: "StorageImageReadWithoutFormat", value: 55, params: &[] },
+    Enumerant { name: "StorageImageWriteWithoutFormat", value: 56, params: &[] },
+    Enumerant { name: "MultiViewport", value: 57, params: &[] },
+    Enumerant { name: "SubgroupDispatch", value: 58, params: &[] },
+    Enumerant { name: "NamedBarrier", value: 59, params: &[] },
+    Enumerant { name: "PipeStorage", value: 60, params: &[] },
+    Enumerant { name: "GroupNonUniform", value: 61, params: &[] },
+    Enumerant { name: "GroupNonUniformVote", value: 62, params: &[] },
+    Enumerant { name: "GroupNonUniformArithmetic", value: 63, params: &[] },
+    Enumerant { name: "GroupNonUniformBallot", value: 64, params: &[] },
+    Enumerant { name: "GroupNonUniformShuffle", value: 65, params: &[] },
+    Enumerant { name: "GroupNonUniformShuffleRelative", value: 66, params: &[] },
+    Enumerant { name: "GroupNonUniformClustered", value: 67, params: &[] },
+    Enumerant { name: "GroupNonUniformQuad", value: 68, params: &[] },
+    Enumerant { name: "ShaderLayer", value: 69, params: &[] },
+    Enumerant { name: "ShaderViewportIndex", value: 70, params: &[] },
+    Enumerant { name: "SubgroupBallotKHR", value: 4423, params: &[] },
+    Enumerant { name: "DrawParameters", value: 4427, params: &[] },
+    Enumerant { name: "SubgroupVoteKHR", value: 4431, params: &[] },
+    Enumerant { name: "StorageBuffer16BitAccess", value: 4433, params: &[] },
+    Enumerant { name: "StorageUniformBufferBlock16", value: 4433, params: &[] },
+    Enumerant { name: "UniformAndStorageBuffer16BitAccess", value: 4434, params: &[] },
+    Enumerant { name: "StorageUniform16", value: 4434, params: &[] },
+    Enumerant { name: "StoragePushConstant16", value: 4435, params: &[] },
+    Enumerant { name: "StorageInputOutput16", value: 4436, params: &[] },
+    Enumerant { name: "DeviceGroup", value: 4437, params: &[] },
+    Enumerant { name: "MultiView", value: 4439, params: &[] },
+    Enumerant { name: "VariablePointersStorageBuffer", value: 4441, params: &[] },
+    Enumerant { name: "VariablePointers", value: 4442, params: &[] },
+    Enumerant { name: "AtomicStorageOps", value: 4445, params: &[] },
+    Enumerant { name: "SampleMaskPostDepthCoverage", value: 4447, params: &[] },
+    Enumerant { name: "StorageBuffer8BitAccess", value: 4448, params: &[] },
+    Enumerant { name: "UniformAndStorageBuffer8BitAccess", value: 4449, params: &[] },
+    Enumerant { name: "StoragePushConstant8", value: 4450, params: &[] },
+    Enumerant { name: "DenormPreserve", value: 4464, params: &[] },
+    Enumerant { name: "DenormFlushToZero", value: 4465, params: &[] },
+    Enumerant { name: "SignedZeroInfNanPreserve", value: 4466, params: &[] },
+    Enumerant { name: "RoundingModeRTE", value: 4467, params: &[] },
+    Enumerant { name: "RoundingModeRTZ", value: 4468, params: &[] },
+    Enumerant { name: "RayQueryProvisionalKHR", value: 4471, params: &[] },
+    Enumerant { name: "RayTraversalPrimitiveCullingKHR", value: 4478, params: &[] },
+    Enumerant { name: "Float16ImageAMD", value: 5008, params: &[] },
+    Enumerant { name: "ImageGatherBiasLodAMD", value: 5009, params: &[] },
+    Enumerant { name: "FragmentMaskAMD", value: 5010, params: &[] },
+    Enumerant { name: "StencilExportEXT", value: 5013, params: &[] },
+    Enumerant { name: "ImageReadWriteLodAMD", value: 5015, params: &[] },
+    Enumerant { name: "ShaderClockKHR", value: 5055, params: &[] },
+    Enumerant { name: "SampleMaskOverrideCoverageNV", value: 5249, params: &[] },
+    Enumerant { name: "GeometryShaderPassthroughNV", value: 5251, params: &[] },
+    Enumerant { name: "ShaderViewportIndexLayerEXT", value: 5254, params: &[] },
+    Enumerant { name: "ShaderViewportIndexLayerNV", value: 5254, params: &[] },
+    Enumerant { name: "ShaderViewportMaskNV", value: 5255, params: &[] },
+    Enumerant { name: "ShaderStereoViewNV", value: 5259, params: &[] },
+    Enumerant { name: "PerViewAttributesNV", value: 5260, params: &[] },
+    Enumerant { name: "FragmentFullyCoveredEXT", value: 5265, params: &[] },
+    Enumerant { name: "MeshShadingNV", value: 5266, params: &[] },
+    Enumerant { name: "ImageFootprintNV", value: 5282, params: &[] },
+    Enumerant { name: "FragmentBarycentricKHR", value: 5284, params: &[] },
+    Enumerant { name: "FragmentBarycentricNV", value: 5284, params: &[] },
+    Enumerant { name: "ComputeDerivativeGroupQuadsNV", value: 5288, params: &[] },
+    Enumerant { name: "FragmentDensityEXT", value: 5291, params: &[] },
+    Enumerant { name: "ShadingRateNV", value: 5291, params: &[] },
+    Enumerant { name: "GroupNonUniformPartitionedNV", value: 5297, params: &[] },
+    Enumerant { name: "ShaderNonUniform", value: 5301, params: &[] },
+    Enumerant { name: "ShaderNonUniformEXT", value: 5301, params: &[] },
+    Enumerant { name: "RuntimeDescriptorArray", value: 5302, params: &[] },
+    Enumerant { name: "RuntimeDescriptorArrayEXT", value: 5302, params: &[] },
+    Enumerant { name: "InputAttachmentArrayDynamicIndexing", value: 5303, params: &[] },
+    Enumerant { name: "InputAttachmentArrayDynamicIndexingEXT", value: 5303, params: &[] },
+    Enumerant { name: "UniformTexelBufferArrayDynamicIndexing", value: 5304, params: &[] },
+    Enumerant { name: "UniformTexelBufferArrayDynamicIndexingEXT", value: 5304, params: &[] },
+    Enumerant { name: "StorageTexelBufferArrayDynamicIndexing", value: 5305, params: &[] },
+    Enumerant { name: "StorageTexelBufferArrayDynamicIndexingEXT", value: 5305, params: &[] },
+    Enumerant { name: "UniformBufferArrayNonUniformIndexing", value: 5306, params: &[] },
+    Enumerant { name: "UniformBufferArrayNonUniformIndexingEXT", value: 5306, params: &[] },
+    Enumerant { name: "SampledImageArrayNonUniformIndexing", value: 5307, params: &[] },
+    Enumerant { name: "SampledImageArrayNonUniformIndexingEXT", value: 5307, params: &[] },
+    Enumerant { name: "StorageBufferArrayNonUniformIndexing", value: 5308, params: &[] },
+    Enumerant { name: "StorageBufferArrayNonUniformIndexingEXT", value: 5308, params: &[] },
+    Enumerant { name: "StorageImageArrayNonUniformIndexing", value: 5309, params: &[] },
+    Enumerant { name: "StorageImageArrayNonUniformIndexingEXT", value: 5309, params: &[] },
+    Enumerant { name: "InputAttachmentArrayNonUniformIndexing", value: 5310, params: &[] },
+    Enumerant { name: "InputAttachmentArrayNonUniformIndexingEXT", value: 5310, params: &[] },
+    Enumerant { name: "UniformTexelBufferArrayNonUniformIndexing", value: 5311, params: &[] },
+    Enumerant { name: "UniformTexelBufferArrayNonUniformIndexingEXT", value: 5311, params: &[] },
+    Enumerant { name: "StorageTexelBufferArrayNonUniformIndexing", value: 5312, params: &[] },
+    Enumerant { name: "StorageTexelBufferArrayNonUniformIndexingEXT", value: 5312, params: &[] },
+    Enumerant { name: "RayTracingNV", value: 5340, params: &[] },
+    Enumerant { name: "VulkanMemoryModel", value: 5345, params: &[] },
+    Enumerant { name: "VulkanMemoryModelKHR", value: 5345, params: &[] },
+    Enumerant { name: "VulkanMemoryModelDeviceScope", value: 5346, params: &[] },
+    Enumerant { name: "VulkanMemoryModelDeviceScopeKHR", value: 5346, params: &[] },
+    Enumerant { name: "PhysicalStorageBufferAddresses", value: 5347, params: &[] },
+    Enumerant { name: "PhysicalStorageBufferAddressesEXT", value: 5347, params: &[] },
+    Enumerant { name: "ComputeDerivativeGroupLinearNV", value: 5350, params: &[] },
+    Enumerant { name: "RayTracingProvisionalKHR", value: 5353, params: &[] },
+    Enumerant { name: "CooperativeMatrixNV", value: 5357, params: &[] },
+    Enumerant { name: "FragmentShaderSampleInterlockEXT", value: 5363, params: &[] },
+    Enumerant { name: "FragmentShaderShadingRateInterlockEXT", value: 5372, params: &[] },
+    Enumerant { name: "ShaderSMBuiltinsNV", value: 5373, params: &[] },
+    Enumerant { name: "FragmentShaderPixelInterlockEXT", value: 5378, params: &[] },
+    Enumerant { name: "DemoteToHelperInvocation", value: 5379, params: &[] },
+    Enumerant { name: "DemoteToHelperInvocationEXT", value: 5379, params: &[] },
+    Enumerant { name: "SubgroupShuffleINTEL", value: 5568, params: &[] },
+    Enumerant { name: "SubgroupBufferBlockIOINTEL", value: 5569, params: &[] },
+    Enumerant { name: "SubgroupImageBlockIOINTEL", value: 5570, params: &[] },
+    Enumerant { name: "SubgroupImageMediaBlockIOINTEL", value: 5579, params: &[] },
+    Enumerant { name: "IntegerFunctions2INTEL", value: 5584, params: &[] },
+    Enumerant { name: "SubgroupAvcMotionEstimationINTEL", value: 5696, params: &[] },
+    Enumerant { name: "SubgroupAvcMotionEstimationIntraINTEL", value: 5697, params: &[] },
+    Enumerant { name: "SubgroupAvcMotionEstimationChromaINTEL", value: 5698, params: &[] },
+];
+const RAY_QUERY_INTERSECTION: &[Enumerant] = &[
+    Enumerant { name: "RayQueryCandidateIntersectionKHR", value: 0, params: &[] },
+    Enumerant { name: "RayQueryCommittedIntersectionKHR", value: 1, params: &[] },
+];
+const RAY_QUERY_COMMITTED_INTERSECTION_TYPE: &[Enumerant] = &[
+    Enumerant { name: "RayQueryCommittedIntersectionNoneKHR", value: 0, params: &[] },
+    Enumerant { name: "RayQueryCommittedIntersectionTriangleKHR", value: 1, params: &[] },
+    Enumerant { name: "RayQueryCommittedIntersectionGeneratedKHR", value: 2, params: &[] },
+];
+const RAY_QUERY_CANDIDATE_INTERSECTION_TYPE: &[Enumerant] = &[
+    Enumerant { name: "RayQueryCandidateIntersectionTriangleKHR", value: 0, params: &[] },
+    Enumerant { name: "RayQueryCandidateIntersectionAABBKHR", value: 1, params: &[] },
+];
+
+/// Name of an opcode as in `spirv_headers::Op` with the `Op` prefix, e.g.
+/// `OpTypeInt`. Opcodes unknown to `spirv_headers` are named by value, like
+/// `Op12345`.
+pub(crate) fn op_name(opcode: u32) -> String {
+    match Op::from_u32(opcode) {
+        Some(op) => format!("Op{:?}", op),
+        None => format!("Op{}", opcode),
+    }
+}
+/// Get the grammar of an instruction by its opcode.
+pub(crate) fn instr_by_opcode(opcode: u32) -> Option<&'static InstrGrammar> {
+    let i = INSTRS.binary_search_by_key(&opcode, |x| x.opcode).ok()?;
+    Some(&INSTRS[i])
+}
+/// Get the grammar of an instruction by its name, e.g. `OpTypeInt`.
+pub(crate) fn instr_by_name(name: &str) -> Option<&'static InstrGrammar> {
+    INSTRS.iter().find(|x| x.name() == name)
+}
+/// Get all enumerants of an enumeration operand kind. `None` is returned if
+/// the kind is not an enumeration.
+pub(crate) fn enumerants(kind: OperandKind) -> Option<&'static [Enumerant]> {
+    let rv = match kind {
+        ImageOperands => IMAGE_OPERANDS,
+        FPFastMathMode => FPFAST_MATH_MODE,
+        SelectionControl => SELECTION_CONTROL,
+        LoopControl => LOOP_CONTROL,
+        FunctionControl => FUNCTION_CONTROL,
+        MemorySemantics => MEMORY_SEMANTICS,
+        MemoryAccess => MEMORY_ACCESS,
+        KernelProfilingInfo => KERNEL_PROFILING_INFO,
+        RayFlags => RAY_FLAGS,
+        SourceLanguage => SOURCE_LANGUAGE,
+        ExecutionModel => EXECUTION_MODEL,
+        AddressingModel => ADDRESSING_MODEL,
+        MemoryModel => MEMORY_MODEL,
+        ExecutionMode => EXECUTION_MODE,
+        StorageClass => STORAGE_CLASS,
+        Dim => DIM,
+        SamplerAddressingMode => SAMPLER_ADDRESSING_MODE,
+        SamplerFilterMode => SAMPLER_FILTER_MODE,
+        ImageFormat => IMAGE_FORMAT,
+        ImageChannelOrder => IMAGE_CHANNEL_ORDER,
+        ImageChannelDataType => IMAGE_CHANNEL_DATA_TYPE,
+        FPRoundingMode => FPROUNDING_MODE,
+        LinkageType => LINKAGE_TYPE,
+        AccessQualifier => ACCESS_QUALIFIER,
+        FunctionParameterAttribute => FUNCTION_PARAMETER_ATTRIBUTE,
+        Decoration => DECORATION,
+        BuiltIn => BUILT_IN,
+        Scope => SCOPE,
+        GroupOperation => GROUP_OPERATION,
+        KernelEnqueueFlags => KERNEL_ENQUEUE_FLAGS,
+        Capability => CAPABILITY,
+        RayQueryIntersection => RAY_QUERY_INTERSECTION,
+        RayQueryCommittedIntersectionType => RAY_QUERY_COMMITTED_INTERSECTION_TYPE,
+        RayQueryCandidateIntersectionType => RAY_QUERY_CANDIDATE_INTERSECTION_TYPE,
+        _ => return None,
+    };
+    Some(rv)
+}
+/// Whether the operand kind is a bit mask whose enumerants can be combined.
+pub(crate) fn is_bit_enum(kind: OperandKind) -> bool {
+    matches!(kind, ImageOperands | FPFastMathMode | SelectionControl | LoopControl | FunctionControl | MemorySemantics | MemoryAccess | KernelProfilingInfo | RayFlags)
+}
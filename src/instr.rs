@@ -1,22 +1,31 @@
+//! Typed operands of the instructions SPIR-Q understands.
+//!
+//! An instruction can be decoded with `TryFrom`, e.g.
+//! `OpTypeInt::try_from(&instr)`. The decoder only checks that there are
+//! enough operands, so it's up to the caller to match the opcode first.
+//! Alternatively, `DecodedInstr::try_from(&instr)` matches the opcode and
+//! decodes the instruction into the corresponding variant.
 use std::convert::TryFrom;
 use std::marker::PhantomData;
-use spirv_headers::{Decoration, Dim, StorageClass};
 use super::{Error, Result};
 use super::parse::{Instr};
 
-pub use spirv_headers::{ExecutionModel, ImageFormat};
+pub use spirv_headers::{AddressingModel, Capability, Decoration, Dim, ExecutionMode,
+    ExecutionModel, ImageFormat, MemoryModel, Op, SamplerAddressingMode,
+    SamplerFilterMode, StorageClass};
 
-pub type InstrId = u32;
-pub type FunctionId = u32;
-pub type TypeId = u32;
-pub type ResourceId = u32;
-pub type ConstantId = u32;
+pub(crate) type InstrId = u32;
+pub(crate) type FunctionId = u32;
+pub(crate) type TypeId = u32;
+pub(crate) type ResourceId = u32;
+pub(crate) type ConstantId = u32;
 
-pub type MemberIdx = u32;
+pub(crate) type MemberIdx = u32;
 
 macro_rules! define_ops {
-    ($($opcode:ident { $($field:ident: $type:ty = $read_fn:ident(),)+ })+) => {
+    ($($opcode:ident = $op:ident { $($field:ident: $type:ty = $read_fn:ident(),)+ })+) => {
         $(
+            #[doc = concat!("Typed operands of `", stringify!($opcode), "`.")]
             pub struct $opcode<'a> {
                 $( pub $field: $type, )*
                 _ph: PhantomData<&'a ()>,
@@ -33,62 +42,105 @@ macro_rules! define_ops {
                 }
             }
         )+
+
+        /// An instruction decoded into the typed operands of its opcode.
+        /// Instructions SPIR-Q doesn't understand are kept `Raw`.
+        pub enum DecodedInstr<'a> {
+            $( $opcode($opcode<'a>), )+
+            Raw(Instr<'a>),
+        }
+        impl<'a> TryFrom<&Instr<'a>> for DecodedInstr<'a> {
+            type Error = Error;
+            fn try_from(instr: &Instr<'a>) -> Result<Self> {
+                let decoded = match instr.op() {
+                    $( Some(Op::$op) => DecodedInstr::$opcode($opcode::try_from(instr)?), )+
+                    _ => DecodedInstr::Raw(*instr),
+                };
+                Ok(decoded)
+            }
+        }
     };
 }
 
 // Be aware that the order of the read methods is important.
 define_ops!{
-    OpEntryPoint {
+    OpCapability = Capability {
+        cap: Capability = read_enum(),
+    }
+    OpExtension = Extension {
+        name: &'a str = read_str(),
+    }
+    OpExtInstImport = ExtInstImport {
+        instr_set_id: InstrId = read_u32(),
+        name: &'a str = read_str(),
+    }
+    OpMemoryModel = MemoryModel {
+        addr_model: AddressingModel = read_enum(),
+        mem_model: MemoryModel = read_enum(),
+    }
+    OpEntryPoint = EntryPoint {
         exec_model: ExecutionModel = read_enum(),
         func_id: FunctionId = read_u32(),
         name: &'a str = read_str(),
+        interface_ids: &'a [ResourceId] = read_list(),
     }
 
-    OpName {
+    OpExecutionMode = ExecutionMode {
+        func_id: FunctionId = read_u32(),
+        exec_mode: ExecutionMode = read_enum(),
+        params: &'a [u32] = read_list(),
+    }
+    OpExecutionModeId = ExecutionModeId {
+        func_id: FunctionId = read_u32(),
+        exec_mode: ExecutionMode = read_enum(),
+        params: &'a [ConstantId] = read_list(),
+    }
+
+    OpName = Name {
         target_id: InstrId = read_u32(),
         name: &'a str = read_str(),
     }
-    OpMemberName {
+    OpMemberName = MemberName {
         target_id: InstrId = read_u32(),
         member_idx: MemberIdx = read_u32(),
         name: &'a str = read_str(),
     }
 
-    OpDecorate {
+    OpDecorate = Decorate {
         target_id: InstrId = read_u32(),
         deco: Decoration = read_enum(),
         params: &'a [u32] = read_list(),
     }
-    OpMemberDecorate {
+    OpMemberDecorate = MemberDecorate {
         target_id: InstrId = read_u32(),
         member_idx: MemberIdx = read_u32(),
         deco: Decoration = read_enum(),
         params: &'a [u32] = read_list(),
     }
 
-    OpTypeBool {
+    OpTypeBool = TypeBool {
         ty_id: TypeId = read_u32(),
     }
-    OpTypeInt {
+    OpTypeInt = TypeInt {
         ty_id: TypeId = read_u32(),
         nbyte: u32 = read_u32(),
         is_signed: bool = read_bool(),
     }
-    OpTypeFloat {
+    OpTypeFloat = TypeFloat {
         ty_id: TypeId = read_u32(),
         nbyte: u32 = read_u32(),
     }
-    OpTypeVector {
+    OpTypeVector = TypeVector {
         ty_id: TypeId = read_u32(),
         scalar_ty_id: TypeId = read_u32(),
         nscalar: u32 = read_u32(),
     }
-    OpTypeMatrix {
+    OpTypeMatrix = TypeMatrix {
         ty_id: TypeId = read_u32(),
         vec_ty_id: TypeId = read_u32(),
         nvec: u32 = read_u32(),
     }
-    OpTypeImage {
+    OpTypeImage = TypeImage {
         ty_id: TypeId = read_u32(),
         unit_ty_id: TypeId = read_u32(),
         dim: Dim = read_enum(),
@@ -98,62 +150,183 @@ define_ops!{
         is_sampled: u32 = read_u32(),
         color_fmt: ImageFormat = read_enum(),
     }
-    OpTypeSampler {
+    OpTypeSampler = TypeSampler {
         ty_id: TypeId = read_u32(),
     }
-    OpTypeSampledImage {
+    OpTypeSampledImage = TypeSampledImage {
         ty_id: TypeId = read_u32(),
         img_ty_id: TypeId = read_u32(),
     }
-    OpTypeArray {
+    OpTypeArray = TypeArray {
         ty_id: TypeId = read_u32(),
         proto_ty_id: TypeId = read_u32(),
         nrepeat_const_id: ConstantId = read_u32(),
     }
-    OpTypeRuntimeArray {
+    OpTypeRuntimeArray = TypeRuntimeArray {
         ty_id: TypeId = read_u32(),
         proto_ty_id: TypeId = read_u32(),
     }
-    OpTypeStruct {
+    OpTypeStruct = TypeStruct {
         ty_id: TypeId = read_u32(),
         member_ty_ids: &'a [TypeId] = read_list(),
     }
-    OpTypePointer {
+    OpTypePointer = TypePointer {
         ty_id: TypeId = read_u32(),
         store_cls: StorageClass = read_enum(),
         target_ty_id: TypeId = read_u32(),
     }
-    OpConstant {
+    OpConstantTrue = ConstantTrue {
+        ty_id: TypeId = read_u32(),
+        const_id: ConstantId = read_u32(),
+    }
+    OpConstantFalse = ConstantFalse {
+        ty_id: TypeId = read_u32(),
+        const_id: ConstantId = read_u32(),
+    }
+    OpConstant = Constant {
+        ty_id: TypeId = read_u32(),
+        const_id: ConstantId = read_u32(),
+        value: &'a [u32] = read_list(),
+    }
+    OpConstantSampler = ConstantSampler {
         ty_id: TypeId = read_u32(),
         const_id: ConstantId = read_u32(),
+        addr_mode: SamplerAddressingMode = read_enum(),
+        normalized: bool = read_bool(),
+        filter_mode: SamplerFilterMode = read_enum(),
+    }
+    OpConstantNull = ConstantNull {
+        ty_id: TypeId = read_u32(),
+        const_id: ConstantId = read_u32(),
+    }
+    OpSpecConstantTrue = SpecConstantTrue {
+        ty_id: TypeId = read_u32(),
+        spec_const_id: ConstantId = read_u32(),
+    }
+    OpSpecConstantFalse = SpecConstantFalse {
+        ty_id: TypeId = read_u32(),
+        spec_const_id: ConstantId = read_u32(),
+    }
+    OpSpecConstant = SpecConstant {
+        ty_id: TypeId = read_u32(),
+        spec_const_id: ConstantId = read_u32(),
         value: &'a [u32] = read_list(),
     }
-    OpVariable {
+    OpConstantComposite = ConstantComposite {
+        ty_id: TypeId = read_u32(),
+        const_id: ConstantId = read_u32(),
+        constituents: &'a [ConstantId] = read_list(),
+    }
+    OpSpecConstantComposite = SpecConstantComposite {
+        ty_id: TypeId = read_u32(),
+        spec_const_id: ConstantId = read_u32(),
+        constituents: &'a [ConstantId] = read_list(),
+    }
+    OpSpecConstantOp = SpecConstantOp {
+        ty_id: TypeId = read_u32(),
+        spec_const_id: ConstantId = read_u32(),
+        opcode: u32 = read_u32(),
+        operands: &'a [u32] = read_list(),
+    }
+    OpVariable = Variable {
         ty_id: TypeId = read_u32(),
         alloc_id: ResourceId = read_u32(),
         store_cls: StorageClass = read_enum(),
     }
 
-    OpFunction {
+    OpFunction = Function {
         return_ty_id: TypeId = read_u32(),
         func_id: TypeId = read_u32(),
     }
-    OpFunctionCall {
+    OpFunctionCall = FunctionCall {
         return_ty_id: TypeId = read_u32(),
         return_id: InstrId = read_u32(),
         func_id: FunctionId = read_u32(),
+        arg_ids: &'a [InstrId] = read_list(),
     }
-    OpLoad {
+    OpLoad = Load {
         return_ty_id: TypeId = read_u32(),
         return_id: InstrId = read_u32(),
         rsc_id: ResourceId = read_u32(),
     }
-    OpStore {
+    OpStore = Store {
         rsc_id: ResourceId = read_u32(),
     }
-    OpAccessChain {
+    OpCopyMemory = CopyMemory {
+        dst_rsc_id: ResourceId = read_u32(),
+        src_rsc_id: ResourceId = read_u32(),
+    }
+    OpAccessChain = AccessChain {
         rsc_ty_id: TypeId = read_u32(),
         rsc_id: ResourceId = read_u32(),
         accessed_rsc_id: ResourceId = read_u32(),
+        idx_ids: &'a [InstrId] = read_list(),
+    }
+    OpCopyObject = CopyObject {
+        ty_id: TypeId = read_u32(),
+        rsc_id: ResourceId = read_u32(),
+        src_rsc_id: ResourceId = read_u32(),
+    }
+    OpImageTexelPointer = ImageTexelPointer {
+        rsc_ty_id: TypeId = read_u32(),
+        rsc_id: ResourceId = read_u32(),
+        img_rsc_id: ResourceId = read_u32(),
+    }
+    OpSampledImage = SampledImage {
+        ty_id: TypeId = read_u32(),
+        sampled_img_id: InstrId = read_u32(),
+        img_id: InstrId = read_u32(),
+    }
+    OpImage = Image {
+        ty_id: TypeId = read_u32(),
+        img_id: InstrId = read_u32(),
+        sampled_img_id: InstrId = read_u32(),
+    }
+    OpImageRead = ImageRead {
+        ty_id: TypeId = read_u32(),
+        texel_id: InstrId = read_u32(),
+        img_id: InstrId = read_u32(),
+    }
+    OpImageWrite = ImageWrite {
+        img_id: InstrId = read_u32(),
+    }
+    OpFunctionParameter = FunctionParameter {
+        ty_id: TypeId = read_u32(),
+        param_id: InstrId = read_u32(),
+    }
+    OpArrayLength = ArrayLength {
+        return_ty_id: TypeId = read_u32(),
+        return_id: InstrId = read_u32(),
+        rsc_id: ResourceId = read_u32(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use crate::SpirvBinary;
+    use super::DecodedInstr;
+
+    #[test]
+    fn test_decode() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpSource GLSL 450
+        %int = OpTypeInt 32 1
+"#).unwrap();
+        let decoded = spv.instrs()
+            .map(|instr| DecodedInstr::try_from(&instr).unwrap())
+            .collect::<Vec<_>>();
+        assert!(matches!(decoded[0], DecodedInstr::OpCapability(_)));
+        assert!(matches!(decoded[1], DecodedInstr::OpMemoryModel(_)));
+        match &decoded[2] {
+            DecodedInstr::Raw(instr) => assert_eq!(instr.opcode(), 3),
+            _ => panic!("OpSource should be kept raw"),
+        }
+        match &decoded[3] {
+            DecodedInstr::OpTypeInt(op) => assert!(op.nbyte == 32 && op.is_signed),
+            _ => panic!("OpTypeInt should be decoded"),
+        }
     }
 }
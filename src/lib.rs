@@ -10,7 +10,7 @@
 //!
 //! ```ignore
 //! // Load SPIR-V data into `[u32]` buffer `spv_words`.
-//! let spv: SpirvBinary = spv_words.into();
+//! let spv = SpirvBinary::try_from(spv_words).unwrap();
 //! let entries = spv.reflect().unwrap();
 //! // All extracted entry point data are available in `entries`.
 //! ```
@@ -20,6 +20,18 @@
 //! [`EntryPoint`]s. Each entry point has a [`Manifest`] that supports queries
 //! from allocation requirement to fine-grained typing details.
 //!
+//! If the SPIR-V data is owned by someone else, e.g. a memory-mapped shader
+//! archive, [`SpirvModule`] can be used instead to reflect the data in place
+//! without copying.
+//!
+//! ## Module editing
+//!
+//! Besides reflection, a module can be decoded into a [`ModuleEditor`] to
+//! insert, remove or replace instructions, and then be encoded back into a
+//! binary with word counts and the id bound recomputed. Common rewrites are
+//! provided directly on [`SpirvBinary`], e.g. [`remap`] moves descriptors and
+//! input variables to new bindings and locations.
+//!
 //! ## Size calculation
 //!
 //! The struct member offsets and array/matrix strides are specified in SPIR-V
@@ -44,7 +56,8 @@
 //! aTexCoord
 //! vWorldPosition
 //! 1.2 // ERROR: I/O variables cannot be nested.
-//! gl_Position // WARNING: Built-in variables are ignored during reflection.
+//! gl_Position // ERROR: Built-in variables are listed by `inputs_builtin` and
+//!             // `outputs_builtin` rather than referred to by symbols.
 //! ```
 //!
 //! Descriptors have to be referred to with both the descriptor set number and
@@ -64,60 +77,236 @@
 //!
 //! Note: Although `spv` files generated directly from compilers normally keep
 //! the nameing data, it should be noticed that names are debug information that
-//! might be wiped out during compression.
+//! might be wiped out during compression. [`strip_debug`] keeps the names in a
+//! [`NameSidecar`] so they can be attached to entry points reflected from the
+//! stripped binary.
 //!
 //! [`SpirvBinary`]: struct.SpirvBinary.html
+//! [`SpirvModule`]: struct.SpirvModule.html
+//! [`ModuleEditor`]: edit/struct.ModuleEditor.html
 //! [`EntryPoint`]: struct.EntryPoint.html
 //! [`reflect`]: struct.SpirvBinary.html#method.reflect
+//! [`remap`]: struct.SpirvBinary.html#method.remap
+//! [`strip_debug`]: struct.SpirvBinary.html#method.strip_debug
+//! [`NameSidecar`]: struct.NameSidecar.html
 //! [`Manifest`]: struct.Manifest.html
 //! [`Type`]: ty/enum.Type.html
 //! [`Symbol`]: sym/struct.Symbol.html
 mod consts;
-mod parse;
-mod instr;
 mod reflect;
+mod grammar;
+mod dis;
+mod asm;
+mod remap;
+mod strip;
+mod spec;
+mod constant;
 pub mod sym;
 pub mod error;
 pub mod ty;
+pub mod parse;
+pub mod instr;
+pub mod visit;
+pub mod edit;
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+use std::borrow::Cow;
 use std::collections::{HashMap};
 use std::fmt;
 use std::iter::FromIterator;
-use std::ops::Deref;
-use parse::{Instrs, Instr};
-use ty::{Type, DescriptorType};
+use std::ops::{Deref, Range};
+use parse::Instrs;
+use ty::{Type, DescriptorType, MemberVariableRouting};
 pub use sym::*;
 pub use error::*;
-pub use spirv_headers::ExecutionModel;
+pub use visit::Visitor;
+pub use edit::{InstrBuf, ModuleEditor};
+pub use remap::Remapping;
+pub use strip::NameSidecar;
+pub use spec::{SpecConstant, SpecValue};
+pub use constant::ConstantValue;
+pub use spirv_headers::{AddressingModel, BuiltIn, Capability, ExecutionMode, ExecutionModel,
+    MemoryModel, SamplerAddressingMode, SamplerFilterMode};
+
+/// SPIR-V module header, the first five words of every SPIR-V binary.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct SpirvHeader {
+    /// Magic number, always `0x07230203` after endianness correction.
+    pub magic: u32,
+    /// Major version number of SPIR-V the module conforms to.
+    pub major_version: u8,
+    /// Minor version number of SPIR-V the module conforms to.
+    pub minor_version: u8,
+    /// Registered vendor and tool id of the module generator. 0 if the
+    /// generator is not registered.
+    pub generator_id: u16,
+    /// Version number of the generator. Its meaning is up to the generator.
+    pub generator_version: u16,
+    /// All ids in the module are guaranteed to be smaller than this bound.
+    pub bound: u32,
+    /// Reserved instruction schema, should be 0.
+    pub schema: u32,
+}
+impl SpirvHeader {
+    /// Number of words a header takes.
+    pub const WORD_COUNT: usize = 5;
+
+    /// Parse the header from the leading words of a binary in native
+    /// endianness.
+    pub fn parse(words: &[u32]) -> Result<SpirvHeader> {
+        if words.len() < Self::WORD_COUNT { return Err(Error::HEADER_TOO_SHORT); }
+        if words[0] != spirv_headers::MAGIC_NUMBER { return Err(Error::MAGIC_MISMATCH); }
+        if words[1] >> 16 != 1 { return Err(Error::UNSUPPORTED_VERSION); }
+        // Version and generator words are packed as `0x00MMmm00` and
+        // `0xTTTTVVVV` respectively.
+        let header = SpirvHeader {
+            magic: words[0],
+            major_version: (words[1] >> 16) as u8,
+            minor_version: (words[1] >> 8) as u8,
+            generator_id: (words[2] >> 16) as u16,
+            generator_version: words[2] as u16,
+            bound: words[3],
+            schema: words[4],
+        };
+        Ok(header)
+    }
+    /// Encode the header into words.
+    pub fn to_words(&self) -> [u32; Self::WORD_COUNT] {
+        [
+            self.magic,
+            (self.major_version as u32) << 16 | (self.minor_version as u32) << 8,
+            (self.generator_id as u32) << 16 | self.generator_version as u32,
+            self.bound,
+            self.schema,
+        ]
+    }
+}
 
 /// SPIR-V program binary.
 #[derive(Debug, Default, Clone)]
 pub struct SpirvBinary(Vec<u32>);
-impl From<Vec<u32>> for SpirvBinary {
-    fn from(x: Vec<u32>) -> Self { SpirvBinary(x) }
+impl TryFrom<Vec<u32>> for SpirvBinary {
+    type Error = Error;
+    fn try_from(x: Vec<u32>) -> Result<Self> {
+        SpirvModule::try_from(Cow::Owned(x)).map(SpirvModule::into_binary)
+    }
 }
-impl FromIterator<u32> for SpirvBinary {
-    fn from_iter<I: IntoIterator<Item=u32>>(iter: I) -> Self { SpirvBinary(iter.into_iter().collect::<Vec<u32>>()) }
+impl TryFrom<&[u32]> for SpirvBinary {
+    type Error = Error;
+    fn try_from(x: &[u32]) -> Result<Self> {
+        SpirvModule::try_from(x).map(SpirvModule::into_binary)
+    }
 }
-impl From<Vec<u8>> for SpirvBinary {
-    fn from(x: Vec<u8>) -> Self {
-        if x.len() == 0 { return SpirvBinary::default(); }
-        x.chunks_exact(4)
-            .map(|x| x.try_into().unwrap())
-            .map(match x[0] {
-                0x03 => u32::from_le_bytes,
-                0x07 => u32::from_be_bytes,
-                _ => return SpirvBinary::default(),
-            })
-            .collect::<SpirvBinary>()
+impl TryFrom<&[u8]> for SpirvBinary {
+    type Error = Error;
+    fn try_from(x: &[u8]) -> Result<Self> {
+        SpirvModule::try_from(x).map(SpirvModule::into_binary)
     }
 }
+impl TryFrom<Vec<u8>> for SpirvBinary {
+    type Error = Error;
+    fn try_from(x: Vec<u8>) -> Result<Self> { SpirvBinary::try_from(x.as_ref() as &[u8]) }
+}
+impl FromIterator<u32> for SpirvBinary {
+    /// Collect words into a binary. The words are NOT validated, so
+    /// [`header`](#method.header) should be checked before use.
+    fn from_iter<I: IntoIterator<Item=u32>>(iter: I) -> Self { SpirvBinary(iter.into_iter().collect::<Vec<u32>>()) }
+}
 
 impl SpirvBinary {
-    pub(crate) fn instrs<'a>(&'a self) -> Instrs<'a> { Instrs::new(&self.0) }
+    /// Assemble `spirv-as`-style text into a binary. Ids can be either
+    /// numeric, like `%12`, or named, like `%main`; named ids are assigned
+    /// numbers not used elsewhere in the text.
+    pub fn assemble(src: &str) -> Result<SpirvBinary> {
+        asm::assemble(src)
+    }
+    /// Borrow the binary as a module without copying.
+    pub fn as_module(&self) -> SpirvModule<'_> { SpirvModule(Cow::Borrowed(&self.0)) }
+    /// Iterate over the instructions in the binary.
+    pub fn instrs(&self) -> Instrs<'_> { Instrs::new(&self.0) }
+    /// Walk through the binary with a visitor, section by section.
+    pub fn visit<'a, V: Visitor<'a>>(&'a self, visitor: &mut V) -> Result<()> {
+        visit::visit(self.instrs(), visitor)
+    }
+    /// Decode the binary into a list of instructions for editing.
+    pub fn edit(&self) -> Result<ModuleEditor> { self.as_module().edit() }
+    /// Move descriptors, input attachments and input variables as specified
+    /// in `remapping` by patching their decorations. Decorations are added if
+    /// a resource was implicitly bound to set 0 or binding 0.
+    pub fn remap(&mut self, remapping: &Remapping) -> Result<()> {
+        let mut editor = self.edit()?;
+        remap::remap(&mut editor, remapping)?;
+        *self = editor.encode()?;
+        Ok(())
+    }
+    /// Turn the specialization constants whose `SpecId`s are in `spec_values`
+    /// into regular constants of the given values, for targets that need
+    /// fully specialized binaries.
+    pub fn freeze_spec_consts(&mut self, spec_values: &HashMap<u32, SpecValue>) -> Result<()> {
+        let mut editor = self.edit()?;
+        spec::freeze_spec_consts(&mut editor, spec_values)?;
+        *self = editor.encode()?;
+        Ok(())
+    }
+    /// Strip debug information off the binary, including names, source
+    /// listings, line information and non-semantic instructions. The names
+    /// used in symbol resolution are returned in a sidecar, which can be
+    /// attached to the entry points reflected from the stripped binary with
+    /// [`EntryPoint::attach_names`](struct.EntryPoint.html#method.attach_names).
+    /// The names are collected by reflection, so the binary is left untouched
+    /// and the error is returned if it can't be reflected.
+    pub fn strip_debug(&mut self) -> Result<NameSidecar> {
+        let entry_points = self.reflect()?;
+        let sidecar = NameSidecar::from_entry_points(&entry_points)?;
+        self.strip_debug_unchecked()?;
+        Ok(sidecar)
+    }
+    /// Strip debug information off the binary like `strip_debug`, without
+    /// reflecting the binary. The names are lost, so this is meant for
+    /// binaries that can't be reflected or are never resolved by name.
+    pub fn strip_debug_unchecked(&mut self) -> Result<()> {
+        let mut editor = self.edit()?;
+        strip::strip_debug(&mut editor)?;
+        *self = editor.encode()?;
+        Ok(())
+    }
+    /// Get the module header. Binaries collected from unchecked words might
+    /// not have a valid header.
+    pub fn header(&self) -> Result<SpirvHeader> {
+        SpirvHeader::parse(&self.0)
+    }
     pub fn reflect(&self) -> Result<Box<[EntryPoint]>> {
-        reflect::reflect_spirv(&self)
+        self.as_module().reflect()
+    }
+    /// Reflect the binary as if the specialization constants were specialized
+    /// with `spec_values`, keyed by `SpecId`. Array sizes and workgroup sizes
+    /// depending on specialization constants are evaluated with the given
+    /// values.
+    pub fn reflect_with_specialization(&self, spec_values: &HashMap<u32, SpecValue>) -> Result<Box<[EntryPoint]>> {
+        self.as_module().reflect_with_specialization(spec_values)
+    }
+    /// Get the capabilities, extensions and memory model the binary requires.
+    pub fn module_info(&self) -> Result<ModuleInfo> {
+        self.as_module().module_info()
+    }
+    /// Get the functions defined in the binary and how they call each other.
+    pub fn call_graph(&self) -> Result<CallGraph> {
+        self.as_module().call_graph()
+    }
+    /// Get the values of the constants declared in the binary.
+    /// Specialization constants are reported with their default values.
+    pub fn constants(&self) -> Result<ConstantTable> {
+        self.as_module().constants()
+    }
+    /// Disassemble the binary into `spirv-dis`-style text. Ids are referred to
+    /// by their names from debug information, if possible.
+    pub fn disassemble(&self) -> Result<String> {
+        self.as_module().disassemble()
+    }
+    /// Disassemble the binary into `spirv-dis`-style text with numeric ids
+    /// only.
+    pub fn disassemble_raw_ids(&self) -> Result<String> {
+        self.as_module().disassemble_raw_ids()
     }
     pub fn words(&self) -> &[u32] {
         &self.0
@@ -132,6 +321,119 @@ impl SpirvBinary {
     pub fn into_words(self) -> Vec<u32> { self.0 }
 }
 
+/// SPIR-V program module borrowing its words from elsewhere, e.g. a
+/// memory-mapped shader archive.
+///
+/// A module only copies when the borrowed data cannot be read in place, that
+/// is, when the bytes are not aligned to 4 bytes or the module was encoded in
+/// the opposite endianness.
+#[derive(Debug, Clone)]
+pub struct SpirvModule<'a>(Cow<'a, [u32]>);
+impl<'a> TryFrom<Cow<'a, [u32]>> for SpirvModule<'a> {
+    type Error = Error;
+    fn try_from(mut x: Cow<'a, [u32]>) -> Result<Self> {
+        // Words decoded from a binary of the opposite endianness have their
+        // magic number byte-swapped.
+        match x.first() {
+            Some(&magic) if magic == spirv_headers::MAGIC_NUMBER.swap_bytes() => {
+                x.to_mut().iter_mut().for_each(|word| *word = word.swap_bytes());
+            },
+            _ => {},
+        }
+        SpirvHeader::parse(&x)?;
+        Ok(SpirvModule(x))
+    }
+}
+impl<'a> TryFrom<&'a [u32]> for SpirvModule<'a> {
+    type Error = Error;
+    fn try_from(x: &'a [u32]) -> Result<Self> { SpirvModule::try_from(Cow::Borrowed(x)) }
+}
+impl<'a> TryFrom<&'a [u8]> for SpirvModule<'a> {
+    type Error = Error;
+    fn try_from(x: &'a [u8]) -> Result<Self> {
+        if x.len() & 3 != 0 { return Err(Error::UNALIGNED_BINARY); }
+        if x.len() < SpirvHeader::WORD_COUNT * 4 { return Err(Error::HEADER_TOO_SHORT); }
+        let magic = [x[0], x[1], x[2], x[3]];
+        let read_word = if u32::from_ne_bytes(magic) == spirv_headers::MAGIC_NUMBER {
+            // Every bit pattern is a valid `u32` so the reinterpretation is
+            // sound as long as the data is aligned.
+            let (prefix, words, suffix) = unsafe { x.align_to::<u32>() };
+            if prefix.is_empty() && suffix.is_empty() {
+                return SpirvModule::try_from(words);
+            }
+            u32::from_ne_bytes
+        } else if u32::from_le_bytes(magic) == spirv_headers::MAGIC_NUMBER {
+            u32::from_le_bytes
+        } else if u32::from_be_bytes(magic) == spirv_headers::MAGIC_NUMBER {
+            u32::from_be_bytes
+        } else {
+            return Err(Error::MAGIC_MISMATCH);
+        };
+        let words = x.chunks_exact(4)
+            .map(|x| read_word(x.try_into().unwrap()))
+            .collect::<Vec<u32>>();
+        SpirvModule::try_from(Cow::Owned(words))
+    }
+}
+impl<'a> From<&'a SpirvBinary> for SpirvModule<'a> {
+    fn from(x: &'a SpirvBinary) -> Self { x.as_module() }
+}
+
+impl<'a> SpirvModule<'a> {
+    /// Iterate over the instructions in the module.
+    pub fn instrs(&self) -> Instrs<'_> { Instrs::new(&self.0) }
+    /// Walk through the module with a visitor, section by section.
+    pub fn visit<'b, V: Visitor<'b>>(&'b self, visitor: &mut V) -> Result<()> {
+        visit::visit(self.instrs(), visitor)
+    }
+    /// Decode the module into a list of instructions for editing.
+    pub fn edit(&self) -> Result<ModuleEditor> { ModuleEditor::new(self) }
+    /// Get the module header.
+    pub fn header(&self) -> Result<SpirvHeader> {
+        SpirvHeader::parse(&self.0)
+    }
+    pub fn reflect(&self) -> Result<Box<[EntryPoint]>> {
+        reflect::reflect_spirv(self, &HashMap::new())
+    }
+    /// Reflect the module as if the specialization constants were specialized
+    /// with `spec_values`, keyed by `SpecId`.
+    pub fn reflect_with_specialization(&self, spec_values: &HashMap<u32, SpecValue>) -> Result<Box<[EntryPoint]>> {
+        reflect::reflect_spirv(self, spec_values)
+    }
+    /// Get the capabilities, extensions and memory model the module requires.
+    pub fn module_info(&self) -> Result<ModuleInfo> {
+        reflect::reflect_module_info(self)
+    }
+    /// Get the functions defined in the module and how they call each other.
+    pub fn call_graph(&self) -> Result<CallGraph> {
+        reflect::reflect_call_graph(self)
+    }
+    /// Get the values of the constants declared in the module.
+    /// Specialization constants are reported with their default values.
+    pub fn constants(&self) -> Result<ConstantTable> {
+        reflect::reflect_const_table(self)
+    }
+    /// Disassemble the module into `spirv-dis`-style text. Ids are referred to
+    /// by their names from debug information, if possible.
+    pub fn disassemble(&self) -> Result<String> {
+        dis::disassemble(self, true)
+    }
+    /// Disassemble the module into `spirv-dis`-style text with numeric ids
+    /// only.
+    pub fn disassemble_raw_ids(&self) -> Result<String> {
+        dis::disassemble(self, false)
+    }
+    pub fn words(&self) -> &[u32] {
+        &self.0
+    }
+    /// Whether the module reads the borrowed data in place.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+    /// Copy the module into an owned binary, if it's not owned already.
+    pub fn into_binary(self) -> SpirvBinary { SpirvBinary(self.0.into_owned()) }
+}
+
 
 /// Internal hasher for type equality check.
 pub(crate) fn hash<H: std::hash::Hash>(h: &H) -> u64 {
@@ -141,8 +443,6 @@ pub(crate) fn hash<H: std::hash::Hash>(h: &H) -> u64 {
     h.hash(&mut hasher);
     hasher.finish()
 }
-
-
 // Resource locationing.
 
 /// Interface variable location.
@@ -177,6 +477,23 @@ impl fmt::Debug for Component {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { (self as &dyn fmt::Display).fmt(f) }
 }
 
+/// Blend equation input index of a fragment output. Outputs of index 1 are
+/// the second sources of dual-source blending.
+#[derive(PartialEq, Eq, Hash, Default, Clone, Copy)]
+pub struct Index(u32);
+impl From<u32> for Index {
+    fn from(x: u32) -> Index { Index(x) }
+}
+impl From<Index> for u32 {
+    fn from(x: Index) -> u32 { x.0 }
+}
+impl fmt::Display for Index {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.0.fmt(f) }
+}
+impl fmt::Debug for Index {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { (self as &dyn fmt::Display).fmt(f) }
+}
+
 /// Descriptor set and binding point carrier.
 #[derive(PartialEq, Eq, Hash, Default, Clone, Copy)]
 pub struct DescriptorBinding(Option<(u32, u32)>);
@@ -201,26 +518,137 @@ impl fmt::Debug for DescriptorBinding {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { (self as &dyn fmt::Display).fmt(f) }
 }
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-pub(crate) enum ResourceLocator {
+/// How a resource is accessed by the shader, as far as it can be told
+/// statically.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum AccessType {
+    ReadOnly,
+    WriteOnly,
+    ReadWrite,
+}
+impl AccessType {
+    pub fn is_readable(&self) -> bool { *self != AccessType::WriteOnly }
+    pub fn is_writable(&self) -> bool { *self != AccessType::ReadOnly }
+    /// Access type of a resource accessed in both ways.
+    pub fn union(self, other: AccessType) -> AccessType {
+        if self == other { self } else { AccessType::ReadWrite }
+    }
+}
+
+/// Resource an entry point or a function can refer to.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ResourceLocator {
     Input(Location, Component),
-    Output(Location, Component),
+    Output(Location, Component, Index),
     Descriptor(DescriptorBinding),
 }
 
 // Resolution results.
 
 
+/// Interpolation of an interface variable between vertices.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum Interpolation {
+    #[default]
+    Smooth,
+    Flat,
+    NoPerspective,
+}
+
+/// Where in a pixel an interpolated interface variable is sampled.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum Sampling {
+    #[default]
+    Center,
+    Centroid,
+    Sample,
+}
+
+/// Interpolation and auxiliary decorations of an interface variable. For
+/// members of input/output blocks, decorations of the block variable are
+/// included.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct InterfaceQualifiers {
+    /// Interpolation, by `Flat` or `NoPerspective`.
+    pub interp: Interpolation,
+    /// Sampling location, by `Centroid` or `Sample`.
+    pub sampling: Sampling,
+    /// Whether the variable is per-patch rather than per-vertex, by `Patch`.
+    pub is_patch: bool,
+    /// Whether the variable is decorated by `Invariant`.
+    pub is_invariant: bool,
+    /// Blend equation input index of a fragment output, by `Index`. Outputs
+    /// with index 1 are the second sources of dual-source blending.
+    pub index: u32,
+    /// Whether the variable is decorated by `RelaxedPrecision`.
+    pub is_relaxed_precision: bool,
+}
+
 /// Interface variables resolution result.
 #[derive(Debug)]
 pub struct InterfaceVariableResolution<'a> {
-    /// Location of the current interface variable. It should be noted that
-    /// matrix types can take more than one location.
+    /// First location of the current interface variable. It should be noted
+    /// that matrix, array and 64-bit vector types can take more than one
+    /// location, see `locations`.
     pub location: Location,
     /// First component used by the interface variable.
     pub component: Component,
     /// Type of the resolution target.
     pub ty: &'a Type,
+    /// Interpolation and auxiliary decorations.
+    pub quals: InterfaceQualifiers,
+    /// Number of vertices of a per-vertex variable of tessellation and
+    /// geometry shaders, like `in vec3 pos[]`. `ty` is then the type of each
+    /// vertex's element. `None` for per-patch and other variables.
+    pub nvertex: Option<u32>,
+}
+impl<'a> InterfaceVariableResolution<'a> {
+    /// Get the range of locations taken by the interface variable.
+    pub fn locations(&self) -> Range<u32> {
+        let location = u32::from(self.location);
+        let nlocation = self.ty.component_masks(self.component.into())
+            .map_or(0, |x| x.len() as u32);
+        location..(location + nlocation)
+    }
+    /// Get the components taken by the interface variable at the location,
+    /// as a bit mask where bit `i` stands for component `i`. 64-bit
+    /// components take two 32-bit components each.
+    pub fn component_mask(&self, location: Location) -> u8 {
+        let i = u32::from(location).wrapping_sub(self.location.into()) as usize;
+        self.ty.component_masks(self.component.into())
+            .and_then(|x| x.get(i).copied())
+            .unwrap_or(0)
+    }
+}
+
+/// An input/output variable recorded in a manifest.
+#[derive(Debug, Clone)]
+pub(crate) struct InterfaceVariable {
+    /// Type of the variable, or of each vertex's element of a per-vertex
+    /// variable.
+    pub(crate) ty: Type,
+    pub(crate) quals: InterfaceQualifiers,
+    pub(crate) nvertex: Option<u32>,
+}
+impl InterfaceVariable {
+    fn resolve(&self, location: Location, component: Component) -> InterfaceVariableResolution<'_> {
+        InterfaceVariableResolution {
+            location,
+            component,
+            ty: &self.ty,
+            quals: self.quals,
+            nvertex: self.nvertex,
+        }
+    }
+}
+
+/// Built-in variable resolution result.
+#[derive(Debug)]
+pub struct BuiltInVariableResolution<'a> {
+    /// The built-in, like `Position`.
+    pub builtin: BuiltIn,
+    /// Type of the built-in variable.
+    pub ty: &'a Type,
 }
 
 /// Descriptor variable resolution result.
@@ -246,19 +674,40 @@ pub struct MemberVariableResolution<'a> {
 /// A set of information used to describe variable typing and routing.
 #[derive(Default, Clone)]
 pub struct Manifest {
-    pub(crate) input_map: HashMap<(Location, Component), Type>,
-    pub(crate) output_map: HashMap<(Location, Component), Type>,
+    pub(crate) input_map: HashMap<(Location, Component), InterfaceVariable>,
+    // Second sources of dual-source blending share locations with the first
+    // sources, so outputs are told apart by their blend equation input
+    // indices.
+    pub(crate) output_map: HashMap<(Location, Component, Index), InterfaceVariable>,
+    pub(crate) builtin_input_map: HashMap<BuiltIn, Type>,
+    pub(crate) builtin_output_map: HashMap<BuiltIn, Type>,
     pub(crate) desc_map: HashMap<DescriptorBinding, DescriptorType>,
+    pub(crate) desc_access_map: HashMap<DescriptorBinding, AccessType>,
+    pub(crate) desc_usage_map: HashMap<DescriptorBinding, Vec<Symbol>>,
     pub(crate) var_name_map: HashMap<String, ResourceLocator>,
+    pub(crate) stages: Vec<ExecutionModel>,
 }
 impl Manifest {
+    /// Execution models of the entry points merged into this manifest, in
+    /// merge order.
+    pub fn stages(&self) -> &[ExecutionModel] { &self.stages }
+    fn mismatch(&self, other: &Manifest, conflict: ManifestConflict) -> Error {
+        let mismatch = ManifestMismatch {
+            conflict,
+            dst_stages: self.stages.clone(),
+            src_stages: other.stages.clone(),
+        };
+        Error::MismatchedManifest(mismatch)
+    }
     /// Merge metadata records in another manifest into the current one IN
     /// ORDER. Inputs of the current manifest will kept; outputs will be
     /// replaced by the `other`'s; and descriptors will be aggregated to contain
-    /// both set of metadata.
+    /// both set of metadata, with the access types combined. Built-in inputs
+    /// and outputs are treated like inputs and outputs.
     pub fn merge(&mut self, other: &Manifest) -> Result<()> {
         use std::collections::hash_map::Entry::{Vacant, Occupied};
         self.output_map = other.output_map.clone();
+        self.builtin_output_map = other.builtin_output_map.clone();
         for (desc_bind, desc_ty) in other.desc_map.iter() {
             match self.desc_map.entry(*desc_bind) {
                 Vacant(entry) => { entry.insert(desc_ty.clone()); },
@@ -267,7 +716,10 @@ impl Manifest {
                         // Merge push constants scattered in different stages.
                         // This match must success.
                         if let DescriptorType::PushConstant(Type::Struct(src_struct_ty)) = desc_ty {
-                            dst_struct_ty.merge(&src_struct_ty)?;
+                            if let Err(name) = dst_struct_ty.merge(src_struct_ty) {
+                                let conflict = ManifestConflict::PushConstantMember(name);
+                                return Err(self.mismatch(other, conflict));
+                            }
                         } else {
                             unreachable!("push constant merging with push constant");
                         }
@@ -277,86 +729,251 @@ impl Manifest {
                         // Just regular descriptor types. Simply compare the
                         // hashes.
                         if hash(entry.get()) != hash(&desc_ty) {
-                            return Err(Error::MismatchedManifest);
+                            let conflict = ManifestConflict::Descriptor(*desc_bind);
+                            return Err(self.mismatch(other, conflict));
                         }
                     }
                 }
             }
         }
+        for (desc_bind, access) in other.desc_access_map.iter() {
+            self.desc_access_map.entry(*desc_bind)
+                .and_modify(|x| *x = x.union(*access))
+                .or_insert(*access);
+        }
+        for (desc_bind, syms) in other.desc_usage_map.iter() {
+            let mut merged = self.desc_usage_map.remove(desc_bind)
+                .unwrap_or_default();
+            merged.extend(syms.iter().cloned());
+            self.desc_usage_map.insert(*desc_bind, Symbol::prune(merged));
+        }
         for (name, locator) in other.var_name_map.iter() {
-            match self.var_name_map.entry(name.to_owned()) {
-                Vacant(entry) => { entry.insert(locator.clone()); },
-                Occupied(entry) => if entry.get() != locator {
+            if let Some(old_locator) = self.var_name_map.get(name) {
+                if old_locator != locator {
                     // Mismatched names are not allowed.
-                    return Err(Error::MismatchedManifest);
-                },
+                    let conflict = ManifestConflict::Name(name.to_owned());
+                    return Err(self.mismatch(other, conflict));
+                }
+            } else {
+                self.var_name_map.insert(name.to_owned(), *locator);
             }
         }
+        self.stages.extend(other.stages.iter().copied());
         Ok(())
     }
-    /// Get the input interface variable type.
-    pub fn get_input<'a>(&'a self, location: Location) -> Option<&'a Type> {
-        self.input_map.get(&(location, 0.into()))
+    /// Get the type of the input interface variable starting at the location.
+    /// If multiple variables are packed in the location, the one taking the
+    /// lowest component is returned. See `input_at` for variables taking more
+    /// than one location.
+    pub fn get_input(&self, location: Location) -> Option<&Type> {
+        (0..4).find_map(|i| self.input_map.get(&(location, i.into())))
+            .map(|x| &x.ty)
+    }
+    /// Get the type of the output interface variable starting at the
+    /// location. If multiple variables are packed in the location, the one
+    /// taking the lowest component is returned. See `output_at` for variables
+    /// taking more than one location.
+    pub fn get_output(&self, location: Location) -> Option<&Type> {
+        (0..4).find_map(|i| self.output_map.get(&(location, i.into(), 0.into())))
+            .map(|x| &x.ty)
+    }
+    /// Get the type of the second-source output of dual-source blending
+    /// starting at the location, i.e., the output decorated by `Index` 1.
+    /// Second-source outputs are not reachable by `get_output` or
+    /// `output_at`, but can be resolved by name with `resolve_output`.
+    pub fn get_dual_src_output(&self, location: Location) -> Option<&Type> {
+        (0..4).find_map(|i| self.output_map.get(&(location, i.into(), 1.into())))
+            .map(|x| &x.ty)
+    }
+    /// Whether any fragment output is a second source of dual-source blending.
+    pub fn is_dual_src_blending(&self) -> bool {
+        self.output_map.keys().any(|x| u32::from(x.2) != 0)
+    }
+    // First-source outputs, i.e., outputs not decorated by a non-zero `Index`.
+    fn first_src_outputs(&self) -> impl Iterator<Item=((Location, Component), &InterfaceVariable)> {
+        self.output_map.iter()
+            .filter(|(x, _)| u32::from(x.2) == 0)
+            .map(|(&(location, component, _), ivar)| ((location, component), ivar))
+    }
+    fn ivar_at<'a, I>(ivars: I, location: Location, component: Component) -> Option<InterfaceVariableResolution<'a>>
+        where I: Iterator<Item=((Location, Component), &'a InterfaceVariable)>
+    {
+        let bit = 1u32.checked_shl(component.into())?;
+        ivars
+            .map(|((loc, comp), ivar)| ivar.resolve(loc, comp))
+            .find(|x| x.component_mask(location) as u32 & bit != 0)
+    }
+    fn ivar_locations_used<'a, I>(ivars: I) -> Vec<(Location, u8)>
+        where I: Iterator<Item=((Location, Component), &'a InterfaceVariable)>
+    {
+        let mut mask_map = HashMap::<u32, u8>::new();
+        for ((location, component), ivar) in ivars {
+            let masks = ivar.ty.component_masks(component.into()).unwrap_or_default();
+            for (i, mask) in masks.into_iter().enumerate() {
+                *mask_map.entry(u32::from(location) + i as u32).or_default() |= mask;
+            }
+        }
+        let mut locations = mask_map.into_iter()
+            .map(|(location, mask)| (location.into(), mask))
+            .collect::<Vec<(Location, u8)>>();
+        locations.sort_by_key(|x| u32::from(x.0));
+        locations
+    }
+    /// Get the input interface variable taking the component at the location,
+    /// which is not necessarily the first location or component of the
+    /// variable.
+    pub fn input_at(&self, location: Location, component: Component) -> Option<InterfaceVariableResolution<'_>> {
+        let inputs = self.input_map.iter().map(|(&key, ivar)| (key, ivar));
+        Self::ivar_at(inputs, location, component)
+    }
+    /// Get the output interface variable taking the component at the
+    /// location, which is not necessarily the first location or component of
+    /// the variable.
+    pub fn output_at(&self, location: Location, component: Component) -> Option<InterfaceVariableResolution<'_>> {
+        Self::ivar_at(self.first_src_outputs(), location, component)
+    }
+    /// List the locations taken by input interface variables in ascending
+    /// order, with the masks of components taken in each location.
+    pub fn input_locations_used(&self) -> Vec<(Location, u8)> {
+        Self::ivar_locations_used(self.input_map.iter().map(|(&key, ivar)| (key, ivar)))
+    }
+    /// List the locations taken by output interface variables in ascending
+    /// order, with the masks of components taken in each location.
+    pub fn output_locations_used(&self) -> Vec<(Location, u8)> {
+        Self::ivar_locations_used(self.first_src_outputs())
     }
-    /// Get the output interface variable type.
-    pub fn get_output<'a>(&'a self, location: Location) -> Option<&'a Type> {
-        self.output_map.get(&(location, 0.into()))
+    /// Get the type of a built-in input variable, if it's used.
+    pub fn get_input_builtin(&self, builtin: BuiltIn) -> Option<&Type> {
+        self.builtin_input_map.get(&builtin)
+    }
+    /// Get the type of a built-in output variable, if it's used.
+    pub fn get_output_builtin(&self, builtin: BuiltIn) -> Option<&Type> {
+        self.builtin_output_map.get(&builtin)
     }
     /// Get the descriptor type at the given descriptor binding point.
-    pub fn get_desc<'a>(&'a self, desc_bind: DescriptorBinding) -> Option<&'a DescriptorType> {
+    pub fn get_desc(&self, desc_bind: DescriptorBinding) -> Option<&DescriptorType> {
         self.desc_map.get(&desc_bind)
     }
+    /// Get how the descriptor at the given descriptor binding is accessed.
+    /// Accesses are collected from loads, stores, atomics and image
+    /// instructions, and limited by `NonReadable` and `NonWritable`
+    /// decorations. Descriptors only statically used without being read or
+    /// written are reported as `ReadOnly`, unless they are not readable.
+    pub fn get_desc_access(&self, desc_bind: DescriptorBinding) -> Option<AccessType> {
+        self.desc_access_map.get(&desc_bind).copied()
+    }
+    /// Get the members of the buffer at the given descriptor binding that are
+    /// statically read or written, with symbols relative to the buffer. A
+    /// member is reported as a whole when it's indexed dynamically, like an
+    /// array indexed by a variable, or when it's accessed through a vector or
+    /// matrix component. An empty symbol means the entire buffer is used.
+    ///
+    /// `None` is returned if there is no buffer at the binding.
+    pub fn get_desc_used_members(&self, desc_bind: DescriptorBinding) -> Option<Vec<MemberVariableRouting<'_>>> {
+        let desc_ty = self.desc_map.get(&desc_bind)?;
+        let syms = self.desc_usage_map.get(&desc_bind)?;
+        let members = syms.iter()
+            .filter_map(|sym| {
+                let res = desc_ty.resolve(&**sym)?;
+                let routing = MemberVariableRouting {
+                    sym: sym.clone(),
+                    offset: res.offset,
+                    ty: res.ty,
+                };
+                Some(routing)
+            })
+            .collect();
+        Some(members)
+    }
+    /// Get the byte ranges of the buffer at the given descriptor binding that
+    /// are statically read or written, sorted and with overlapping ranges
+    /// merged. Ranges covering runtime arrays extend to `usize::MAX`.
+    ///
+    /// `None` is returned if there is no buffer at the binding.
+    pub fn get_desc_used_ranges(&self, desc_bind: DescriptorBinding) -> Option<Vec<Range<usize>>> {
+        let mut ranges = self.get_desc_used_members(desc_bind)?
+            .into_iter()
+            .map(|x| {
+                let end = if x.ty.is_unsized() {
+                    usize::MAX
+                } else {
+                    x.offset + x.ty.nbyte().unwrap_or(0)
+                };
+                x.offset..end
+            })
+            .collect::<Vec<_>>();
+        ranges.sort_by_key(|x| x.start);
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => {
+                    last.end = last.end.max(range.end);
+                },
+                _ => merged.push(range),
+            }
+        }
+        Some(merged)
+    }
     /// Get the name that also refers to the input at the given location.
-    pub fn get_input_name<'a>(&'a self, location: Location) -> Option<&'a str> {
+    pub fn get_input_name(&self, location: Location) -> Option<&str> {
         self.var_name_map.iter()
             .find_map(|x| if let ResourceLocator::Input(loc, _comp) = x.1 {
                 if *loc == location { Some(x.0.as_ref()) } else { None }
             } else { None })
     }
     /// Get the name that also refers to the output at the given location.
-    pub fn get_output_name<'a>(&'a self, location: Location) -> Option<&'a str> {
+    pub fn get_output_name(&self, location: Location) -> Option<&str> {
         self.var_name_map.iter()
-            .find_map(|x| if let ResourceLocator::Output(loc, _comp) = x.1 {
-                if *loc == location { Some(x.0.as_ref()) } else { None }
+            .find_map(|x| if let ResourceLocator::Output(loc, _comp, idx) = x.1 {
+                if *loc == location && u32::from(*idx) == 0 { Some(x.0.as_ref()) } else { None }
             } else { None })
     }
     /// Get the name that also refers to the descriptor at the given descriptor
     /// binding.
-    pub fn get_desc_name<'a>(&'a self, desc_bind: DescriptorBinding) -> Option<&'a str> {
+    pub fn get_desc_name(&self, desc_bind: DescriptorBinding) -> Option<&str> {
         self.var_name_map.iter()
             .find_map(|x| if let ResourceLocator::Descriptor(db) = x.1 {
                 if *db == desc_bind { Some(x.0.as_ref()) } else { None }
             } else { None })
     }
-    fn resolve_ivar<'a>(&self, map: &'a HashMap<(Location, Component), Type>, sym: &Sym) -> Option<InterfaceVariableResolution<'a>> {
-        let mut segs = sym.segs();
-        let (location, component) = match segs.next() {
-            // TODO: Should component ever be non-zero here?
-            Some(Seg::Index(location)) => ((location as u32).into(), 0.into()),
-            Some(Seg::Name(name)) => {
-                if let Some(ResourceLocator::Input(location, component)) = self.var_name_map.get(name) {
-                    (*location, *component)
-                } else { return None }
-            },
-            _ => return None,
+    fn resolve_ivar(&self, sym: &Sym, is_input: bool) -> Option<InterfaceVariableResolution<'_>> {
+        // Names can be dotted for the members of input/output blocks, like
+        // `vs_out.uv`, so the entire symbol is looked up first.
+        let named = match self.var_name_map.get(AsRef::<str>::as_ref(sym)) {
+            Some(ResourceLocator::Input(location, component)) if is_input => Some((*location, *component, 0.into())),
+            Some(ResourceLocator::Output(location, component, index)) if !is_input => Some((*location, *component, *index)),
+            _ => None,
+        };
+        let (location, component, index) = if let Some(x) = named { x } else {
+            let mut segs = sym.segs();
+            let x = match segs.next() {
+                // TODO: Should component ever be non-zero here?
+                Some(Seg::Index(location)) => ((location as u32).into(), 0.into(), 0.into()),
+                _ => return None,
+            };
+            if segs.next().is_some() { return None }
+            x
+        };
+        let ivar = if is_input {
+            self.input_map.get(&(location, component))?
+        } else {
+            self.output_map.get(&(location, component, index))?
         };
-        if segs.next().is_some() { return None }
-        let ty = map.get(&(location, component))?;
-        let ivar_res = InterfaceVariableResolution { location, component, ty };
-        Some(ivar_res)
+        Some(ivar.resolve(location, component))
     }
     /// Get the metadata of a input variable identified by a symbol.
-    pub fn resolve_input<S: AsRef<Sym>>(&self, sym: S) -> Option<InterfaceVariableResolution> {
-        self.resolve_ivar(&self.output_map, sym.as_ref())
+    pub fn resolve_input<S: AsRef<Sym>>(&self, sym: S) -> Option<InterfaceVariableResolution<'_>> {
+        self.resolve_ivar(sym.as_ref(), true)
     }
-    /// Get the metadata of a output variable identified by a symbol.
-    pub fn resolve_output<S: AsRef<Sym>>(&self, sym: S) -> Option<InterfaceVariableResolution> {
-        self.resolve_ivar(&self.input_map, sym.as_ref())
+    /// Get the metadata of a output variable identified by a symbol. Second
+    /// sources of dual-source blending can only be resolved by name.
+    pub fn resolve_output<S: AsRef<Sym>>(&self, sym: S) -> Option<InterfaceVariableResolution<'_>> {
+        self.resolve_ivar(sym.as_ref(), false)
     }
     /// Get the metadata of a descriptor variable identified by a symbol.
     /// If the exact variable cannot be resolved, the descriptor part of the
     /// resolution will still be returned, if possible.
-    pub fn resolve_desc<S: AsRef<Sym>>(&self, sym: S) -> Option<DescriptorResolution> {
+    pub fn resolve_desc<S: AsRef<Sym>>(&self, sym: S) -> Option<DescriptorResolution<'_>> {
         let mut segs = sym.as_ref().segs();
         let desc_bind = match segs.next() {
             Some(Seg::Index(desc_set)) => {
@@ -385,16 +1002,25 @@ impl Manifest {
     /// List all input locations
     pub fn inputs<'a>(&'a self) -> impl Iterator<Item=InterfaceVariableResolution<'a>> {
         self.input_map.iter()
-            .map(|(&(location, component), ty)| {
-                InterfaceVariableResolution { location, component, ty }
-            })
+            .map(|(&(location, component), ivar)| ivar.resolve(location, component))
     }
-    /// List all output locations in this manifest.
+    /// List all output locations in this manifest. Second-source outputs of
+    /// dual-source blending are included and have a non-zero `quals.index`.
     pub fn outputs<'a>(&'a self) -> impl Iterator<Item=InterfaceVariableResolution<'a>> {
         self.output_map.iter()
-            .map(|(&(location, component), ty)|  {
-                InterfaceVariableResolution { location, component, ty }
-            })
+            .map(|(&(location, component, _), ivar)| ivar.resolve(location, component))
+    }
+    /// List all built-in inputs used, including the members of built-in blocks
+    /// like `gl_PerVertex`.
+    pub fn inputs_builtin<'a>(&'a self) -> impl Iterator<Item=BuiltInVariableResolution<'a>> {
+        self.builtin_input_map.iter()
+            .map(|(&builtin, ty)| BuiltInVariableResolution { builtin, ty })
+    }
+    /// List all built-in outputs used, including the members of built-in
+    /// blocks like `gl_PerVertex`.
+    pub fn outputs_builtin<'a>(&'a self) -> impl Iterator<Item=BuiltInVariableResolution<'a>> {
+        self.builtin_output_map.iter()
+            .map(|(&builtin, ty)| BuiltInVariableResolution { builtin, ty })
     }
     /// List all descriptors in this manifest. Results will not contain anything
     /// about exact variables in buffers.
@@ -407,8 +1033,209 @@ impl Manifest {
 }
 
 
+// SPIR-V module-level information.
+
+/// Features a SPIR-V module declares to use, which should be checked against
+/// the device before a pipeline is created.
+#[derive(Debug, Clone)]
+pub struct ModuleInfo {
+    /// Capabilities declared by `OpCapability`. Capabilities unknown to
+    /// `spirv_headers` are left out, see `caps_raw`.
+    pub caps: Vec<Capability>,
+    /// Raw values of all capabilities declared by `OpCapability`, including
+    /// the ones newer than `spirv_headers`.
+    pub caps_raw: Vec<u32>,
+    /// Extensions declared by `OpExtension`, like `SPV_KHR_multiview`.
+    pub exts: Vec<String>,
+    /// Extended instruction sets imported by `OpExtInstImport`, like
+    /// `GLSL.std.450`.
+    pub ext_inst_imports: Vec<String>,
+    /// Addressing model declared by `OpMemoryModel`.
+    pub addr_model: AddressingModel,
+    /// Memory model declared by `OpMemoryModel`.
+    pub mem_model: MemoryModel,
+}
+impl ModuleInfo {
+    /// Whether the module declares the capability.
+    pub fn has_cap(&self, cap: Capability) -> bool { self.caps.contains(&cap) }
+    /// Whether the module declares the capability of the raw value.
+    pub fn has_cap_raw(&self, cap: u32) -> bool { self.caps_raw.contains(&cap) }
+    /// Whether the module declares the extension.
+    pub fn has_ext(&self, ext: &str) -> bool { self.exts.iter().any(|x| x == ext) }
+}
+
+// Function call graph.
+
+/// A function defined in a SPIR-V module.
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    /// Result id of the `OpFunction`.
+    pub func_id: u32,
+    /// Name of the function, if debug information is kept.
+    pub name: Option<String>,
+    /// Return type of the function, `None` for `void`. Types unknown to SPIR-Q
+    /// are also reported as `None`.
+    pub return_ty: Option<Type>,
+    /// Types of the parameters in order. Pointers are reported as the types
+    /// they point to.
+    pub param_tys: Vec<Option<Type>>,
+    /// Functions calling this function.
+    pub callers: Vec<u32>,
+    /// Functions called by this function.
+    pub callees: Vec<u32>,
+    /// Execution models and names of the entry points calling this function
+    /// directly or indirectly. An entry point function lists its own entry
+    /// points. Names alone are ambiguous since entry points of different
+    /// execution models can share a name.
+    pub entry_points: Vec<(ExecutionModel, String)>,
+    /// Resources referred to in the function body, including those passed to
+    /// callees as arguments.
+    pub direct_rscs: Vec<ResourceLocator>,
+    /// Resources accessed by the function and all the functions it calls
+    /// directly or indirectly.
+    pub transitive_rscs: Vec<ResourceLocator>,
+}
+impl FunctionInfo {
+    /// Whether the function can be reached from any entry point.
+    pub fn is_reachable(&self) -> bool { !self.entry_points.is_empty() }
+}
+
+/// Functions defined in a SPIR-V module and how they call each other.
+#[derive(Debug, Default, Clone)]
+pub struct CallGraph {
+    /// Functions in declaration order.
+    pub funcs: Vec<FunctionInfo>,
+}
+impl CallGraph {
+    /// Get the function by its id.
+    pub fn get_func(&self, func_id: u32) -> Option<&FunctionInfo> {
+        self.funcs.iter().find(|x| x.func_id == func_id)
+    }
+    /// Get the function by name.
+    pub fn get_func_by_name(&self, name: &str) -> Option<&FunctionInfo> {
+        self.funcs.iter().find(|x| x.name.as_deref() == Some(name))
+    }
+    /// List the functions no entry point can reach.
+    pub fn dead_funcs(&self) -> impl Iterator<Item=&FunctionInfo> {
+        self.funcs.iter().filter(|x| !x.is_reachable())
+    }
+}
+
+// Constants.
+
+/// A constant declared in a SPIR-V module.
+#[derive(Debug, Clone)]
+pub struct ConstantInfo {
+    /// Result id of the constant.
+    pub const_id: u32,
+    /// Name of the constant, if debug information is kept.
+    pub name: Option<String>,
+    /// Type of the constant.
+    pub ty: Type,
+    /// Value of the constant.
+    pub value: ConstantValue,
+    /// Whether the constant is declared as a specialization constant,
+    /// including composites and `OpSpecConstantOp` expressions.
+    pub is_spec: bool,
+}
+
+/// Constants declared in a SPIR-V module. Constants of types SPIR-Q doesn't
+/// support, or whose values can't be evaluated, are left out.
+#[derive(Debug, Default, Clone)]
+pub struct ConstantTable {
+    /// Constants in declaration order.
+    pub consts: Vec<ConstantInfo>,
+}
+impl ConstantTable {
+    /// Get the constant by its id.
+    pub fn get_const(&self, const_id: u32) -> Option<&ConstantInfo> {
+        self.consts.iter().find(|x| x.const_id == const_id)
+    }
+    /// Get the constant by name.
+    pub fn get_const_by_name(&self, name: &str) -> Option<&ConstantInfo> {
+        self.consts.iter().find(|x| x.name.as_deref() == Some(name))
+    }
+}
+
 // SPIR-V program entry points.
 
+/// Typed operands of an execution mode.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ExecutionModeValue {
+    /// The execution mode takes no operand, like `OriginUpperLeft` or
+    /// `EarlyFragmentTests`.
+    Flag,
+    /// Workgroup size by `LocalSize` or `LocalSizeId`.
+    LocalSize(u32, u32, u32),
+    /// Workgroup size hint by `LocalSizeHint` or `LocalSizeHintId`.
+    LocalSizeHint(u32, u32, u32),
+    /// Number of geometry shader invocations by `Invocations`.
+    Invocations(u32),
+    /// Number of vertices in the output patch of a tessellation control
+    /// shader, or the maximum number of vertices a geometry or mesh shader
+    /// emits, by `OutputVertices`.
+    OutputVertices(u32),
+    /// Maximum number of primitives a mesh shader emits, by
+    /// `OutputPrimitivesNV`.
+    OutputPrimitives(u32),
+    /// Subgroup size by `SubgroupSize`.
+    SubgroupSize(u32),
+    /// Number of subgroups in a workgroup by `SubgroupsPerWorkgroup` or
+    /// `SubgroupsPerWorkgroupId`.
+    SubgroupsPerWorkgroup(u32),
+    /// Floating-point width the float controls like `DenormPreserve` and
+    /// `RoundingModeRTE` apply to.
+    TargetWidth(u32),
+    /// Encoded vector type hint by `VecTypeHint`.
+    VecTypeHint(u32),
+    /// Execution modes with operands not interpreted by SPIR-Q, or with an
+    /// unexpected number of operands. See `ExecutionModeDecl::operands` for
+    /// the raw operands.
+    Other,
+}
+impl ExecutionModeValue {
+    fn new(exec_mode: ExecutionMode, operands: &[u32]) -> ExecutionModeValue {
+        use ExecutionMode::*;
+        match (exec_mode, operands) {
+            (LocalSize, &[x, y, z]) | (LocalSizeId, &[x, y, z]) =>
+                ExecutionModeValue::LocalSize(x, y, z),
+            (LocalSizeHint, &[x, y, z]) | (LocalSizeHintId, &[x, y, z]) =>
+                ExecutionModeValue::LocalSizeHint(x, y, z),
+            (Invocations, &[x]) => ExecutionModeValue::Invocations(x),
+            (OutputVertices, &[x]) => ExecutionModeValue::OutputVertices(x),
+            (OutputPrimitivesNV, &[x]) => ExecutionModeValue::OutputPrimitives(x),
+            (SubgroupSize, &[x]) => ExecutionModeValue::SubgroupSize(x),
+            (SubgroupsPerWorkgroup, &[x]) | (SubgroupsPerWorkgroupId, &[x]) =>
+                ExecutionModeValue::SubgroupsPerWorkgroup(x),
+            (DenormPreserve, &[x]) | (DenormFlushToZero, &[x]) |
+            (SignedZeroInfNanPreserve, &[x]) | (RoundingModeRTE, &[x]) |
+            (RoundingModeRTZ, &[x]) => ExecutionModeValue::TargetWidth(x),
+            (VecTypeHint, &[x]) => ExecutionModeValue::VecTypeHint(x),
+            (_, &[]) => ExecutionModeValue::Flag,
+            _ => ExecutionModeValue::Other,
+        }
+    }
+}
+
+/// Execution mode declared for an entry point.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ExecutionModeDecl {
+    /// The execution mode.
+    pub exec_mode: ExecutionMode,
+    /// Typed operands of the execution mode.
+    pub value: ExecutionModeValue,
+    /// Literal operands of the execution mode, like the number of output
+    /// vertices of `OutputVertices`. Operands of `OpExecutionModeId` are
+    /// constant ids in SPIR-V, which are resolved to their values here.
+    pub operands: Vec<u32>,
+}
+impl ExecutionModeDecl {
+    pub(crate) fn new(exec_mode: ExecutionMode, operands: Vec<u32>) -> ExecutionModeDecl {
+        let value = ExecutionModeValue::new(exec_mode, &operands);
+        ExecutionModeDecl { exec_mode, value, operands }
+    }
+}
+
 /// Representing an entry point described in a SPIR-V.
 #[derive(Clone)]
 pub struct EntryPoint {
@@ -419,6 +1246,54 @@ pub struct EntryPoint {
     /// Manifest object that contains input, output and descriptor type
     /// information.
     pub manifest: Manifest,
+    /// Execution modes declared for the entry point, in declaration order.
+    pub exec_modes: Vec<ExecutionModeDecl>,
+    /// Specialization constants used by the entry point, in declaration order.
+    /// A constant is used if the functions called by the entry point, the
+    /// types of the variables it uses, or its execution modes refer to it,
+    /// directly or through other constants and types.
+    pub spec_consts: Vec<SpecConstant>,
+}
+impl EntryPoint {
+    /// Attach the names stripped from the module to the manifest of this
+    /// entry point, so that resources and struct members can be resolved by
+    /// name again. Names should be attached before manifests are merged.
+    pub fn attach_names(&mut self, sidecar: &NameSidecar) -> Result<()> {
+        sidecar.attach(self)
+    }
+    /// Get the operands of an execution mode, if it's declared.
+    pub fn get_exec_mode(&self, exec_mode: ExecutionMode) -> Option<&[u32]> {
+        self.exec_modes.iter()
+            .find(|x| x.exec_mode == exec_mode)
+            .map(|x| x.operands.as_ref())
+    }
+    /// Get the typed operands of an execution mode, if it's declared.
+    pub fn get_exec_mode_value(&self, exec_mode: ExecutionMode) -> Option<ExecutionModeValue> {
+        self.exec_modes.iter()
+            .find(|x| x.exec_mode == exec_mode)
+            .map(|x| x.value)
+    }
+    /// Whether an execution mode is declared.
+    pub fn has_exec_mode(&self, exec_mode: ExecutionMode) -> bool {
+        self.get_exec_mode(exec_mode).is_some()
+    }
+    /// Get the specialization constant with the `SpecId`.
+    pub fn get_spec_const(&self, spec_id: u32) -> Option<&SpecConstant> {
+        self.spec_consts.iter().find(|x| x.spec_id == Some(spec_id))
+    }
+    /// Get the specialization constant by name.
+    pub fn get_spec_const_by_name(&self, name: &str) -> Option<&SpecConstant> {
+        self.spec_consts.iter().find(|x| x.name.as_deref() == Some(name))
+    }
+    /// Get the workgroup size of a compute-like entry point, from `LocalSize`,
+    /// `LocalSizeId` or the constant decorated by `WorkgroupSize` built-in,
+    /// whichever is effective. Specialization constants are resolved to their
+    /// default values, or the values given to `reflect_with_specialization`.
+    pub fn local_size(&self) -> Option<(u32, u32, u32)> {
+        let x = self.get_exec_mode_value(ExecutionMode::LocalSize)
+            .or_else(|| self.get_exec_mode_value(ExecutionMode::LocalSizeId))?;
+        if let ExecutionModeValue::LocalSize(x, y, z) = x { Some((x, y, z)) } else { None }
+    }
 }
 impl Deref for EntryPoint {
     type Target = Manifest;
@@ -426,10 +1301,110 @@ impl Deref for EntryPoint {
 }
 impl fmt::Debug for EntryPoint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let inputs = self.manifest.input_map.iter()
+            .map(|(key, ivar)| (key, &ivar.ty))
+            .collect::<HashMap<_, _>>();
+        let outputs = self.manifest.output_map.iter()
+            .map(|(key, ivar)| (key, &ivar.ty))
+            .collect::<HashMap<_, _>>();
         f.debug_struct(&self.name)
-            .field("inputs", &self.manifest.input_map)
-            .field("outputs", &self.manifest.output_map)
+            .field("inputs", &inputs)
+            .field("outputs", &outputs)
             .field("descriptors", &self.manifest.desc_map)
+            .field("exec_modes", &self.exec_modes)
+            .field("spec_consts", &self.spec_consts)
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::convert::TryFrom;
+    use crate::{SpirvBinary, SpirvModule, SpirvHeader, Error};
+
+    const HEADER: [u32; 5] = [0x07230203, 0x00010500, 0x00080001, 16, 0];
+
+    #[test]
+    fn test_header() {
+        let header = SpirvHeader::parse(&HEADER).unwrap();
+        assert_eq!(header.major_version, 1);
+        assert_eq!(header.minor_version, 5);
+        assert_eq!(header.generator_id, 8);
+        assert_eq!(header.generator_version, 1);
+        assert_eq!(header.bound, 16);
+        assert_eq!(header.to_words(), HEADER);
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        assert_eq!(SpirvHeader::parse(&HEADER[..4]), Err(Error::HEADER_TOO_SHORT));
+        let mut words = HEADER;
+        words[0] = 0x07230204;
+        assert_eq!(SpirvHeader::parse(&words), Err(Error::MAGIC_MISMATCH));
+        assert_eq!(SpirvBinary::try_from(&words[..]).unwrap_err(), Error::MAGIC_MISMATCH);
+        let mut words = HEADER;
+        words[1] = 0x00020000;
+        assert_eq!(SpirvHeader::parse(&words), Err(Error::UNSUPPORTED_VERSION));
+        assert_eq!(SpirvBinary::try_from(&words[..]).unwrap_err(), Error::UNSUPPORTED_VERSION);
+
+        let bytes = HEADER.iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<u8>>();
+        assert_eq!(SpirvBinary::try_from(&bytes[..19]).unwrap_err(), Error::UNALIGNED_BINARY);
+        let mut padded = bytes.clone();
+        padded.push(0);
+        assert_eq!(SpirvBinary::try_from(padded).unwrap_err(), Error::UNALIGNED_BINARY);
+        assert_eq!(SpirvBinary::try_from(&bytes[..16]).unwrap_err(), Error::HEADER_TOO_SHORT);
+        assert_eq!(SpirvBinary::try_from(&[][..] as &[u8]).unwrap_err(), Error::HEADER_TOO_SHORT);
+        assert_eq!(SpirvBinary::try_from(&[][..] as &[u32]).unwrap_err(), Error::HEADER_TOO_SHORT);
+    }
+
+    #[test]
+    fn test_byte_swapped_header() {
+        let words = HEADER.iter()
+            .map(|x| x.swap_bytes())
+            .collect::<Vec<u32>>();
+        // A bare header doesn't know its endianness.
+        assert_eq!(SpirvHeader::parse(&words), Err(Error::MAGIC_MISMATCH));
+        // But binaries are corrected by the magic number.
+        let spv = SpirvBinary::try_from(words).unwrap();
+        assert_eq!(spv.words(), &HEADER[..]);
+        assert_eq!(spv.header().unwrap(), SpirvHeader::parse(&HEADER).unwrap());
+    }
+
+    #[test]
+    fn test_module_from_bytes() {
+        // Over-allocate words so that the bytes can be sliced both aligned
+        // and unaligned.
+        let mut buf = [0u32; 6];
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, 24)
+        };
+        for (i, word) in HEADER.iter().enumerate() {
+            bytes[i * 4..i * 4 + 4].copy_from_slice(&word.to_ne_bytes());
+        }
+        let module = SpirvModule::try_from(&bytes[..20]).unwrap();
+        assert!(matches!(module.0, Cow::Borrowed(_)));
+        assert_eq!(module.words(), &HEADER[..]);
+
+        bytes.copy_within(..20, 1);
+        let module = SpirvModule::try_from(&bytes[1..21]).unwrap();
+        assert!(matches!(module.0, Cow::Owned(_)));
+        assert_eq!(module.words(), &HEADER[..]);
+    }
+
+    #[test]
+    fn test_module_from_big_endian_bytes() {
+        let bytes = HEADER.iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect::<Vec<u8>>();
+        let module = SpirvModule::try_from(&bytes[..]).unwrap();
+        assert_eq!(module.words(), &HEADER[..]);
+        let bytes = HEADER.iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let module = SpirvModule::try_from(&bytes[..]).unwrap();
+        assert_eq!(module.words(), &HEADER[..]);
+    }
+}
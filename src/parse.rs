@@ -1,28 +1,47 @@
+//! Instruction-level access to SPIR-V modules.
 use num_traits::FromPrimitive;
-use super::{Error, Result};
+use spirv_headers::Op;
+use super::{Error, Result, SpirvHeader};
+use crate::grammar::{self, OperandKind};
 
-pub struct Instrs<'a>(&'a [u32]);
+/// Iterator over the instructions of a SPIR-V module, following the module
+/// header.
+///
+/// The iteration stops at the end of module or where an instruction has an
+/// invalid word count. In the latter case [`remaining`](#method.remaining) is
+/// not empty.
+#[derive(Clone)]
+pub struct Instrs<'a> {
+    words: &'a [u32],
+    offset: usize,
+}
 impl<'a> Instrs<'a> {
+    /// Iterate over the instructions in the module words `spv`. The header is
+    /// skipped.
     pub fn new(spv: &'a [u32]) -> Instrs<'a> {
-        const HEADER_LEN: usize = 5;
-        if spv.len() < HEADER_LEN { return Instrs(&[] as &[u32]) }
-        Instrs(&spv[HEADER_LEN..])
+        const HEADER_LEN: usize = SpirvHeader::WORD_COUNT;
+        if spv.len() < HEADER_LEN { return Instrs { words: &[], offset: 0 } }
+        Instrs { words: &spv[HEADER_LEN..], offset: HEADER_LEN }
     }
+    /// Words that have not been iterated over yet.
+    pub fn remaining(&self) -> &'a [u32] { self.words }
 }
 impl<'a> Iterator for Instrs<'a> {
     type Item = Instr<'a>;
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(head) = self.0.first() {
-            let len = ((*head as u32) >> 16) as usize;
-            if len <= self.0.len() {
+        if let Some(head) = self.words.first() {
+            let len = (*head >> 16) as usize;
+            // Force break the instruction stream if any invalid word count is
+            // spotted.
+            if len != 0 && len <= self.words.len() {
                 let opcode = head & 0xFFFF;
                 let instr = Instr {
-                    // Force break the instruction stream if any invalid opcode
-                    // is spotted.
-                    opcode: opcode,
-                    operands: &self.0[1..len],
+                    opcode,
+                    offset: self.offset,
+                    operands: &self.words[1..len],
                 };
-                self.0 = &self.0[len..];
+                self.words = &self.words[len..];
+                self.offset += len;
                 return Some(instr);
             }
         }
@@ -31,17 +50,43 @@ impl<'a> Iterator for Instrs<'a> {
 }
 
 
-#[derive(Debug)]
+/// A single SPIR-V instruction.
+///
+/// Instructions spirq understands can be decoded into typed operand structs in
+/// [`instr`](../instr/index.html) with `TryFrom`, or matched by opcode with
+/// `DecodedInstr`; the others can still be read word by word with
+/// [`operands`](#method.operands).
+#[derive(Debug, Clone, Copy)]
 pub struct Instr<'a> {
     opcode: u32,
+    offset: usize,
     operands: &'a [u32],
 }
 impl<'a> Instr<'a> {
     /// Get the opcode of the instruction.
     pub fn opcode(&self) -> u32 { self.opcode }
+    /// Get the opcode of the instruction as an enumeration, if it's known to
+    /// `spirv_headers`.
+    pub fn op(&self) -> Option<Op> { Op::from_u32(self.opcode) }
+    /// Get the offset of the instruction in words, from the beginning of
+    /// module, including the header.
+    pub fn offset(&self) -> usize { self.offset }
     /// Get the word count of the instruction, including the first word
     /// containing the word count and opcode.
-    pub fn _word_count(&self) -> usize { self.operands.len() + 1 }
+    pub fn word_count(&self) -> usize { self.operands.len() + 1 }
+    /// Get the result type id of the instruction, if it has one.
+    pub fn result_type_id(&self) -> Option<u32> {
+        self.id_operand(OperandKind::IdResultType)
+    }
+    /// Get the result id of the instruction, if it has one.
+    pub fn result_id(&self) -> Option<u32> {
+        self.id_operand(OperandKind::IdResult)
+    }
+    fn id_operand(&self, kind: OperandKind) -> Option<u32> {
+        id_operand(self.opcode, self.operands, kind)
+    }
+    /// Get the raw operand words of the instruction.
+    pub fn operand_words(&self) -> &'a [u32] { self.operands }
     /// Get an instruction operand reader. The reader does NO boundary checking
     /// so the user code MUST make sure the implementation follows the
     /// specification.
@@ -50,9 +95,109 @@ impl<'a> Instr<'a> {
     }
 }
 
+/// Find the result type id or result id in the operands of an instruction.
+pub(crate) fn id_operand(opcode: u32, operands: &[u32], kind: OperandKind) -> Option<u32> {
+    // Result type and result ids always lead the operands.
+    let instr_grammar = grammar::instr_by_opcode(opcode)?;
+    let i = instr_grammar.operands.iter()
+        .take(2)
+        .position(|(x, _)| *x == kind)?;
+    operands.get(i).copied()
+}
+
+/// Collect the ids an instruction refers to, including the result type id but
+/// not the result id. Unknown instructions don't refer to any id. The
+/// collection stops at context-dependent literals, like the case literals of
+/// `OpSwitch`, whose widths depend on the types of other operands; only labels
+/// follow them. It also stops at operands that can't be decoded, like
+/// enumerants unknown to the grammar, keeping the ids collected before them.
+pub(crate) fn ref_ids(opcode: u32, operands: &[u32]) -> Vec<u32> {
+    use grammar::{OperandGrammar, Quantifier};
+    use OperandKind::*;
+    // Returns `false` if the collection has to stop.
+    fn collect_one(kind: OperandKind, operands: &mut Operands, out: &mut Vec<u32>) -> Result<bool> {
+        match kind {
+            IdResult => { operands.read_u32()?; },
+            IdResultType | IdRef | IdScope | IdMemorySemantics => out.push(operands.read_u32()?),
+            LiteralInteger | LiteralExtInstInteger => { operands.read_u32()?; },
+            LiteralString => { operands.read_str()?; },
+            LiteralContextDependentNumber => return Ok(false),
+            LiteralSpecConstantOpInteger => {
+                let opcode = operands.read_u32()?;
+                let grammar = grammar::instr_by_opcode(opcode).ok_or(Error::UNENCODED_ENUM)?;
+                let params = grammar.operands.iter()
+                    .skip_while(|x| x.0 == IdResultType || x.0 == IdResult)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                return collect(&params, operands, out);
+            },
+            PairLiteralIntegerIdRef => {
+                operands.read_u32()?;
+                out.push(operands.read_u32()?);
+            },
+            PairIdRefLiteralInteger => {
+                out.push(operands.read_u32()?);
+                operands.read_u32()?;
+            },
+            PairIdRefIdRef => {
+                out.push(operands.read_u32()?);
+                out.push(operands.read_u32()?);
+            },
+            _ => {
+                let enumerants = grammar::enumerants(kind).ok_or(Error::UNENCODED_ENUM)?;
+                let value = operands.read_u32()?;
+                // Parameters of bit enumerants follow in the order of values.
+                let params = enumerants.iter()
+                    .filter(|e| if grammar::is_bit_enum(kind) {
+                        e.value != 0 && value & e.value == e.value
+                    } else {
+                        e.value == value
+                    })
+                    .map(|e| e.params)
+                    .collect::<Vec<_>>();
+                for params in params {
+                    if !collect(params, operands, out)? { return Ok(false); }
+                }
+            },
+        }
+        Ok(true)
+    }
+    fn collect(grammar: &[OperandGrammar], operands: &mut Operands, out: &mut Vec<u32>) -> Result<bool> {
+        for &(kind, quant) in grammar {
+            let keep_going = match quant {
+                Quantifier::One => collect_one(kind, operands, out)?,
+                Quantifier::Optional if operands.is_empty() => true,
+                Quantifier::Optional => collect_one(kind, operands, out)?,
+                Quantifier::Variadic => {
+                    let mut keep_going = true;
+                    while keep_going && !operands.is_empty() {
+                        keep_going = collect_one(kind, operands, out)?;
+                    }
+                    keep_going
+                },
+            };
+            if !keep_going { return Ok(false); }
+        }
+        Ok(true)
+    }
+    let mut out = Vec::new();
+    if let Some(instr_grammar) = grammar::instr_by_opcode(opcode) {
+        // Decoding failures are not fatal, the ids are only used to find
+        // references.
+        let _ = collect(instr_grammar.operands, &mut Operands::new(operands), &mut out);
+    }
+    out
+}
+
+/// Sequential reader of instruction operands.
 pub struct Operands<'a>(&'a [u32]);
 impl<'a> Operands<'a> {
+    pub(crate) fn new(words: &'a [u32]) -> Operands<'a> { Operands(words) }
+    /// Whether all operands have been read.
+    pub fn is_empty(&self) -> bool { self.0.is_empty() }
+    /// Read a word as a boolean, non-zero values are `true`.
     pub fn read_bool(&mut self) -> Result<bool> { self.read_u32().map(|x| x != 0) }
+    /// Read a word.
     pub fn read_u32(&mut self) -> Result<u32> {
         if let Some(x) = self.0.first() {
             self.0 = &self.0[1..];
@@ -61,12 +206,13 @@ impl<'a> Operands<'a> {
             Err(Error::INSTR_TOO_SHORT)
         }
     }
+    /// Read a nul-terminated literal string.
     pub fn read_str(&mut self) -> Result<&'a str> {
         use std::os::raw::c_char;
         use std::ffi::CStr;
         let ptr = self.0.as_ptr() as *const c_char;
         let char_slice = unsafe { std::slice::from_raw_parts(ptr, self.0.len() * 4) };
-        if let Some(nul_pos) = char_slice.into_iter().position(|x| *x == 0) {
+        if let Some(nul_pos) = char_slice.iter().position(|x| *x == 0) {
             let nword = nul_pos / 4 + 1;
             self.0 = &self.0[nword..];
             if let Ok(string) = unsafe { CStr::from_ptr(ptr) }.to_str() {
@@ -75,6 +221,7 @@ impl<'a> Operands<'a> {
         }
         Err(Error::STR_NOT_TERMINATED)
     }
+    /// Read a word as an enumeration value.
     pub fn read_enum<E: FromPrimitive>(&mut self) -> Result<E> {
         self.read_u32()
             .and_then(|x| {
@@ -82,6 +229,7 @@ impl<'a> Operands<'a> {
                     .ok_or(Error::UNENCODED_ENUM)
             })
     }
+    /// Read all the remaining words.
     pub fn read_list(&mut self) -> Result<&'a [u32]> {
         let rv = self.0;
         self.0 = &[];
@@ -2,37 +2,146 @@
 use std::convert::{TryFrom};
 use std::collections::{HashMap, HashSet};
 use std::iter::Peekable;
-use std::ops::RangeInclusive;
-use spirv_headers::{Decoration, Dim, StorageClass};
+use num_traits::FromPrimitive;
+use spirv_headers::{BuiltIn, Decoration, Dim, StorageClass};
 use crate::ty::*;
 use crate::consts::*;
-use crate::{Location, DescriptorBinding, SpirvBinary, Instrs, Instr, Manifest,
-    ResourceLocator, ExecutionModel, EntryPoint, Component};
-use crate::error::{Error, Result};
+use crate::parse::{self, Instrs, Instr};
+use crate::{Location, DescriptorBinding, SpirvModule, Manifest,
+    ResourceLocator, ExecutionModel, EntryPoint, Component, ModuleInfo,
+    ExecutionModeDecl, ExecutionModeValue, SpecConstant, SpecValue, ConstantValue, AccessType,
+    InterfaceQualifiers, InterfaceVariable, Interpolation, Sampling,
+    CallGraph, FunctionInfo, ConstantTable, ConstantInfo};
+use crate::sym::{Seg, Symbol};
+use crate::error::{Error, Result, InstrLocation};
 use crate::instr::*;
+use crate::spec::eval_spec_op;
 
 // Intermediate types used in reflection.
 
 #[derive(Debug, Clone)]
-struct Constant<'a> {
+struct Constant {
     ty: InstrId,
-    value: &'a [u32],
+    value: ConstantValue,
 }
 #[derive(Clone)]
 enum Variable {
-    Input(Location, Component, Type),
-    Output(Location, Component, Type),
-    Descriptor(DescriptorBinding, DescriptorType),
+    Input(Location, Component, Type, InterfaceQualifiers),
+    Output(Location, Component, Type, InterfaceQualifiers),
+    // The last field is the access allowed by the type and decorations.
+    Descriptor(DescriptorBinding, DescriptorType, AccessFlags),
+    // Input/output blocks, like `out VS_OUT { vec2 uv; } vs_out;`. Arrays of
+    // blocks have the number of elements, and the members are those of the
+    // first element.
+    Block {
+        is_input: bool,
+        nrepeat: Option<u32>,
+        members: Vec<BlockMember>,
+    },
+    // Built-in variables, or blocks of built-in members like `gl_PerVertex`
+    // with the member indices. Blocks can be arrays of blocks, e.g., `gl_in`.
+    BuiltIn {
+        is_input: bool,
+        is_arrayed: bool,
+        builtins: Vec<(Option<MemberIdx>, BuiltIn, Type)>,
+    },
 }
+// Member of an input/output block, with the location and component assigned.
+#[derive(Clone)]
+struct BlockMember {
+    location: Location,
+    component: Component,
+    ty: Type,
+    name: Option<String>,
+    quals: InterfaceQualifiers,
+}
+// Bit flags of how a variable is accessed. A variable used without being read
+// or written, e.g., passed to a function or only queried for size, has no
+// flag set.
+type AccessFlags = u8;
+const ACCESS_READ: AccessFlags = 1;
+const ACCESS_WRITE: AccessFlags = 2;
+fn to_access_ty(flags: AccessFlags, allowed: AccessFlags) -> AccessType {
+    match flags & allowed {
+        ACCESS_READ => AccessType::ReadOnly,
+        ACCESS_WRITE => AccessType::WriteOnly,
+        0 if allowed & ACCESS_READ == 0 => AccessType::WriteOnly,
+        0 => AccessType::ReadOnly,
+        _ => AccessType::ReadWrite,
+    }
+}
+// Whether the interface variables of the stage in the direction are arrays of
+// per-vertex elements, unless decorated by `Patch`.
+fn is_per_vertex_arrayed(exec_model: ExecutionModel, is_input: bool) -> bool {
+    match exec_model {
+        ExecutionModel::TessellationControl => true,
+        ExecutionModel::TessellationEvaluation | ExecutionModel::Geometry => is_input,
+        _ => false,
+    }
+}
+// Get an operand word of an instruction that is handled together with other
+// opcodes of different layouts, rather than decoded by its own operand struct.
+fn nth_operand(instr: &Instr, i: usize) -> Result<u32> {
+    instr.operand_words().get(i).copied().ok_or(Error::INSTR_TOO_SHORT)
+}
+// Number of locations taken by each element of an array of blocks, from the
+// first location of the members to the end of the last.
+fn block_nlocation(members: &[BlockMember]) -> u32 {
+    let first = members.iter()
+        .map(|x| u32::from(x.location))
+        .min()
+        .unwrap_or(0);
+    members.iter()
+        .map(|x| u32::from(x.location) + x.ty.nlocation().unwrap_or(0))
+        .max()
+        .map_or(0, |end| end - first)
+}
+// Number of vertices of the per-vertex interface variables given by the
+// execution modes. Per-vertex inputs of tessellation shaders are sized by
+// `gl_MaxPatchVertices` instead, which is only known from the array types.
+fn get_nvertex(exec_model: ExecutionModel, exec_modes: &[ExecutionModeDecl], is_input: bool) -> Option<u32> {
+    exec_modes.iter()
+        .find_map(|x| match (exec_model, x.exec_mode) {
+            (ExecutionModel::TessellationControl, ExecutionMode::OutputVertices) if !is_input => {
+                if let ExecutionModeValue::OutputVertices(n) = x.value { Some(n) } else { None }
+            },
+            (ExecutionModel::Geometry, ExecutionMode::InputPoints) => Some(1),
+            (ExecutionModel::Geometry, ExecutionMode::InputLines) => Some(2),
+            (ExecutionModel::Geometry, ExecutionMode::InputLinesAdjacency) => Some(4),
+            (ExecutionModel::Geometry, ExecutionMode::Triangles) => Some(3),
+            (ExecutionModel::Geometry, ExecutionMode::InputTrianglesAdjacency) => Some(6),
+            _ => None,
+        })
+}
+// Indices of the access chains leading to a pointer, `None` for indices that
+// are not constant.
+type AccessPath = Vec<Option<u32>>;
+// Variable or parameter a pointer is derived from, and the path to it.
+type PtrOrigin = (InstrId, AccessPath);
+// Accesses and paths collected from the functions and their callees.
+type AccessMemo = HashMap<FunctionId, HashMap<InstrId, AccessFlags>>;
+type PathMemo = HashMap<FunctionId, HashSet<PtrOrigin>>;
 #[derive(Default, Debug, Clone)]
 struct Function {
-    accessed_vars: HashSet<InstrId>,
-    calls: HashSet<InstrId>,
+    // Variables and parameters accessed in the function, traced back from
+    // the pointers and image handles derived from them.
+    accessed_vars: HashMap<InstrId, AccessFlags>,
+    // Paths into the variables and parameters actually read or written.
+    accessed_paths: HashSet<(InstrId, AccessPath)>,
+    // Callees with the origins of the arguments passed to them. An argument
+    // selected from several pointers has several origins.
+    calls: Vec<(FunctionId, Vec<Vec<PtrOrigin>>)>,
+    return_ty_id: TypeId,
+    // Parameter ids and their types.
+    params: Vec<(InstrId, TypeId)>,
+    // Module-level types, constants and variables referred to in the function.
+    global_ref_ids: HashSet<InstrId>,
 }
 struct EntryPointDeclartion<'a> {
     func_id: u32,
     name: &'a str,
     exec_model: ExecutionModel,
+    interface_ids: &'a [VariableId],
 }
 
 
@@ -46,14 +155,31 @@ type FunctionId = ObjectId;
 
 #[derive(Default)]
 struct ReflectIntermediate<'a> {
+    // SPIR-V version of the module, as `(major, minor)`.
+    version: (u8, u8),
+    // Module-level declarations are only decoded on request, so that
+    // capabilities unknown to `spirv_headers` don't fail reflection.
+    module_info_instrs: Vec<Instr<'a>>,
     entry_point_declrs: Vec<EntryPointDeclartion<'a>>,
+    // Execution modes are resolved after all the constants are populated.
+    exec_mode_instrs: Vec<Instr<'a>>,
     name_map: HashMap<(InstrId, Option<u32>), &'a str>,
     deco_map: HashMap<(InstrId, Option<u32>, Decoration), &'a [u32]>,
     ty_map: HashMap<TypeId, Type>,
     var_map: HashMap<VariableId, Variable>,
-    const_map: HashMap<ConstantId, Constant<'a>>,
+    // Values of constants, with specialization constants specialized.
+    const_map: HashMap<ConstantId, Constant>,
+    // Specialization constants in declaration order.
+    spec_const_ids: Vec<ConstantId>,
+    // Default values of specialization constants and the expressions
+    // depending on them.
+    spec_default_map: HashMap<ConstantId, ConstantValue>,
+    spec_values: HashMap<u32, SpecValue>,
     ptr_map: HashMap<TypeId, TypeId>,
     func_map: HashMap<FunctionId, Function>,
+    // Functions in declaration order.
+    func_ids: Vec<FunctionId>,
+    def_map: HashMap<ObjectId, Instr<'a>>,
 }
 impl<'a> ReflectIntermediate<'a> {
     /// Resolve one recurring layer of pointers to the pointer that refer to the
@@ -70,7 +196,7 @@ impl<'a> ReflectIntermediate<'a> {
     }
     fn get_deco_u32(&self, id: InstrId, member_idx: Option<u32>, deco: Decoration) -> Option<u32> {
         self.deco_map.get(&(id, member_idx, deco))
-            .and_then(|x| x.get(0))
+            .and_then(|x| x.first())
             .cloned()
     }
     fn get_var_location(&self, var_id: VariableId) -> Option<Location> {
@@ -81,6 +207,43 @@ impl<'a> ReflectIntermediate<'a> {
             .unwrap_or(0)
             .into()
     }
+    /// Get the interpolation and auxiliary decorations of an interface
+    /// variable. Decorations of a block member are combined with those of the
+    /// block variable.
+    fn get_var_quals(&self, var_id: VariableId, member: Option<(TypeId, MemberIdx)>) -> InterfaceQualifiers {
+        let has = |deco| {
+            self.contains_deco(var_id, None, deco) ||
+                member.is_some_and(|(struct_ty_id, i)| self.contains_deco(struct_ty_id, Some(i), deco))
+        };
+        let index = self.get_deco_u32(var_id, None, Decoration::Index)
+            .or_else(|| {
+                let (struct_ty_id, i) = member?;
+                self.get_deco_u32(struct_ty_id, Some(i), Decoration::Index)
+            })
+            .unwrap_or(0);
+        let interp = if has(Decoration::Flat) {
+            Interpolation::Flat
+        } else if has(Decoration::NoPerspective) {
+            Interpolation::NoPerspective
+        } else {
+            Interpolation::Smooth
+        };
+        let sampling = if has(Decoration::Sample) {
+            Sampling::Sample
+        } else if has(Decoration::Centroid) {
+            Sampling::Centroid
+        } else {
+            Sampling::Center
+        };
+        InterfaceQualifiers {
+            interp,
+            sampling,
+            is_patch: has(Decoration::Patch),
+            is_invariant: has(Decoration::Invariant),
+            index,
+            is_relaxed_precision: has(Decoration::RelaxedPrecision),
+        }
+    }
     fn get_var_desc_bind_or_default(&self, var_id: VariableId) -> DescriptorBinding {
         let desc_set = self.get_deco_u32(var_id, None, Decoration::DescriptorSet)
             .unwrap_or(0);
@@ -89,63 +252,164 @@ impl<'a> ReflectIntermediate<'a> {
         DescriptorBinding::desc_bind(desc_set, bind_point)
     }
     fn get_name(&self, id: InstrId, member_idx: Option<u32>) -> Option<&'a str> {
-        self.name_map.get(&(id, member_idx))
-            .map(|x| *x)
+        self.name_map.get(&(id, member_idx)).copied()
+    }
+    /// Describe where the instruction is for error reporting.
+    fn locate(&self, instr: &Instr<'a>) -> InstrLocation {
+        let id = instr.result_id().or_else(|| match instr.opcode() {
+            OP_ENTRY_POINT => instr.operand_words().get(1).copied(),
+            OP_NAME | OP_MEMBER_NAME => instr.operand_words().first().copied(),
+            x if ANNOTATION_OPS.contains(&x) => instr.operand_words().first().copied(),
+            _ => None,
+        });
+        let name = id.and_then(|id| self.get_name(id, None))
+            .map(ToOwned::to_owned);
+        InstrLocation { offset: instr.offset(), opcode: instr.opcode(), id, name }
+    }
+    /// Attach the location of the instruction defining `id` to the error.
+    fn err_at_def(&self, id: ObjectId, err: Error) -> Error {
+        if let Some(instr) = self.def_map.get(&id) {
+            err.at(self.locate(instr))
+        } else { err }
     }
     fn populate_entry_points(&mut self, instrs: &'_ mut Peekable<Instrs<'a>>) -> Result<()> {
         while let Some(instr) = instrs.peek() {
-            if instr.opcode() != OP_ENTRY_POINT { break; }
-            let op = OpEntryPoint::try_from(instr)?;
-            let entry_point_declr = EntryPointDeclartion {
-                exec_model: op.exec_model,
-                func_id: op.func_id,
-                name: op.name,
-            };
-            self.entry_point_declrs.push(entry_point_declr);
+            match instr.opcode() {
+                OP_CAPABILITY | OP_EXTENSION | OP_EXT_INST_IMPORT | OP_MEMORY_MODEL |
+                OP_ENTRY_POINT => {
+                    self.populate_one_preamble(instr)
+                        .map_err(|e| e.at(self.locate(instr)))?;
+                },
+                _ => break,
+            }
             instrs.next();
         }
         Ok(())
     }
+    fn populate_one_preamble(&mut self, instr: &Instr<'a>) -> Result<()> {
+        match instr.opcode() {
+            OP_CAPABILITY | OP_EXTENSION | OP_EXT_INST_IMPORT | OP_MEMORY_MODEL => {
+                self.module_info_instrs.push(*instr);
+            },
+            OP_ENTRY_POINT => {
+                let op = OpEntryPoint::try_from(instr)?;
+                let entry_point_declr = EntryPointDeclartion {
+                    exec_model: op.exec_model,
+                    func_id: op.func_id,
+                    name: op.name,
+                    interface_ids: op.interface_ids,
+                };
+                self.entry_point_declrs.push(entry_point_declr);
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+    fn collect_module_info(&self) -> Result<ModuleInfo> {
+        let mut caps = Vec::new();
+        let mut caps_raw = Vec::new();
+        let mut exts = Vec::new();
+        let mut ext_inst_imports = Vec::new();
+        let mut mem_model = None;
+        for instr in self.module_info_instrs.iter() {
+            let res = match instr.opcode() {
+                // Unknown capabilities are kept by value so that newer
+                // modules can still be inspected.
+                OP_CAPABILITY => instr.operands().read_u32()
+                    .map(|cap| {
+                        caps.extend(Capability::from_u32(cap));
+                        caps_raw.push(cap);
+                    }),
+                OP_EXTENSION => OpExtension::try_from(instr)
+                    .map(|op| exts.push(op.name.to_owned())),
+                OP_EXT_INST_IMPORT => OpExtInstImport::try_from(instr)
+                    .map(|op| ext_inst_imports.push(op.name.to_owned())),
+                OP_MEMORY_MODEL => OpMemoryModel::try_from(instr)
+                    .map(|op| mem_model = Some((op.addr_model, op.mem_model))),
+                _ => Ok(()),
+            };
+            res.map_err(|e| e.at(self.locate(instr)))?;
+        }
+        let (addr_model, mem_model) = mem_model
+            .ok_or(Error::MISSING_MEMORY_MODEL)?;
+        let module_info = ModuleInfo {
+            caps,
+            caps_raw,
+            exts,
+            ext_inst_imports,
+            addr_model,
+            mem_model,
+        };
+        Ok(module_info)
+    }
     fn populate_names(&mut self, instrs: &'_ mut Peekable<Instrs<'a>>) -> Result<()> {
         // Extract naming. Names are generally produced as debug information by
         // `glslValidator` but it might be in absence.
         while let Some(instr) = instrs.peek() {
-            let (key, value) = match instr.opcode() {
-                OP_NAME => {
-                    let op = OpName::try_from(instr)?;
-                    ((op.target_id, None), op.name)
-                },
-                OP_MEMBER_NAME => {
-                    let op = OpMemberName::try_from(instr)?;
-                    ((op.target_id, Some(op.member_idx)), op.name)
-                },
-                _ => break,
-            };
-            let collision = self.name_map.insert(key, value);
-            if collision.is_some() { return Err(Error::NAME_COLLISION); }
+            let opcode = instr.opcode();
+            if opcode == OP_NAME || opcode == OP_MEMBER_NAME {
+                self.populate_one_name(instr)
+                    .map_err(|e| e.at(self.locate(instr)))?;
+            } else if opcode == OP_EXECUTION_MODE || opcode == OP_EXECUTION_MODE_ID {
+                self.exec_mode_instrs.push(*instr);
+            } else if !DEBUG_OPS.contains(&opcode) {
+                break;
+            }
             instrs.next();
         }
         Ok(())
     }
+    fn populate_one_name(&mut self, instr: &Instr<'a>) -> Result<()> {
+        let (key, value) = if instr.opcode() == OP_NAME {
+            let op = OpName::try_from(instr)?;
+            ((op.target_id, None), op.name)
+        } else {
+            let op = OpMemberName::try_from(instr)?;
+            ((op.target_id, Some(op.member_idx)), op.name)
+        };
+        let collision = self.name_map.insert(key, value);
+        if collision.is_some() { return Err(Error::NAME_COLLISION); }
+        Ok(())
+    }
     fn populate_decos(&mut self, instrs: &'_ mut Peekable<Instrs<'a>>) -> Result<()> {
         while let Some(instr) = instrs.peek() {
-            let (key, value) = match instr.opcode() {
-                OP_DECORATE => {
-                    let op = OpDecorate::try_from(instr)?;
-                    ((op.target_id, None, op.deco), op.params)
-                }
-                OP_MEMBER_DECORATE => {
-                    let op = OpMemberDecorate::try_from(instr)?;
-                    ((op.target_id, Some(op.member_idx), op.deco), op.params)
-                },
-                _ => break,
-            };
-            let collision = self.deco_map.insert(key, value);
-            if collision.is_some() { return Err(Error::DECO_COLLISION); }
+            let opcode = instr.opcode();
+            if opcode == OP_DECORATE || opcode == OP_MEMBER_DECORATE {
+                self.populate_one_deco(instr)
+                    .map_err(|e| e.at(self.locate(instr)))?;
+            } else if !ANNOTATION_OPS.contains(&opcode) {
+                break;
+            }
             instrs.next();
         }
         Ok(())
     }
+    fn populate_one_deco(&mut self, instr: &Instr<'a>) -> Result<()> {
+        let (key, value) = if instr.opcode() == OP_DECORATE {
+            let op = OpDecorate::try_from(instr)?;
+            ((op.target_id, None, op.deco), op.params)
+        } else {
+            let op = OpMemberDecorate::try_from(instr)?;
+            ((op.target_id, Some(op.member_idx), op.deco), op.params)
+        };
+        let collision = self.deco_map.insert(key, value);
+        if collision.is_some() { return Err(Error::DECO_COLLISION); }
+        Ok(())
+    }
+    /// Whether the type is a struct left out of `ty_map`, like a shader
+    /// input/output block. Arrays of such structs are left out as well.
+    fn is_skipped_struct(&self, ty_id: TypeId) -> bool {
+        if self.ty_map.contains_key(&ty_id) { return false; }
+        match self.def_map.get(&ty_id) {
+            Some(instr) if instr.opcode() == OP_TYPE_STRUCT => true,
+            // The element type follows the result id.
+            Some(instr) if instr.opcode() == OP_TYPE_ARRAY || instr.opcode() == OP_TYPE_RUNTIME_ARRAY => {
+                instr.operand_words().get(1)
+                    .is_some_and(|&x| self.is_skipped_struct(x))
+            },
+            _ => false,
+        }
+    }
     fn populate_one_ty(&mut self, instr: &Instr<'a>) -> Result<()> {
         use std::collections::hash_map::Entry::Vacant;
         let (key, value) = match instr.opcode() {
@@ -205,17 +469,14 @@ impl<'a> ReflectIntermediate<'a> {
             },
             OP_TYPE_ARRAY => {
                 let op = OpTypeArray::try_from(instr)?;
+                if self.is_skipped_struct(op.proto_ty_id) { return Ok(()) }
                 let proto_ty = self.ty_map.get(&op.proto_ty_id)
                     .ok_or(Error::TY_NOT_FOUND)?;
-                let nrepeat = self.const_map.get(&op.nrepeat_const_id)
-                    .and_then(|constant| {
-                        if let Some(Type::Scalar(scalar_ty)) = self.ty_map.get(&constant.ty) {
-                            if scalar_ty.nbyte() == 4 && scalar_ty.is_uint() {
-                                return Some(constant.value[0]);
-                            }
-                        }
-                        None
-                    })
+                // Lengths can be integers of any width and signedness, e.g.,
+                // HLSL compilers use signed lengths.
+                let nrepeat = self.get_const_value(op.nrepeat_const_id)
+                    .and_then(ConstantValue::to_u64)
+                    .and_then(|x| u32::try_from(x).ok())
                     .ok_or(Error::CONST_NOT_FOUND)?;
                 let stride = self.get_deco_u32(op.ty_id, None, Decoration::ArrayStride)
                     .map(|x| x as usize);
@@ -228,6 +489,7 @@ impl<'a> ReflectIntermediate<'a> {
             },
             OP_TYPE_RUNTIME_ARRAY => {
                 let op = OpTypeRuntimeArray::try_from(instr)?;
+                if self.is_skipped_struct(op.proto_ty_id) { return Ok(()) }
                 let proto_ty = self.ty_map.get(&op.proto_ty_id)
                     .ok_or(Error::TY_NOT_FOUND)?;
                 let stride = self.get_deco_u32(op.ty_id, None, Decoration::ArrayStride)
@@ -241,6 +503,9 @@ impl<'a> ReflectIntermediate<'a> {
                 let mut struct_ty = StructType::default();
                 for (i, &member_ty_id) in op.member_ty_ids.iter().enumerate() {
                     let i = i as u32;
+                    // Structs nested in shader input/output blocks are left
+                    // out along with the blocks.
+                    if self.is_skipped_struct(member_ty_id) { return Ok(()) }
                     let mut member_ty = self.ty_map.get(&member_ty_id)
                         .cloned()
                         .ok_or(Error::TY_NOT_FOUND)?;
@@ -270,8 +535,9 @@ impl<'a> ReflectIntermediate<'a> {
                         struct_ty.push_member(member)?;
                     } else {
                         // For shader input/output blocks there are no offset
-                        // decoration. Since these variables are not externally
-                        // accessible we don't have to worry about them.
+                        // decoration. Their members are reflected as separate
+                        // interface variables instead, see
+                        // `get_var_block`.
                         return Ok(())
                     }
                 }
@@ -291,23 +557,455 @@ impl<'a> ReflectIntermediate<'a> {
             entry.insert(value); Ok(())
         } else { Err(Error::ID_COLLISION) }
     }
+    fn decode_const(&self, ty_id: TypeId, words: &[u32]) -> Option<ConstantValue> {
+        if let Some(Type::Scalar(scalar_ty)) = self.ty_map.get(&ty_id) {
+            ConstantValue::from_words(scalar_ty, words)
+        } else { None }
+    }
+    /// Get the value of a constant, specialized if it's a specialization
+    /// constant.
+    fn get_const_value(&self, const_id: ConstantId) -> Option<&ConstantValue> {
+        self.const_map.get(&const_id).map(|x| &x.value)
+    }
+    /// Get the value of a constant as if no specialization constant is
+    /// specialized.
+    fn get_default_value(&self, const_id: ConstantId) -> Option<&ConstantValue> {
+        self.spec_default_map.get(&const_id)
+            .or_else(|| self.get_const_value(const_id))
+    }
+    /// Replace the default value of a specialization constant with the value
+    /// specified by the user, if any.
+    fn specialize(&self, const_id: ConstantId, ty_id: TypeId, default: Option<ConstantValue>) -> Result<Option<ConstantValue>> {
+        let spec_id = match self.get_deco_u32(const_id, None, Decoration::SpecId) {
+            Some(x) => x,
+            None => return Ok(default),
+        };
+        let value = match self.spec_values.get(&spec_id) {
+            Some(x) => x,
+            None => return Ok(default),
+        };
+        if let Some(Type::Scalar(scalar_ty)) = self.ty_map.get(&ty_id) {
+            if let Some(x) = value.cast(scalar_ty) { return Ok(Some(x)); }
+        }
+        Err(Error::MismatchedSpecValue(spec_id))
+    }
     fn populate_one_const(&mut self, instr: &Instr<'a>) -> Result<()> {
         use std::collections::hash_map::Entry::Vacant;
-        if instr.opcode() != OP_CONSTANT { return Ok(()) }
-        let op = OpConstant::try_from(instr)?;
-        let constant = Constant { ty: op.ty_id, value: op.value };
-        if let Vacant(entry) = self.const_map.entry(op.const_id) {
-            entry.insert(constant); Ok(())
-        } else { Err(Error::ID_COLLISION) }
+        fn compose<'b, F>(constituents: &[ConstantId], get: F) -> Option<ConstantValue>
+            where F: Fn(ConstantId) -> Option<&'b ConstantValue>
+        {
+            constituents.iter()
+                .map(|&x| get(x).cloned())
+                .collect::<Option<Vec<_>>>()
+                .map(ConstantValue::Composite)
+        }
+        // Constants of unsupported types are left out. They are reported when
+        // they are actually used, e.g., as an array length.
+        let (ty_id, const_id, value, default) = match instr.opcode() {
+            OP_CONSTANT_TRUE => {
+                let op = OpConstantTrue::try_from(instr)?;
+                (op.ty_id, op.const_id, Some(ConstantValue::Bool(true)), None)
+            },
+            OP_CONSTANT_FALSE => {
+                let op = OpConstantFalse::try_from(instr)?;
+                (op.ty_id, op.const_id, Some(ConstantValue::Bool(false)), None)
+            },
+            OP_CONSTANT => {
+                let op = OpConstant::try_from(instr)?;
+                let value = self.decode_const(op.ty_id, op.value);
+                (op.ty_id, op.const_id, value, None)
+            },
+            OP_CONSTANT_SAMPLER => {
+                let op = OpConstantSampler::try_from(instr)?;
+                let value = ConstantValue::Sampler {
+                    addr_mode: op.addr_mode,
+                    normalized: op.normalized,
+                    filter_mode: op.filter_mode,
+                };
+                (op.ty_id, op.const_id, Some(value), None)
+            },
+            OP_CONSTANT_NULL => {
+                let op = OpConstantNull::try_from(instr)?;
+                let value = self.ty_map.get(&op.ty_id)
+                    .map_or(ConstantValue::Null, ConstantValue::zero);
+                (op.ty_id, op.const_id, Some(value), None)
+            },
+            OP_CONSTANT_COMPOSITE => {
+                let op = OpConstantComposite::try_from(instr)?;
+                let value = compose(op.constituents, |x| self.get_const_value(x));
+                (op.ty_id, op.const_id, value, None)
+            },
+            OP_SPEC_CONSTANT_TRUE => {
+                let op = OpSpecConstantTrue::try_from(instr)?;
+                self.spec_const_ids.push(op.spec_const_id);
+                let default = Some(ConstantValue::Bool(true));
+                let value = self.specialize(op.spec_const_id, op.ty_id, default.clone())?;
+                (op.ty_id, op.spec_const_id, value, default)
+            },
+            OP_SPEC_CONSTANT_FALSE => {
+                let op = OpSpecConstantFalse::try_from(instr)?;
+                self.spec_const_ids.push(op.spec_const_id);
+                let default = Some(ConstantValue::Bool(false));
+                let value = self.specialize(op.spec_const_id, op.ty_id, default.clone())?;
+                (op.ty_id, op.spec_const_id, value, default)
+            },
+            OP_SPEC_CONSTANT => {
+                let op = OpSpecConstant::try_from(instr)?;
+                self.spec_const_ids.push(op.spec_const_id);
+                let default = self.decode_const(op.ty_id, op.value);
+                let value = self.specialize(op.spec_const_id, op.ty_id, default.clone())?;
+                (op.ty_id, op.spec_const_id, value, default)
+            },
+            OP_SPEC_CONSTANT_COMPOSITE => {
+                let op = OpSpecConstantComposite::try_from(instr)?;
+                self.spec_const_ids.push(op.spec_const_id);
+                let value = compose(op.constituents, |x| self.get_const_value(x));
+                let default = compose(op.constituents, |x| self.get_default_value(x));
+                (op.ty_id, op.spec_const_id, value, default)
+            },
+            OP_SPEC_CONSTANT_OP => {
+                let op = OpSpecConstantOp::try_from(instr)?;
+                let ty = self.ty_map.get(&op.ty_id);
+                let get_ty = |x| self.def_map.get(&x)?
+                    .result_type_id()
+                    .and_then(|ty_id| self.ty_map.get(&ty_id));
+                let value = eval_spec_op(op.opcode, ty, op.operands,
+                    |x| Some((self.get_const_value(x)?, get_ty(x))));
+                let default = eval_spec_op(op.opcode, ty, op.operands,
+                    |x| Some((self.get_default_value(x)?, get_ty(x))));
+                (op.ty_id, op.spec_const_id, value, default)
+            },
+            _ => return Ok(()),
+        };
+        if let Some(default) = default {
+            self.spec_default_map.insert(const_id, default);
+        }
+        if let Some(value) = value {
+            if let Vacant(entry) = self.const_map.entry(const_id) {
+                entry.insert(Constant { ty: ty_id, value });
+            } else { return Err(Error::ID_COLLISION); }
+        }
+        Ok(())
+    }
+    /// Get the value of a 32-bit integral scalar constant.
+    fn get_const_u32(&self, const_id: ConstantId) -> Option<u32> {
+        let constant = self.const_map.get(&const_id)?;
+        if let Some(Type::Scalar(scalar_ty)) = self.ty_map.get(&constant.ty) {
+            if scalar_ty.nbyte() == 4 {
+                match constant.value {
+                    ConstantValue::I32(x) => return Some(x as u32),
+                    ConstantValue::U32(x) => return Some(x),
+                    _ => {},
+                }
+            }
+        }
+        None
+    }
+    /// Collect the specialization constants in declaration order, with their
+    /// ids. Constants whose values can't be decoded are listed without default
+    /// values; only the ones of types SPIR-Q doesn't support are left out.
+    fn collect_spec_consts(&self) -> Vec<(ConstantId, SpecConstant)> {
+        self.spec_const_ids.iter()
+            .filter_map(|&const_id| {
+                let ty_id = self.def_map.get(&const_id)?.result_type_id()?;
+                let spec_const = SpecConstant {
+                    spec_id: self.get_deco_u32(const_id, None, Decoration::SpecId),
+                    name: self.get_name(const_id, None).map(ToOwned::to_owned),
+                    ty: self.ty_map.get(&ty_id)?.clone(),
+                    default_value: self.spec_default_map.get(&const_id).cloned(),
+                };
+                Some((const_id, spec_const))
+            })
+            .collect()
+    }
+    /// Collect the constants of supported types in declaration order.
+    fn collect_const_table(&self) -> ConstantTable {
+        let mut consts = self.const_map.iter()
+            .filter_map(|(&const_id, constant)| {
+                let instr = self.def_map.get(&const_id)?;
+                let info = ConstantInfo {
+                    const_id,
+                    name: self.get_name(const_id, None).map(ToOwned::to_owned),
+                    ty: self.ty_map.get(&constant.ty)?.clone(),
+                    value: constant.value.clone(),
+                    is_spec: SPEC_CONST_RANGE.contains(&instr.opcode()),
+                };
+                Some((instr.offset(), info))
+            })
+            .collect::<Vec<_>>();
+        consts.sort_by_key(|x| x.0);
+        let consts = consts.into_iter()
+            .map(|x| x.1)
+            .collect();
+        ConstantTable { consts }
+    }
+    /// Collect the specialization constants the module-level definitions
+    /// refer to, directly or through other types and constants.
+    fn collect_spec_const_refs(&self, ids: impl IntoIterator<Item=ObjectId>) -> Result<HashSet<ConstantId>> {
+        let mut visited = HashSet::new();
+        let mut spec_const_ids = HashSet::new();
+        let mut stack = ids.into_iter().collect::<Vec<_>>();
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) { continue; }
+            let instr = if let Some(x) = self.def_map.get(&id) { x } else { continue };
+            if SPEC_CONST_RANGE.contains(&instr.opcode()) {
+                spec_const_ids.insert(id);
+            }
+            stack.extend(parse::ref_ids(instr.opcode(), instr.operand_words()));
+        }
+        Ok(spec_const_ids)
+    }
+    fn resolve_exec_modes(&self, func_id: FunctionId) -> Result<Vec<ExecutionModeDecl>> {
+        let mut exec_modes = Vec::new();
+        for instr in self.exec_mode_instrs.iter() {
+            let res = if instr.opcode() == OP_EXECUTION_MODE {
+                OpExecutionMode::try_from(instr)
+                    .map(|op| (op.func_id, op.exec_mode, op.params.to_owned()))
+            } else {
+                OpExecutionModeId::try_from(instr)
+                    .and_then(|op| {
+                        let params = op.params.iter()
+                            .map(|&x| self.get_const_u32(x).ok_or(Error::CONST_NOT_FOUND))
+                            .collect::<Result<Vec<_>>>()?;
+                        Ok((op.func_id, op.exec_mode, params))
+                    })
+            };
+            let (target_id, exec_mode, operands) = match res {
+                Ok(x) => x,
+                // Execution modes unknown to `spirv_headers` cannot be
+                // represented. They are ignored like unknown instructions.
+                Err(e) if e == Error::UNENCODED_ENUM => continue,
+                Err(e) => return Err(e.at(self.locate(instr))),
+            };
+            if target_id == func_id {
+                exec_modes.push(ExecutionModeDecl::new(exec_mode, operands));
+            }
+        }
+        Ok(exec_modes)
+    }
+    /// Get the constant decorated with `WorkgroupSize` built-in.
+    fn get_workgroup_size_id(&self) -> Option<ConstantId> {
+        self.deco_map.iter()
+            .find_map(|(&(id, member_idx, deco), params)| {
+                let is_workgroup_size = member_idx.is_none() &&
+                    deco == Decoration::BuiltIn &&
+                    params.first() == Some(&(BuiltIn::WorkgroupSize as u32));
+                if is_workgroup_size { Some(id) } else { None }
+            })
+    }
+    /// Get the workgroup size specified by the constant decorated with
+    /// `WorkgroupSize` built-in, which overrides `LocalSize` and `LocalSizeId`.
+    fn get_workgroup_size(&self) -> Option<Vec<u32>> {
+        let const_id = self.get_workgroup_size_id()?;
+        if let ConstantValue::Composite(xs) = self.get_const_value(const_id)? {
+            xs.iter()
+                .map(|x| match *x {
+                    ConstantValue::I32(x) => Some(x as u32),
+                    ConstantValue::U32(x) => Some(x),
+                    _ => None,
+                })
+                .collect()
+        } else { None }
+    }
+    /// Get the access to a descriptor allowed by its type, and the
+    /// `NonReadable` and `NonWritable` decorations.
+    fn get_desc_access_allowed(&self, var_id: VariableId, ty_id: TypeId, desc_ty: &DescriptorType) -> Result<AccessFlags> {
+        match desc_ty {
+            DescriptorType::StorageBuffer(..) => {},
+            DescriptorType::Image(Type::Image(img_ty)) => {
+                if let ImageUnitFormat::Color(_) = img_ty.unit_fmt {} else {
+                    return Ok(ACCESS_READ);
+                }
+            },
+            _ => return Ok(ACCESS_READ),
+        }
+        // `readonly` and `writeonly` blocks have all their members decorated
+        // instead of the variable, so find the struct behind the arrays.
+        let mut struct_ty_id = ty_id;
+        while let Some(instr) = self.def_map.get(&struct_ty_id) {
+            match instr.opcode() {
+                OP_TYPE_ARRAY => {
+                    struct_ty_id = OpTypeArray::try_from(instr)?.proto_ty_id;
+                },
+                OP_TYPE_RUNTIME_ARRAY => {
+                    struct_ty_id = OpTypeRuntimeArray::try_from(instr)?.proto_ty_id;
+                },
+                _ => break,
+            }
+        }
+        let nmember = match self.def_map.get(&struct_ty_id) {
+            Some(instr) if instr.opcode() == OP_TYPE_STRUCT => {
+                OpTypeStruct::try_from(instr)?.member_ty_ids.len() as u32
+            },
+            _ => 0,
+        };
+        let is_decorated = |deco| {
+            self.contains_deco(var_id, None, deco) || (nmember > 0 &&
+                (0..nmember).all(|i| self.contains_deco(struct_ty_id, Some(i), deco)))
+        };
+        let mut allowed = ACCESS_READ | ACCESS_WRITE;
+        if is_decorated(Decoration::NonReadable) { allowed &= !ACCESS_READ; }
+        if is_decorated(Decoration::NonWritable) { allowed &= !ACCESS_WRITE; }
+        Ok(allowed)
+    }
+    fn get_builtin(&self, id: InstrId, member_idx: Option<MemberIdx>) -> Option<BuiltIn> {
+        self.get_deco_u32(id, member_idx, Decoration::BuiltIn)
+            .and_then(BuiltIn::from_u32)
+    }
+    /// Get the built-ins declared by a variable, either decorated as a
+    /// built-in itself or as a block of built-in members. Built-ins of types
+    /// unknown to SPIR-Q are left out.
+    fn get_var_builtins(&self, var_id: VariableId, ptr_ty_id: TypeId, is_input: bool) -> Result<Option<Variable>> {
+        let ty_id = match self.ptr_map.get(&ptr_ty_id) {
+            Some(x) => *x,
+            None => return Ok(None),
+        };
+        if let Some(builtin) = self.get_builtin(var_id, None) {
+            let builtins = self.ty_map.get(&ty_id)
+                .map(|ty| (None, builtin, ty.clone()))
+                .into_iter()
+                .collect();
+            let var = Variable::BuiltIn { is_input, is_arrayed: false, builtins };
+            return Ok(Some(var));
+        }
+        let mut struct_ty_id = ty_id;
+        let mut is_arrayed = false;
+        if let Some(instr) = self.def_map.get(&ty_id) {
+            match instr.opcode() {
+                OP_TYPE_ARRAY => {
+                    struct_ty_id = OpTypeArray::try_from(instr)?.proto_ty_id;
+                    is_arrayed = true;
+                },
+                OP_TYPE_RUNTIME_ARRAY => {
+                    struct_ty_id = OpTypeRuntimeArray::try_from(instr)?.proto_ty_id;
+                    is_arrayed = true;
+                },
+                _ => {},
+            }
+        }
+        let member_ty_ids = match self.def_map.get(&struct_ty_id) {
+            Some(instr) if instr.opcode() == OP_TYPE_STRUCT => {
+                OpTypeStruct::try_from(instr)?.member_ty_ids
+            },
+            _ => return Ok(None),
+        };
+        let mut builtins = Vec::new();
+        let mut is_builtin_block = false;
+        for (i, member_ty_id) in member_ty_ids.iter().enumerate() {
+            let i = i as MemberIdx;
+            if let Some(builtin) = self.get_builtin(struct_ty_id, Some(i)) {
+                is_builtin_block = true;
+                if let Some(ty) = self.ty_map.get(member_ty_id) {
+                    builtins.push((Some(i), builtin, ty.clone()));
+                }
+            }
+        }
+        if is_builtin_block {
+            Ok(Some(Variable::BuiltIn { is_input, is_arrayed, builtins }))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Get the input/output block variable, if the variable is a block or an
+    /// array of blocks. Members without explicit locations follow the previous
+    /// members, starting from the location of the variable.
+    fn get_var_block(&self, var_id: VariableId, ptr_ty_id: TypeId, is_input: bool) -> Result<Option<Variable>> {
+        let ty_id = match self.ptr_map.get(&ptr_ty_id) {
+            Some(x) => *x,
+            None => return Ok(None),
+        };
+        // Arrays of blocks are per-vertex blocks in some stages, and are
+        // otherwise flattened element by element. It's decided for each entry
+        // point.
+        let (struct_ty_id, nrepeat) = match self.def_map.get(&ty_id) {
+            Some(instr) if instr.opcode() == OP_TYPE_ARRAY => {
+                let op = OpTypeArray::try_from(instr)?;
+                let nrepeat = self.get_const_value(op.nrepeat_const_id)
+                    .and_then(ConstantValue::to_u64)
+                    .and_then(|x| u32::try_from(x).ok())
+                    .ok_or(Error::CONST_NOT_FOUND)?;
+                (op.proto_ty_id, Some(nrepeat))
+            },
+            _ => (ty_id, None),
+        };
+        if !self.is_skipped_struct(struct_ty_id) { return Ok(None) }
+        let member_ty_ids = match self.def_map.get(&struct_ty_id) {
+            Some(instr) => OpTypeStruct::try_from(instr)?.member_ty_ids,
+            None => return Ok(None),
+        };
+        let mut location = self.get_var_location(var_id);
+        let mut members = Vec::with_capacity(member_ty_ids.len());
+        for (i, &member_ty_id) in member_ty_ids.iter().enumerate() {
+            let i = i as MemberIdx;
+            if let Some(x) = self.get_deco_u32(struct_ty_id, Some(i), Decoration::Location) {
+                location = Some(x.into());
+            }
+            let component = self.get_deco_u32(struct_ty_id, Some(i), Decoration::Component)
+                .unwrap_or(0)
+                .into();
+            let name = self.get_name(struct_ty_id, Some(i))
+                .filter(|x| !x.is_empty())
+                .map(ToOwned::to_owned);
+            let quals = self.get_var_quals(var_id, Some((struct_ty_id, i)));
+            // Members of nested structs take consecutive locations, in the
+            // order of declaration.
+            let mut leaves = Vec::new();
+            self.flatten_block_member(member_ty_id, name, &mut leaves)?;
+            for (ty, name) in leaves {
+                let member_location = location.ok_or(Error::LOCATION_NOT_FOUND)?;
+                location = ty.nlocation()
+                    .map(|x| (u32::from(member_location) + x).into());
+                let member = BlockMember { location: member_location, component, ty, name, quals };
+                members.push(member);
+            }
+        }
+        Ok(Some(Variable::Block { is_input, nrepeat, members }))
+    }
+    /// Flatten a member of an input/output block into the members of the
+    /// structs nested in it, with dotted names like `s.x`. Arrays of structs
+    /// are flattened element by element, like `s.0.x`.
+    fn flatten_block_member(&self, ty_id: TypeId, name: Option<String>, out: &mut Vec<(Type, Option<String>)>) -> Result<()> {
+        if let Some(ty) = self.ty_map.get(&ty_id) {
+            out.push((ty.clone(), name));
+            return Ok(());
+        }
+        let instr = self.def_map.get(&ty_id).ok_or(Error::TY_NOT_FOUND)?;
+        match instr.opcode() {
+            OP_TYPE_STRUCT => {
+                let op = OpTypeStruct::try_from(instr)?;
+                for (i, &member_ty_id) in op.member_ty_ids.iter().enumerate() {
+                    let member_name = self.get_name(ty_id, Some(i as MemberIdx))
+                        .filter(|x| !x.is_empty());
+                    let name = match (name.as_ref(), member_name) {
+                        (Some(name), Some(member_name)) => Some(format!("{}.{}", name, member_name)),
+                        _ => None,
+                    };
+                    self.flatten_block_member(member_ty_id, name, out)?;
+                }
+            },
+            OP_TYPE_ARRAY => {
+                let op = OpTypeArray::try_from(instr)?;
+                let nrepeat = self.get_const_value(op.nrepeat_const_id)
+                    .and_then(ConstantValue::to_u64)
+                    .and_then(|x| u32::try_from(x).ok())
+                    .ok_or(Error::CONST_NOT_FOUND)?;
+                for j in 0..nrepeat {
+                    let name = name.as_ref().map(|x| format!("{}.{}", x, j));
+                    self.flatten_block_member(op.proto_ty_id, name, out)?;
+                }
+            },
+            _ => return Err(Error::TY_NOT_FOUND),
+        }
+        Ok(())
     }
     fn populate_one_var(&mut self, instr: &Instr<'a>) -> Result<()> {
         fn ty2buf(ty: &Type) -> Option<(u32, Type)> {
             match ty {
-                Type::Array(arr_ty) => if let Type::Struct(struct_ty) = &*arr_ty.proto_ty() {
+                Type::Array(arr_ty) => if let Type::Struct(struct_ty) = arr_ty.proto_ty() {
                     Some((arr_ty.nrepeat()?, Type::Struct(struct_ty.clone())))
-                } else { return None },
+                } else { None },
                 Type::Struct(_) => Some((1, ty.clone())),
-                _ => return None,
+                _ => None,
             }
         }
         fn ty2uniform(buf_ty: &Type) -> Option<DescriptorType> {
@@ -322,18 +1020,32 @@ impl<'a> ReflectIntermediate<'a> {
         }
 
         let op = OpVariable::try_from(instr)?;
+        if op.store_cls == StorageClass::Input || op.store_cls == StorageClass::Output {
+            let is_input = op.store_cls == StorageClass::Input;
+            let var = if let Some(var) = self.get_var_builtins(op.alloc_id, op.ty_id, is_input)? {
+                Some(var)
+            } else {
+                self.get_var_block(op.alloc_id, op.ty_id, is_input)?
+            };
+            if let Some(var) = var {
+                if self.var_map.insert(op.alloc_id, var).is_some() {
+                    return Err(Error::ID_COLLISION);
+                }
+                return Ok(());
+            }
+        }
         let (ty_id, ty) = if let Some(x) = self.resolve_ref(op.ty_id) { x } else {
             // If a variable is declared based on a unregistered type, very
-            // likely it's a input/output block passed between shader stages. We
-            // can safely ignore them.
+            // likely it's a input/output block that cannot be located. We can
+            // safely ignore them.
             return Ok(());
         };
         match op.store_cls {
             StorageClass::Input => {
-                // TODO: Handle inputs like gl_VertexIndex that do not have a location.
                 if let Some(location) = self.get_var_location(op.alloc_id) {
                     let component = self.get_var_component_or_default(op.alloc_id);
-                    let var = Variable::Input(location, component, ty.clone());
+                    let quals = self.get_var_quals(op.alloc_id, None);
+                    let var = Variable::Input(location, component, ty.clone(), quals);
                     if self.var_map.insert(op.alloc_id, var).is_some() {
                         return Err(Error::ID_COLLISION);
                     }
@@ -343,10 +1055,10 @@ impl<'a> ReflectIntermediate<'a> {
                 }
             },
             StorageClass::Output => {
-                // TODO: Handle outputs that do not have a location
                 if let Some(location) = self.get_var_location(op.alloc_id) {
                     let component = self.get_var_component_or_default(op.alloc_id);
-                    let var = Variable::Output(location, component, ty.clone());
+                    let quals = self.get_var_quals(op.alloc_id, None);
+                    let var = Variable::Output(location, component, ty.clone(), quals);
                     if self.var_map.insert(op.alloc_id, var).is_some() {
                         return Err(Error::ID_COLLISION);
                     }
@@ -358,7 +1070,7 @@ impl<'a> ReflectIntermediate<'a> {
                 if let Type::Struct(_) = ty {
                     let desc_bind = DescriptorBinding::push_const();
                     let desc_ty = DescriptorType::PushConstant(ty.clone());
-                    let var = Variable::Descriptor(desc_bind, desc_ty);
+                    let var = Variable::Descriptor(desc_bind, desc_ty, ACCESS_READ);
                     if self.var_map.insert(op.alloc_id, var).is_some() {
                         return Err(Error::ID_COLLISION);
                     }
@@ -371,7 +1083,8 @@ impl<'a> ReflectIntermediate<'a> {
                     ty2uniform(ty)
                 }.ok_or(Error::TY_NOT_FOUND)?;
                 let desc_bind = self.get_var_desc_bind_or_default(op.alloc_id);
-                let var = Variable::Descriptor(desc_bind, desc_ty);
+                let allowed = self.get_desc_access_allowed(op.alloc_id, ty_id, &desc_ty)?;
+                let var = Variable::Descriptor(desc_bind, desc_ty, allowed);
                 if self.var_map.insert(op.alloc_id, var).is_some() {
                     return Err(Error::ID_COLLISION);
                 }
@@ -379,7 +1092,8 @@ impl<'a> ReflectIntermediate<'a> {
             StorageClass::StorageBuffer => {
                 let desc_ty = ty2storage(ty).ok_or(Error::TY_NOT_FOUND)?;
                 let desc_bind = self.get_var_desc_bind_or_default(op.alloc_id);
-                let var = Variable::Descriptor(desc_bind, desc_ty);
+                let allowed = self.get_desc_access_allowed(op.alloc_id, ty_id, &desc_ty)?;
+                let var = Variable::Descriptor(desc_bind, desc_ty, allowed);
                 if self.var_map.insert(op.alloc_id, var).is_some() {
                     return Err(Error::ID_COLLISION);
                 }
@@ -397,7 +1111,8 @@ impl<'a> ReflectIntermediate<'a> {
                 } else {
                     return Err(Error::UNSUPPORTED_TY);
                 };
-                let var = Variable::Descriptor(desc_bind, desc_ty);
+                let allowed = self.get_desc_access_allowed(op.alloc_id, ty_id, &desc_ty)?;
+                let var = Variable::Descriptor(desc_bind, desc_ty, allowed);
                 if self.var_map.insert(op.alloc_id, var).is_some() {
                     return Err(Error::ID_COLLISION);
                 }
@@ -414,127 +1129,750 @@ impl<'a> ReflectIntermediate<'a> {
         // instructions here.
         while let Some(instr) = instrs.peek() {
             let opcode = instr.opcode();
-            if TYPE_RANGE.contains(&opcode) {
-                self.populate_one_ty(instr)?;
+            let res = if opcode == OP_FUNCTION {
+                break;
+            } else if TYPE_RANGE.contains(&opcode) {
+                self.populate_one_ty(instr)
             } else if opcode == OP_VARIABLE {
-                self.populate_one_var(instr)?;
-            } else if CONST_RANGE.contains(&opcode) {
-                self.populate_one_const(instr)?;
-            } else if SPEC_CONST_RANGE.contains(&opcode) {
-                // TODO: (penguinliong)
-            } else { break; }
+                self.populate_one_var(instr)
+            } else if CONST_RANGE.contains(&opcode) || SPEC_CONST_RANGE.contains(&opcode) {
+                self.populate_one_const(instr)
+            } else {
+                // Other instructions like `OpUndef` and `OpLine` can be
+                // interleaved with definitions.
+                Ok(())
+            };
+            res.map_err(|e| e.at(self.locate(instr)))?;
+            if let Some(id) = instr.result_id() {
+                self.def_map.insert(id, *instr);
+            }
             instrs.next();
         }
         Ok(())
     }
     fn populate_access(&mut self, instrs: &'_ mut Peekable<Instrs<'a>>) -> Result<()> {
         while instrs.peek().is_some() {
-            let mut access_chain_map = HashMap::new();
-            let mut func: Option<&mut Function> = None;
+            let mut ptr_origin_map = HashMap::new();
+            let mut ptr_merge_map = HashMap::new();
+            let mut func_id = None;
             while let Some(instr) = instrs.peek() {
                 if instr.opcode() == OP_FUNCTION {
-                    let op = OpFunction::try_from(instr)?;
-                    func = Some(self.func_map.entry(op.func_id).or_default());
+                    let op = OpFunction::try_from(instr)
+                        .map_err(|e| e.at(self.locate(instr)))?;
+                    self.func_map.entry(op.func_id).or_default().return_ty_id = op.return_ty_id;
+                    self.func_ids.push(op.func_id);
+                    func_id = Some(op.func_id);
                     break;
                 }
                 instrs.next();
             }
+            let func_id = if let Some(x) = func_id { x } else { break };
 
             while let Some(instr) = instrs.peek() {
-                match instr.opcode() {
-                    OP_FUNCTION_CALL => {
-                        let op = OpFunctionCall::try_from(instr)?;
-                        func.as_mut().unwrap().calls.insert(op.func_id);
-                    },
-                    OP_LOAD => {
-                        let op = OpLoad::try_from(instr)?;
-                        let mut rsc_id = op.rsc_id;
-                        if let Some(&x) = access_chain_map.get(&rsc_id) { rsc_id = x }
-                        func.as_mut().unwrap().accessed_vars.insert(rsc_id);
+                if instr.opcode() == OP_FUNCTION_END { break; }
+                self.populate_one_access(func_id, &mut ptr_origin_map, &mut ptr_merge_map, instr)
+                    .map_err(|e| e.at(self.locate(instr)))?;
+                instrs.next();
+            }
+            self.resolve_merged_ptrs(func_id, &ptr_origin_map, &ptr_merge_map);
+        }
+        Ok(())
+    }
+    fn populate_one_access(
+        &mut self,
+        func_id: FunctionId,
+        ptr_origin_map: &mut HashMap<InstrId, PtrOrigin>,
+        ptr_merge_map: &mut HashMap<InstrId, Vec<InstrId>>,
+        instr: &Instr<'a>,
+    ) -> Result<()> {
+        // Pointers derived from other pointers, and image handles loaded from
+        // variables, are traced back to the variables they come from.
+        let origin = |ptr_id: InstrId| {
+            ptr_origin_map.get(&ptr_id)
+                .cloned()
+                .unwrap_or_else(|| (ptr_id, AccessPath::new()))
+        };
+        let const_map = &self.const_map;
+        let get_idx = |idx_id: InstrId| {
+            const_map.get(&idx_id)
+                .and_then(|x| x.value.to_u64())
+                .and_then(|x| u32::try_from(x).ok())
+        };
+        let func = self.func_map.entry(func_id).or_default();
+        let def_map = &self.def_map;
+        let ref_ids = parse::ref_ids(instr.opcode(), instr.operand_words());
+        func.global_ref_ids.extend(ref_ids.into_iter().filter(|x| def_map.contains_key(x)));
+        let access = |func: &mut Function, ptr_id: InstrId, flags: AccessFlags| {
+            let (origin_id, path) = origin(ptr_id);
+            *func.accessed_vars.entry(origin_id).or_default() |= flags;
+            if flags != 0 {
+                func.accessed_paths.insert((origin_id, path));
+            }
+        };
+        let opcode = instr.opcode();
+        let derived_id = match opcode {
+            OP_FUNCTION_PARAMETER => {
+                let op = OpFunctionParameter::try_from(instr)?;
+                func.params.push((op.param_id, op.ty_id));
+                None
+            },
+            OP_FUNCTION_CALL => {
+                let op = OpFunctionCall::try_from(instr)?;
+                // Variables passed to functions are statically used. How they
+                // are accessed is decided by the callee.
+                let args = op.arg_ids.iter().map(|&x| vec![origin(x)]).collect::<Vec<_>>();
+                for (arg_id, _) in args.iter().flatten() {
+                    func.accessed_vars.entry(*arg_id).or_default();
+                }
+                func.calls.push((op.func_id, args));
+                None
+            },
+            OP_LOAD => {
+                let op = OpLoad::try_from(instr)?;
+                match self.ty_map.get(&op.return_ty_id) {
+                    // Loading an image or a sampler only gets a handle. The
+                    // texels are accessed by image instructions.
+                    Some(Type::Image(_)) | Some(Type::Sampler) | Some(Type::SubpassData) => {
+                        access(func, op.rsc_id, 0);
+                        Some((op.return_id, origin(op.rsc_id)))
                     },
-                    OP_STORE => {
-                        let op = OpStore::try_from(instr)?;
-                        let mut rsc_id = op.rsc_id;
-                        if let Some(&x) = access_chain_map.get(&rsc_id) { rsc_id = x }
-                        func.as_mut().unwrap().accessed_vars.insert(rsc_id);
+                    _ => {
+                        access(func, op.rsc_id, ACCESS_READ);
+                        None
                     },
-                    OP_ACCESS_CHAIN => {
-                        let op = OpAccessChain::try_from(instr)?;
-                        if access_chain_map.insert(op.rsc_id, op.accessed_rsc_id).is_some() {
-                            return Err(Error::ID_COLLISION);
+                }
+            },
+            OP_SAMPLED_IMAGE => {
+                let op = OpSampledImage::try_from(instr)?;
+                Some((op.sampled_img_id, origin(op.img_id)))
+            },
+            OP_IMAGE => {
+                let op = OpImage::try_from(instr)?;
+                Some((op.img_id, origin(op.sampled_img_id)))
+            },
+            OP_IMAGE_READ => {
+                let op = OpImageRead::try_from(instr)?;
+                access(func, op.img_id, ACCESS_READ);
+                None
+            },
+            // Other image reads, like sampling and fetches, take the image or
+            // the sampled image right after the result type and the result id.
+            _ if IMAGE_READ_OPS.contains(&opcode) => {
+                let img_id = nth_operand(instr, 2)?;
+                access(func, img_id, ACCESS_READ);
+                None
+            },
+            OP_IMAGE_WRITE => {
+                let op = OpImageWrite::try_from(instr)?;
+                access(func, op.img_id, ACCESS_WRITE);
+                None
+            },
+            // Atomics with a result take the pointer right after the result
+            // type and the result id.
+            _ if ATOMIC_OPS.contains(&opcode) => {
+                let ptr_id = nth_operand(instr, 2)?;
+                let flags = if opcode == OP_ATOMIC_LOAD {
+                    ACCESS_READ
+                } else {
+                    ACCESS_READ | ACCESS_WRITE
+                };
+                access(func, ptr_id, flags);
+                None
+            },
+            OP_STORE => {
+                let op = OpStore::try_from(instr)?;
+                access(func, op.rsc_id, ACCESS_WRITE);
+                None
+            },
+            // Atomics without a result take the pointer first, followed by
+            // the scope, the memory semantics and the value, if any.
+            OP_ATOMIC_STORE | OP_ATOMIC_FLAG_CLEAR => {
+                let ptr_id = nth_operand(instr, 0)?;
+                access(func, ptr_id, ACCESS_WRITE);
+                None
+            },
+            OP_COPY_MEMORY | OP_COPY_MEMORY_SIZED => {
+                let op = OpCopyMemory::try_from(instr)?;
+                access(func, op.dst_rsc_id, ACCESS_WRITE);
+                access(func, op.src_rsc_id, ACCESS_READ);
+                None
+            },
+            OP_ARRAY_LENGTH => {
+                let op = OpArrayLength::try_from(instr)?;
+                access(func, op.rsc_id, 0);
+                None
+            },
+            // All kinds of access chains share the same leading operands.
+            _ if ACCESS_CHAIN_OPS.contains(&opcode) => {
+                let op = OpAccessChain::try_from(instr)?;
+                let (origin_id, mut path) = origin(op.accessed_rsc_id);
+                let mut idx_ids = op.idx_ids;
+                if opcode == OP_PTR_ACCESS_CHAIN || opcode == OP_IN_BOUNDS_PTR_ACCESS_CHAIN {
+                    // The element index steps the base pointer itself, as if
+                    // it's added to the last index leading to the base.
+                    if let Some((&elem_id, rest)) = idx_ids.split_first() {
+                        if let Some(last) = path.last_mut() {
+                            *last = match (*last, get_idx(elem_id)) {
+                                (Some(x), Some(0)) => Some(x),
+                                (Some(x), Some(y)) => x.checked_add(y),
+                                _ => None,
+                            };
                         }
-                    },
-                    OP_FUNCTION_END => break,
-                    _ => { },
+                        idx_ids = rest;
+                    }
                 }
-                instrs.next();
+                path.extend(idx_ids.iter().map(|&x| get_idx(x)));
+                Some((op.rsc_id, (origin_id, path)))
+            },
+            OP_COPY_OBJECT => {
+                let op = OpCopyObject::try_from(instr)?;
+                Some((op.rsc_id, origin(op.src_rsc_id)))
+            },
+            OP_IMAGE_TEXEL_POINTER => {
+                let op = OpImageTexelPointer::try_from(instr)?;
+                Some((op.rsc_id, origin(op.img_rsc_id)))
+            },
+            // Pointers and handles selected from others, e.g., with variable
+            // pointers, can come from any of them. The sources of `OpPhi` can
+            // be defined later, so they are resolved at the end of function.
+            OP_PHI | OP_SELECT => {
+                let words = instr.operand_words();
+                if words.len() < 2 { return Err(Error::INSTR_TOO_SHORT); }
+                let src_ids = if opcode == OP_PHI {
+                    words[2..].iter().step_by(2).copied().collect()
+                } else {
+                    words.get(3..).unwrap_or_default().to_owned()
+                };
+                ptr_merge_map.insert(words[1], src_ids);
+                None
+            },
+            _ => None,
+        };
+        if let Some((id, origin)) = derived_id {
+            if ptr_origin_map.insert(id, origin).is_some() {
+                return Err(Error::ID_COLLISION);
             }
         }
         Ok(())
     }
-    fn collect_fn_vars_impl(&self, func: FunctionId, vars: &mut HashSet<VariableId>) {
-        if let Some(func) = self.func_map.get(&func) {
-            let it = func.accessed_vars.iter()
-                .filter(|x| self.var_map.contains_key(x));
-            vars.extend(it);
-            for call in func.calls.iter() {
-                self.collect_fn_vars_impl(*call, vars);
+    /// Attribute the accesses through the results of `OpPhi` and `OpSelect` in
+    /// a function to the origins of their sources.
+    fn resolve_merged_ptrs(
+        &mut self,
+        func_id: FunctionId,
+        ptr_origin_map: &HashMap<InstrId, PtrOrigin>,
+        ptr_merge_map: &HashMap<InstrId, Vec<InstrId>>,
+    ) {
+        fn resolve(
+            ptr_origin_map: &HashMap<InstrId, PtrOrigin>,
+            ptr_merge_map: &HashMap<InstrId, Vec<InstrId>>,
+            id: InstrId,
+            path: &[Option<u32>],
+            visited: &mut HashSet<InstrId>,
+            out: &mut Vec<PtrOrigin>,
+        ) {
+            let src_ids = if let Some(x) = ptr_merge_map.get(&id) { x } else {
+                out.push((id, path.to_owned()));
+                return;
+            };
+            // Loops of `OpPhi`s don't introduce new origins.
+            if !visited.insert(id) { return; }
+            for src_id in src_ids {
+                let (origin_id, mut src_path) = ptr_origin_map.get(src_id)
+                    .cloned()
+                    .unwrap_or_else(|| (*src_id, AccessPath::new()));
+                src_path.extend_from_slice(path);
+                resolve(ptr_origin_map, ptr_merge_map, origin_id, &src_path, visited, out);
             }
+            visited.remove(&id);
         }
+        let resolve = |id: InstrId, path: &[Option<u32>]| {
+            let mut out = Vec::new();
+            resolve(ptr_origin_map, ptr_merge_map, id, path, &mut HashSet::new(), &mut out);
+            out
+        };
+        let func = if let Some(x) = self.func_map.get_mut(&func_id) { x } else { return };
+        if !func.accessed_vars.keys().any(|x| ptr_merge_map.contains_key(x)) { return; }
+        let accessed_vars = std::mem::take(&mut func.accessed_vars);
+        for (id, flags) in accessed_vars {
+            for (origin_id, _) in resolve(id, &[]) {
+                *func.accessed_vars.entry(origin_id).or_default() |= flags;
+            }
+        }
+        let accessed_paths = std::mem::take(&mut func.accessed_paths);
+        for (id, path) in accessed_paths {
+            func.accessed_paths.extend(resolve(id, &path));
+        }
+        for (_, args) in func.calls.iter_mut() {
+            for arg in args.iter_mut() {
+                *arg = arg.iter()
+                    .flat_map(|(id, path)| resolve(*id, path))
+                    .collect();
+            }
+        }
+    }
+    /// Map an id accessed in a callee to the caller's origins of the argument,
+    /// if it's a parameter of the callee.
+    fn map_param<'b>(&self, callee_id: FunctionId, args: &'b [Vec<PtrOrigin>], id: InstrId) -> Option<&'b [PtrOrigin]> {
+        let func = self.func_map.get(&callee_id)?;
+        let i = func.params.iter().position(|&(x, _)| x == id)?;
+        args.get(i).map(AsRef::as_ref)
+    }
+    /// Collect the accesses made by a function and the functions it calls.
+    /// Accesses to the parameters of callees are attributed to the arguments.
+    /// The accesses of each function are memoized in `memo`, so shared callees
+    /// are only visited once.
+    fn collect_fn_accesses<'m>(&self, func_id: FunctionId, memo: &'m mut AccessMemo) -> &'m HashMap<InstrId, AccessFlags> {
+        if !memo.contains_key(&func_id) {
+            // Recursion is not allowed in SPIR-V, but it shouldn't hang either.
+            memo.insert(func_id, HashMap::new());
+            let mut accesses = HashMap::new();
+            if let Some(func) = self.func_map.get(&func_id) {
+                for (&id, &flags) in func.accessed_vars.iter() {
+                    *accesses.entry(id).or_default() |= flags;
+                }
+                for (callee_id, args) in func.calls.iter() {
+                    for (&id, &flags) in self.collect_fn_accesses(*callee_id, memo).iter() {
+                        if let Some(origins) = self.map_param(*callee_id, args, id) {
+                            for (origin_id, _) in origins {
+                                *accesses.entry(*origin_id).or_default() |= flags;
+                            }
+                        } else {
+                            *accesses.entry(id).or_default() |= flags;
+                        }
+                    }
+                }
+            }
+            memo.insert(func_id, accesses);
+        }
+        &memo[&func_id]
+    }
+    /// Collect the paths accessed by a function and the functions it calls.
+    /// Paths into the parameters of callees are prefixed by the paths to the
+    /// arguments. The paths of each function are memoized in `memo`, so shared
+    /// callees are only visited once.
+    fn collect_fn_paths<'m>(&self, func_id: FunctionId, memo: &'m mut PathMemo) -> &'m HashSet<PtrOrigin> {
+        if !memo.contains_key(&func_id) {
+            // Recursion is not allowed in SPIR-V, but it shouldn't hang either.
+            memo.insert(func_id, HashSet::new());
+            let mut paths = HashSet::new();
+            if let Some(func) = self.func_map.get(&func_id) {
+                paths.extend(func.accessed_paths.iter().cloned());
+                for (callee_id, args) in func.calls.iter() {
+                    for (id, path) in self.collect_fn_paths(*callee_id, memo).iter() {
+                        if let Some(origins) = self.map_param(*callee_id, args, *id) {
+                            for (arg_id, arg_path) in origins {
+                                let mut arg_path = arg_path.clone();
+                                arg_path.extend_from_slice(path);
+                                paths.insert((*arg_id, arg_path));
+                            }
+                        } else {
+                            paths.insert((*id, path.clone()));
+                        }
+                    }
+                }
+            }
+            memo.insert(func_id, paths);
+        }
+        &memo[&func_id]
+    }
+    /// Collect the module-level definitions referred to by a function and the
+    /// functions it calls.
+    fn collect_fn_global_refs(&self, func_id: FunctionId) -> HashSet<InstrId> {
+        let mut ref_ids = HashSet::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![func_id];
+        while let Some(func_id) = stack.pop() {
+            if !visited.insert(func_id) { continue; }
+            if let Some(func) = self.func_map.get(&func_id) {
+                ref_ids.extend(func.global_ref_ids.iter().copied());
+                stack.extend(func.calls.iter().map(|x| x.0));
+            }
+        }
+        ref_ids
+    }
+    fn collect_fn_vars(&self, func_id: FunctionId, memo: &mut AccessMemo) -> HashMap<VariableId, AccessFlags> {
+        self.collect_fn_accesses(func_id, memo).iter()
+            .filter(|(id, _)| self.var_map.contains_key(id))
+            .map(|(&id, &flags)| (id, flags))
+            .collect()
     }
-    fn collect_fn_vars(&self, func: FunctionId) -> HashSet<VariableId> {
-        let mut accessed_vars = HashSet::new();
-        self.collect_fn_vars_impl(func, &mut accessed_vars);
-        accessed_vars
+    fn get_var_locator(&self, var_id: VariableId) -> Option<ResourceLocator> {
+        let locator = match self.var_map.get(&var_id)? {
+            Variable::Input(location, component, _, _) => ResourceLocator::Input(*location, *component),
+            Variable::Output(location, component, _, quals) => {
+                ResourceLocator::Output(*location, *component, quals.index.into())
+            },
+            Variable::Descriptor(desc_bind, _, _) => ResourceLocator::Descriptor(*desc_bind),
+            Variable::Block { .. } | Variable::BuiltIn { .. } => return None,
+        };
+        Some(locator)
+    }
+    /// Get the type of a value or of the data a pointer points to. `None` is
+    /// returned for `void` and unknown types.
+    fn get_value_ty(&self, ty_id: TypeId) -> Option<Type> {
+        self.ty_map.get(&ty_id)
+            .or_else(|| self.resolve_ref(ty_id).map(|x| x.1))
+            .cloned()
+    }
+    fn collect_call_graph(&self) -> CallGraph {
+        // Entry points reaching each function.
+        let mut reach_map = HashMap::<FunctionId, Vec<(ExecutionModel, String)>>::new();
+        for entry_point_declr in self.entry_point_declrs.iter() {
+            let mut stack = vec![entry_point_declr.func_id];
+            let mut visited = HashSet::new();
+            while let Some(func_id) = stack.pop() {
+                if !visited.insert(func_id) { continue; }
+                reach_map.entry(func_id).or_default()
+                    .push((entry_point_declr.exec_model, entry_point_declr.name.to_owned()));
+                if let Some(func) = self.func_map.get(&func_id) {
+                    stack.extend(func.calls.iter().map(|x| x.0));
+                }
+            }
+        }
+        // Callers of each function, in declaration order.
+        let mut caller_map = HashMap::<FunctionId, Vec<FunctionId>>::new();
+        for &func_id in self.func_ids.iter() {
+            let func = if let Some(x) = self.func_map.get(&func_id) { x } else { continue };
+            for (callee_id, _) in func.calls.iter() {
+                let callers = caller_map.entry(*callee_id).or_default();
+                if callers.last() != Some(&func_id) { callers.push(func_id); }
+            }
+        }
+        // Resources are listed in the order of variable ids.
+        let sorted_locators = |ids: &mut dyn Iterator<Item=&InstrId>| {
+            let mut ids = ids.copied().collect::<Vec<_>>();
+            ids.sort_unstable();
+            ids.into_iter()
+                .filter_map(|x| self.get_var_locator(x))
+                .collect::<Vec<_>>()
+        };
+        let mut access_memo = HashMap::new();
+        let funcs = self.func_ids.iter()
+            .filter_map(|&func_id| {
+                let func = self.func_map.get(&func_id)?;
+                let mut callees = Vec::new();
+                for (callee_id, _) in func.calls.iter() {
+                    if !callees.contains(callee_id) { callees.push(*callee_id); }
+                }
+                let transitive_rscs = self.collect_fn_vars(func_id, &mut access_memo);
+                let func_info = FunctionInfo {
+                    func_id,
+                    name: self.get_name(func_id, None).map(ToOwned::to_owned),
+                    return_ty: self.get_value_ty(func.return_ty_id),
+                    param_tys: func.params.iter()
+                        .map(|&(_, ty_id)| self.get_value_ty(ty_id))
+                        .collect(),
+                    callers: caller_map.remove(&func_id).unwrap_or_default(),
+                    callees,
+                    entry_points: reach_map.remove(&func_id).unwrap_or_default(),
+                    direct_rscs: sorted_locators(&mut func.accessed_vars.keys()),
+                    transitive_rscs: sorted_locators(&mut transitive_rscs.keys()),
+                };
+                Some(func_info)
+            })
+            .collect();
+        CallGraph { funcs }
+    }
+    /// Get the symbols of buffer members statically used by an entry point.
+    /// Paths are cut at dynamically indexed arrays, so that the entire arrays
+    /// are considered used. `None` is returned for descriptors other than
+    /// buffers.
+    fn collect_used_members(&self, var_id: VariableId, desc_ty: &DescriptorType, paths: &[AccessPath]) -> Option<Vec<Symbol>> {
+        let buf_ty = match desc_ty {
+            DescriptorType::PushConstant(ty) => ty,
+            DescriptorType::UniformBuffer(_, ty) => ty,
+            DescriptorType::StorageBuffer(_, ty) => ty,
+            _ => return None,
+        };
+        // The outer-most index of a multibind buffer selects the binding.
+        let is_multibind = self.def_map.get(&var_id)
+            .and_then(Instr::result_type_id)
+            .and_then(|x| self.resolve_ref(x))
+            .is_some_and(|(_, ty)| ty.is_arr());
+        let syms = paths.iter()
+            .filter_map(|path| {
+                // A multibind buffer accessed as a whole is entirely used.
+                let path = if is_multibind { path.get(1..).unwrap_or_default() } else { path };
+                let mut ty = buf_ty;
+                let mut sym: Option<Symbol> = None;
+                for idx in path.iter() {
+                    let (seg, child_ty) = match (ty, idx) {
+                        (Type::Struct(struct_ty), Some(i)) => {
+                            let i = *i as usize;
+                            let member = struct_ty.get_member(i)?;
+                            let seg = match member.name {
+                                Some(ref name) => Seg::Name(name),
+                                None => Seg::Index(i),
+                            };
+                            (seg, &member.ty)
+                        },
+                        (Type::Array(arr_ty), Some(i)) => {
+                            (Seg::Index(*i as usize), arr_ty.proto_ty())
+                        },
+                        // Components of vectors and matrices are not
+                        // distinguished.
+                        _ => break,
+                    };
+                    if let Some(sym) = sym.as_mut() {
+                        sym.push(&seg);
+                    } else {
+                        sym = Some(seg.into());
+                    }
+                    ty = child_ty;
+                }
+                Some(sym.unwrap_or_default())
+            })
+            .collect();
+        Some(Symbol::prune(syms))
     }
     fn collect_entry_points(&self) -> Result<Box<[EntryPoint]>> {
         let mut entry_points = Vec::with_capacity(self.entry_point_declrs.len());
+        let workgroup_size_id = self.get_workgroup_size_id();
+        let workgroup_size = self.get_workgroup_size();
+        let spec_consts = self.collect_spec_consts();
+        let mut access_memo = HashMap::new();
+        let mut path_memo = HashMap::new();
         for entry_point_declr in self.entry_point_declrs.iter() {
+            let mut exec_modes = self.resolve_exec_modes(entry_point_declr.func_id)?;
+            // Module-level definitions used by the entry point, to find the
+            // specialization constants it depends on.
+            let mut global_ref_ids = self.collect_fn_global_refs(entry_point_declr.func_id);
+            for instr in self.exec_mode_instrs.iter() {
+                if instr.opcode() == OP_EXECUTION_MODE_ID {
+                    let op = OpExecutionModeId::try_from(instr)
+                        .map_err(|e| e.at(self.locate(instr)))?;
+                    if op.func_id == entry_point_declr.func_id {
+                        global_ref_ids.extend(op.params.iter().copied());
+                    }
+                }
+            }
+            if let Some(workgroup_size) = workgroup_size.as_ref() {
+                match entry_point_declr.exec_model {
+                    ExecutionModel::GLCompute | ExecutionModel::Kernel |
+                    ExecutionModel::TaskNV | ExecutionModel::MeshNV => {
+                        exec_modes.retain(|x| {
+                            x.exec_mode != ExecutionMode::LocalSize &&
+                                x.exec_mode != ExecutionMode::LocalSizeId
+                        });
+                        exec_modes.push(ExecutionModeDecl::new(ExecutionMode::LocalSize, workgroup_size.clone()));
+                        global_ref_ids.extend(workgroup_size_id);
+                    },
+                    _ => {},
+                }
+            }
+            let exec_model = entry_point_declr.exec_model;
+            let input_nvertex = get_nvertex(exec_model, &exec_modes, true);
+            let output_nvertex = get_nvertex(exec_model, &exec_modes, false);
+            // Per-vertex variables are reported by the element types along
+            // with the numbers of vertices.
+            let to_ivar = |ty: Type, is_input: bool, quals: InterfaceQualifiers| {
+                let (ty, nvertex) = match ty {
+                    Type::Array(arr_ty) if !quals.is_patch && is_per_vertex_arrayed(exec_model, is_input) => {
+                        let nvertex = if is_input { input_nvertex } else { output_nvertex }
+                            .or_else(|| arr_ty.nrepeat());
+                        if nvertex.is_some() {
+                            (*arr_ty.proto_ty, nvertex)
+                        } else {
+                            (Type::Array(arr_ty), None)
+                        }
+                    },
+                    ty => (ty, None),
+                };
+                InterfaceVariable { ty, quals, nvertex }
+            };
             let mut entry_point = EntryPoint {
                 name: entry_point_declr.name.to_owned(),
                 exec_model: entry_point_declr.exec_model,
-                manifest: Manifest::default(),
+                manifest: Manifest {
+                    stages: vec![entry_point_declr.exec_model],
+                    ..Default::default()
+                },
+                exec_modes,
+                spec_consts: Vec::new(),
             };
-            let accessed_var_ids = self.collect_fn_vars(entry_point_declr.func_id);
-            for accessed_var_id in accessed_var_ids {
+            // Since SPIR-V 1.4 the interface of an entry point lists all the
+            // global variables it statically uses, so the functions are only
+            // walked for the access flags and the accessed paths, which are
+            // needed by descriptors and built-in blocks. Earlier versions only
+            // list input and output variables so the functions have to be
+            // scanned to find the variables.
+            let interface_var_ids = if self.version >= (1, 4) {
+                let var_ids = entry_point_declr.interface_ids.iter()
+                    .filter(|x| self.var_map.contains_key(x))
+                    .copied()
+                    .collect::<Vec<_>>();
+                Some(var_ids)
+            } else { None };
+            let need_walk = interface_var_ids.as_ref().map_or(true, |var_ids| {
+                var_ids.iter().any(|x| matches!(self.var_map.get(x),
+                    Some(Variable::Descriptor(..)) | Some(Variable::BuiltIn { .. })))
+            });
+            let mut accessed_vars = HashMap::new();
+            let mut accessed_paths = HashMap::<VariableId, Vec<AccessPath>>::new();
+            if need_walk {
+                accessed_vars = self.collect_fn_vars(entry_point_declr.func_id, &mut access_memo);
+                for (id, path) in self.collect_fn_paths(entry_point_declr.func_id, &mut path_memo) {
+                    accessed_paths.entry(*id).or_default().push(path.clone());
+                }
+            }
+            if let Some(var_ids) = interface_var_ids {
+                // Variables used through instructions SPIR-Q doesn't track are
+                // considered entirely accessed in every allowed way.
+                for &var_id in var_ids.iter() {
+                    if !accessed_vars.contains_key(&var_id) {
+                        accessed_paths.insert(var_id, vec![AccessPath::new()]);
+                    }
+                }
+                accessed_vars = var_ids.into_iter()
+                    .map(|x| (x, accessed_vars.get(&x).copied().unwrap_or(ACCESS_READ | ACCESS_WRITE)))
+                    .collect();
+            }
+            global_ref_ids.extend(accessed_vars.keys().copied());
+            let used_spec_const_ids = self.collect_spec_const_refs(global_ref_ids)?;
+            entry_point.spec_consts = spec_consts.iter()
+                .filter(|(id, _)| used_spec_const_ids.contains(id))
+                .map(|(_, x)| x.clone())
+                .collect();
+            let mut anonymous_member_names = Vec::new();
+            for (accessed_var_id, flags) in accessed_vars {
                 let accessed_var = self.var_map.get(&accessed_var_id)
                     .cloned()
                     .ok_or(Error::UNDECLARED_VAR)?;
                 match accessed_var {
-                    Variable::Input(location, component, ivar_ty) => {
+                    Variable::Input(location, component, ivar_ty, quals) => {
                         // Input variables can share locations (aliasing).
-                        entry_point.manifest.input_map.insert((location, component), ivar_ty);
+                        let ivar = to_ivar(ivar_ty, true, quals);
+                        entry_point.manifest.input_map.insert((location, component), ivar);
                         if let Some(name) = self.get_name(accessed_var_id, None) {
                             if entry_point.manifest.var_name_map
                                 .insert(name.to_owned(), ResourceLocator::Input(location, component)).is_some() {
-                                return Err(Error::NAME_COLLISION);
+                                return Err(self.err_at_def(accessed_var_id, Error::NAME_COLLISION));
                             }
                         }
                     },
-                    Variable::Output(location, component, ivar_ty) => {
+                    Variable::Output(location, component, ivar_ty, quals) => {
                         // Output variables can share locations (aliasing).
-                        entry_point.manifest.output_map.insert((location, component), ivar_ty);
+                        // Second sources of dual-source blending are told
+                        // apart from the first sources by the indices.
+                        let index = quals.index.into();
+                        let ivar = to_ivar(ivar_ty, false, quals);
+                        entry_point.manifest.output_map.insert((location, component, index), ivar);
                         if let Some(name) = self.get_name(accessed_var_id, None) {
                             if entry_point.manifest.var_name_map
-                                .insert(name.to_owned(), ResourceLocator::Output(location, component)).is_some() {
-                                return Err(Error::NAME_COLLISION);
+                                .insert(name.to_owned(), ResourceLocator::Output(location, component, index)).is_some() {
+                                return Err(self.err_at_def(accessed_var_id, Error::NAME_COLLISION));
+                            }
+                        }
+                    },
+                    Variable::Block { is_input, nrepeat, members } => {
+                        let block_name = self.get_name(accessed_var_id, None)
+                            .filter(|x| !x.is_empty());
+                        let is_per_vertex = is_per_vertex_arrayed(exec_model, is_input);
+                        let nlocation = block_nlocation(&members);
+                        for member in members {
+                            let BlockMember { location, component, ty, name, quals } = member;
+                            // Locations, names and numbers of vertices of the
+                            // elements reported for the member.
+                            let elems = match nrepeat {
+                                Some(nrepeat) if is_per_vertex && !quals.is_patch => {
+                                    let nvertex = if is_input { input_nvertex } else { output_nvertex }
+                                        .unwrap_or(nrepeat);
+                                    vec![(location, name, Some(nvertex))]
+                                },
+                                // Other arrays of blocks take consecutive
+                                // locations element by element, and the members
+                                // are referred to like `blk.1.x`.
+                                Some(nrepeat) => {
+                                    (0..nrepeat)
+                                        .map(|i| {
+                                            let location = (u32::from(location) + i * nlocation).into();
+                                            let name = name.as_ref().map(|x| format!("{}.{}", i, x));
+                                            (location, name, None)
+                                        })
+                                        .collect()
+                                },
+                                None => vec![(location, name, None)],
+                            };
+                            for (location, name, nvertex) in elems {
+                                let ivar = InterfaceVariable { ty: ty.clone(), quals, nvertex };
+                                let locator = if is_input {
+                                    entry_point.manifest.input_map.insert((location, component), ivar);
+                                    ResourceLocator::Input(location, component)
+                                } else {
+                                    let index = quals.index.into();
+                                    entry_point.manifest.output_map.insert((location, component, index), ivar);
+                                    ResourceLocator::Output(location, component, index)
+                                };
+                                match (block_name, name) {
+                                    // Members are referred to by dotted names, like
+                                    // `vs_out.uv`.
+                                    (Some(block_name), Some(name)) => {
+                                        let name = format!("{}.{}", block_name, name);
+                                        if entry_point.manifest.var_name_map.insert(name, locator).is_some() {
+                                            return Err(self.err_at_def(accessed_var_id, Error::NAME_COLLISION));
+                                        }
+                                    },
+                                    (None, Some(name)) => {
+                                        anonymous_member_names.push((name, locator));
+                                    },
+                                    _ => {},
+                                }
+                            }
+                        }
+                    },
+                    Variable::BuiltIn { is_input, is_arrayed, builtins } => {
+                        // Only the members of built-in blocks actually accessed
+                        // are used, e.g., `gl_Position` of `gl_PerVertex`.
+                        let member_idxs = accessed_paths.get(&accessed_var_id)
+                            .map(|paths| {
+                                paths.iter()
+                                    .map(|x| x.get(is_arrayed as usize).copied().flatten())
+                                    .collect::<Option<HashSet<_>>>()
+                            })
+                            .unwrap_or_default();
+                        let builtin_map = if is_input {
+                            &mut entry_point.manifest.builtin_input_map
+                        } else {
+                            &mut entry_point.manifest.builtin_output_map
+                        };
+                        for (member_idx, builtin, ty) in builtins {
+                            let is_used = match (member_idx, member_idxs.as_ref()) {
+                                (Some(i), Some(idxs)) => idxs.contains(&i),
+                                _ => true,
+                            };
+                            if is_used {
+                                builtin_map.insert(builtin, ty);
                             }
                         }
                     },
-                    Variable::Descriptor(desc_bind, desc_ty) => {
+                    Variable::Descriptor(desc_bind, desc_ty, allowed) => {
+                        let paths = accessed_paths.get(&accessed_var_id)
+                            .map_or(&[] as &[AccessPath], |x| x.as_ref());
+                        if let Some(syms) = self.collect_used_members(accessed_var_id, &desc_ty, paths) {
+                            entry_point.manifest.desc_usage_map.insert(desc_bind, syms);
+                        }
                         // Descriptors cannot share bindings.
                         if entry_point.manifest.desc_map.insert(desc_bind, desc_ty).is_some() {
-                            return Err(Error::DESC_BIND_COLLISION);
+                            return Err(self.err_at_def(accessed_var_id, Error::DESC_BIND_COLLISION));
                         }
+                        let access = to_access_ty(flags, allowed);
+                        entry_point.manifest.desc_access_map.insert(desc_bind, access);
                         if let Some(name) = self.get_name(accessed_var_id, None) {
                             if entry_point.manifest.var_name_map
                                 .insert(name.to_owned(), ResourceLocator::Descriptor(desc_bind)).is_some() {
-                                return Err(Error::NAME_COLLISION);
+                                return Err(self.err_at_def(accessed_var_id, Error::NAME_COLLISION));
                             }
                         }
                     },
                 };
             }
+            // Members of anonymous blocks are referred to by their own names,
+            // as in GLSL, unless they are shadowed by other variables.
+            for (name, locator) in anonymous_member_names {
+                entry_point.manifest.var_name_map.entry(name)
+                    .or_insert(locator);
+            }
             entry_points.push(entry_point);
         }
         Ok(entry_points.into_boxed_slice())
@@ -542,23 +1880,878 @@ impl<'a> ReflectIntermediate<'a> {
 }
 
 
-pub(crate) fn reflect_spirv<'a>(module: &'a SpirvBinary) -> Result<Box<[EntryPoint]>> {
-    fn skip_until_range_inclusive<'a>(instrs: &'_ mut Peekable<Instrs<'a>>, rng: RangeInclusive<u32>) {
-        while let Some(instr) = instrs.peek() {
-            if !rng.contains(&instr.opcode()) { instrs.next(); } else { break; }
-        }
-    }
+pub(crate) fn reflect_module_info(module: &SpirvModule) -> Result<ModuleInfo> {
+    module.header()?;
+    let mut instrs = module.instrs().peekable();
+    let mut itm = ReflectIntermediate::default();
+    itm.populate_entry_points(&mut instrs)?;
+    itm.collect_module_info()
+}
+fn populate<'a>(module: &'a SpirvModule, spec_values: &HashMap<u32, SpecValue>) -> Result<ReflectIntermediate<'a>> {
+    // Reject binaries that are not SPIR-V at all rather than reporting no
+    // entry point.
+    let header = module.header()?;
     // Don't change the order. See _2.4 Logical Layout of a Module_ of the
     // SPIR-V specification for more information.
     let mut instrs = module.instrs().peekable();
-    let mut itm = ReflectIntermediate::default();
-    skip_until_range_inclusive(&mut instrs, ENTRY_POINT_RANGE);
+    // Each section is optional, so the instructions of other kinds in a
+    // section are skipped rather than searching for the next section.
+    let mut itm = ReflectIntermediate {
+        version: (header.major_version, header.minor_version),
+        spec_values: spec_values.clone(),
+        ..Default::default()
+    };
     itm.populate_entry_points(&mut instrs)?;
-    skip_until_range_inclusive(&mut instrs, NAME_RANGE);
     itm.populate_names(&mut instrs)?;
-    skip_until_range_inclusive(&mut instrs, DECO_RANGE);
     itm.populate_decos(&mut instrs)?;
     itm.populate_defs(&mut instrs)?;
     itm.populate_access(&mut instrs)?;
-    Ok(itm.collect_entry_points()?)
+    Ok(itm)
+}
+pub(crate) fn reflect_spirv(module: &SpirvModule, spec_values: &HashMap<u32, SpecValue>) -> Result<Box<[EntryPoint]>> {
+    populate(module, spec_values)?.collect_entry_points()
+}
+pub(crate) fn reflect_call_graph(module: &SpirvModule) -> Result<CallGraph> {
+    let itm = populate(module, &HashMap::new())?;
+    Ok(itm.collect_call_graph())
+}
+pub(crate) fn reflect_const_table(module: &SpirvModule) -> Result<ConstantTable> {
+    let itm = populate(module, &HashMap::new())?;
+    Ok(itm.collect_const_table())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SpirvBinary, Error, ExecutionModeValue, ConstantValue, DescriptorBinding, AccessType, Location,
+        ResourceLocator};
+    use crate::ty::{Type, DescriptorType};
+    use spirv_headers::{BuiltIn, Capability, ExecutionMode, ExecutionModel, Op};
+
+    #[test]
+    fn test_unknown_cap() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability 65535
+               OpMemoryModel Logical GLSL450
+"#).unwrap();
+        let module_info = spv.module_info().unwrap();
+        assert_eq!(module_info.caps, vec![Capability::Shader]);
+        assert_eq!(module_info.caps_raw, vec![1, 65535]);
+        assert!(module_info.has_cap_raw(65535));
+    }
+
+    #[test]
+    fn test_exec_mode_values() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Geometry
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Geometry %main "main"
+               OpExecutionMode %main Triangles
+               OpExecutionMode %main Invocations 2
+               OpExecutionMode %main OutputTriangleStrip
+               OpExecutionMode %main OutputVertices 3
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let entry_point = &entry_points[0];
+        assert_eq!(entry_point.get_exec_mode_value(ExecutionMode::Triangles), Some(ExecutionModeValue::Flag));
+        assert_eq!(entry_point.get_exec_mode_value(ExecutionMode::Invocations), Some(ExecutionModeValue::Invocations(2)));
+        assert_eq!(entry_point.get_exec_mode_value(ExecutionMode::OutputVertices), Some(ExecutionModeValue::OutputVertices(3)));
+        assert_eq!(entry_point.get_exec_mode(ExecutionMode::OutputVertices), Some(&[3][..]));
+        assert_eq!(entry_point.get_exec_mode_value(ExecutionMode::LocalSize), None);
+    }
+
+    #[test]
+    fn test_spec_consts_per_entry_point() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpEntryPoint GLCompute %other "other"
+               OpExecutionModeId %main LocalSizeId %x %one %one
+               OpExecutionMode %other LocalSize 1 1 1
+               OpDecorate %x SpecId 0
+               OpDecorate %n SpecId 1
+               OpDecorate %unused SpecId 3
+       %uint = OpTypeInt 32 0
+       %vec2 = OpTypeVector %uint 2
+        %one = OpConstant %uint 1
+          %x = OpSpecConstant %uint 8
+          %n = OpSpecConstant %uint 4
+      %undef = OpUndef %uint
+          %c = OpSpecConstantComposite %vec2 %x %undef
+     %unused = OpSpecConstant %uint 0
+         %n2 = OpSpecConstantOp %uint IAdd %n %one
+        %arr = OpTypeArray %uint %n2
+    %arr_ptr = OpTypePointer Function %arr
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+     %entry0 = OpLabel
+          %a = OpVariable %arr_ptr Function
+               OpReturn
+               OpFunctionEnd
+      %other = OpFunction %void None %void_f
+     %entry1 = OpLabel
+         %xc = OpCompositeExtract %uint %c 0
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let spec_ids = |i: usize| {
+            entry_points[i].spec_consts.iter()
+                .map(|x| x.spec_id)
+                .collect::<Vec<_>>()
+        };
+        // `n` is used by the array length `n2` of a local variable.
+        assert_eq!(spec_ids(0), vec![Some(0), Some(1)]);
+        assert_eq!(spec_ids(1), vec![Some(0), None]);
+        assert_eq!(entry_points[0].local_size(), Some((8, 1, 1)));
+        // Composites of undefined values can't be decoded.
+        assert_eq!(entry_points[1].spec_consts[1].default_value, None);
+    }
+
+    #[test]
+    fn test_const_table() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability Int64
+               OpMemoryModel Logical GLSL450
+               OpName %n "n"
+               OpDecorate %n SpecId 0
+        %int = OpTypeInt 32 1
+      %ulong = OpTypeInt 64 0
+      %v2int = OpTypeVector %int 2
+          %a = OpConstant %int -3
+          %b = OpConstant %ulong 4294967296
+          %v = OpConstantComposite %v2int %a %a
+          %n = OpSpecConstant %int 4
+         %n2 = OpSpecConstantOp %int IMul %n %a
+"#).unwrap();
+        let consts = spv.constants().unwrap();
+        let values = consts.consts.iter()
+            .map(|x| (x.value.clone(), x.is_spec))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![
+            (ConstantValue::I32(-3), false),
+            (ConstantValue::U64(1 << 32), false),
+            (ConstantValue::Composite(vec![ConstantValue::I32(-3); 2]), false),
+            (ConstantValue::I32(4), true),
+            (ConstantValue::I32(-12), true),
+        ]);
+        let n = consts.get_const_by_name("n").unwrap();
+        assert_eq!(consts.get_const(n.const_id).unwrap().value, ConstantValue::I32(4));
+    }
+
+    #[test]
+    fn test_interface_is_authoritative() {
+        let spv = SpirvBinary::assemble(r#"
+; Version: 1.4
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %buf %color
+               OpExecutionMode %main OriginUpperLeft
+               OpName %buf "buf"
+               OpName %ubo "ubo"
+               OpName %color "color"
+               OpDecorate %color Location 0
+               OpDecorate %Buf Block
+               OpMemberDecorate %Buf 0 Offset 0
+               OpDecorate %buf DescriptorSet 0
+               OpDecorate %buf Binding 0
+               OpDecorate %ubo DescriptorSet 0
+               OpDecorate %ubo Binding 1
+      %float = OpTypeFloat 32
+       %vec4 = OpTypeVector %float 4
+        %Buf = OpTypeStruct %float
+    %buf_ptr = OpTypePointer StorageBuffer %Buf
+    %ubo_ptr = OpTypePointer Uniform %Buf
+    %out_ptr = OpTypePointer Output %vec4
+        %buf = OpVariable %buf_ptr StorageBuffer
+        %ubo = OpVariable %ubo_ptr Uniform
+      %color = OpVariable %out_ptr Output
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+          %x = OpLoad %Buf %ubo
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let manifest = &entry_points[0].manifest;
+        // Variables in the interface are used even if no access is found.
+        let buf = DescriptorBinding::desc_bind(0, 0);
+        assert_eq!(manifest.get_desc_access(buf), Some(AccessType::ReadWrite));
+        assert_eq!(manifest.get_desc_used_ranges(buf), Some(std::iter::once(0..4).collect()));
+        assert!(manifest.get_output(Location::from(0)).is_some());
+        // Variables out of the interface are not.
+        assert!(manifest.get_desc(DescriptorBinding::desc_bind(0, 1)).is_none());
+    }
+
+    #[test]
+    fn test_merged_ptrs() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability VariablePointers
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpDecorate %Buf BufferBlock
+               OpMemberDecorate %Buf 0 Offset 0
+               OpMemberDecorate %Buf 1 Offset 4
+               OpDecorate %a DescriptorSet 0
+               OpDecorate %a Binding 0
+               OpDecorate %b DescriptorSet 0
+               OpDecorate %b Binding 1
+               OpDecorate %c DescriptorSet 0
+               OpDecorate %c Binding 2
+       %bool = OpTypeBool
+       %true = OpConstantTrue %bool
+       %uint = OpTypeInt 32 0
+         %u0 = OpConstant %uint 0
+         %u1 = OpConstant %uint 1
+        %Buf = OpTypeStruct %uint %uint
+    %buf_ptr = OpTypePointer Uniform %Buf
+   %uint_ptr = OpTypePointer Uniform %uint
+          %a = OpVariable %buf_ptr Uniform
+          %b = OpVariable %buf_ptr Uniform
+          %c = OpVariable %buf_ptr Uniform
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+        %sel = OpSelect %buf_ptr %true %a %b
+          %p = OpAccessChain %uint_ptr %sel %u1
+          %x = OpLoad %uint %p
+               OpBranch %loop
+       %loop = OpLabel
+        %phi = OpPhi %buf_ptr %c %entry %next %loop
+          %q = OpAccessChain %uint_ptr %phi %u0
+               OpStore %q %x
+       %next = OpCopyObject %buf_ptr %phi
+               OpLoopMerge %exit %loop None
+               OpBranchConditional %true %loop %exit
+       %exit = OpLabel
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let manifest = &entry_points[0].manifest;
+        let a = DescriptorBinding::desc_bind(0, 0);
+        let b = DescriptorBinding::desc_bind(0, 1);
+        let c = DescriptorBinding::desc_bind(0, 2);
+        // Either source of `OpSelect` can be accessed.
+        assert_eq!(manifest.get_desc_access(a), Some(AccessType::ReadOnly));
+        assert_eq!(manifest.get_desc_access(b), Some(AccessType::ReadOnly));
+        assert_eq!(manifest.get_desc_used_ranges(a), Some(std::iter::once(4..8).collect()));
+        // `OpPhi` sources are followed through loops.
+        assert_eq!(manifest.get_desc_access(c), Some(AccessType::WriteOnly));
+        assert_eq!(manifest.get_desc_used_ranges(c), Some(std::iter::once(0..4).collect()));
+    }
+
+    #[test]
+    fn test_multibind_used_as_whole() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpDecorate %Buf Block
+               OpMemberDecorate %Buf 0 Offset 0
+               OpMemberDecorate %Buf 1 Offset 4
+               OpDecorate %bufs DescriptorSet 0
+               OpDecorate %bufs Binding 0
+       %uint = OpTypeInt 32 0
+         %u2 = OpConstant %uint 2
+        %Buf = OpTypeStruct %uint %uint
+        %arr = OpTypeArray %Buf %u2
+    %arr_ptr = OpTypePointer Uniform %arr
+       %bufs = OpVariable %arr_ptr Uniform
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+          %x = OpLoad %arr %bufs
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let manifest = &entry_points[0].manifest;
+        let bufs = DescriptorBinding::desc_bind(0, 0);
+        assert_eq!(manifest.get_desc_used_ranges(bufs), Some(std::iter::once(0..8).collect()));
+    }
+
+    #[test]
+    fn test_call_graph_diamonds() {
+        // Every function calls the next one twice, so there are 2^40 paths
+        // from the entry point to the leaf.
+        const NLEVEL: usize = 40;
+        let mut src = String::from(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %f0 "main"
+               OpExecutionMode %f0 LocalSize 1 1 1
+               OpDecorate %Buf Block
+               OpMemberDecorate %Buf 0 Offset 0
+               OpDecorate %buf DescriptorSet 0
+               OpDecorate %buf Binding 0
+       %uint = OpTypeInt 32 0
+        %Buf = OpTypeStruct %uint
+    %buf_ptr = OpTypePointer Uniform %Buf
+        %buf = OpVariable %buf_ptr Uniform
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+"#);
+        for i in 0..NLEVEL {
+            src += &format!("%f{0} = OpFunction %void None %void_f\n%l{0} = OpLabel\n", i);
+            src += &format!("%a{0} = OpFunctionCall %void %f{1}\n%b{0} = OpFunctionCall %void %f{1}\n", i, i + 1);
+            src += "OpReturn\nOpFunctionEnd\n";
+        }
+        src += &format!("%f{0} = OpFunction %void None %void_f\n%l{0} = OpLabel\n", NLEVEL);
+        src += "%x = OpLoad %Buf %buf\nOpReturn\nOpFunctionEnd\n";
+        let spv = SpirvBinary::assemble(&src).unwrap();
+        let buf = DescriptorBinding::desc_bind(0, 0);
+        let entry_points = spv.reflect().unwrap();
+        assert!(entry_points[0].manifest.get_desc(buf).is_some());
+        let call_graph = spv.call_graph().unwrap();
+        let (root, leaf) = (&call_graph.funcs[0], &call_graph.funcs[NLEVEL]);
+        assert_eq!(root.entry_points, vec![(ExecutionModel::GLCompute, "main".to_owned())]);
+        assert_eq!(leaf.entry_points, root.entry_points);
+        assert_eq!(leaf.callers, vec![call_graph.funcs[NLEVEL - 1].func_id]);
+        assert_eq!(root.callees, vec![call_graph.funcs[1].func_id]);
+        assert_eq!(root.direct_rscs, vec![]);
+        assert_eq!(root.transitive_rscs, vec![ResourceLocator::Descriptor(buf)]);
+        assert_eq!(leaf.direct_rscs, vec![ResourceLocator::Descriptor(buf)]);
+    }
+
+    #[test]
+    fn test_nested_block_members() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %vs_out
+               OpName %B "B"
+               OpMemberName %B 0 "uv"
+               OpMemberName %B 1 "s"
+               OpMemberName %B 2 "arr"
+               OpName %S "S"
+               OpMemberName %S 0 "x"
+               OpMemberName %S 1 "y"
+               OpName %vs_out "vs_out"
+               OpDecorate %B Block
+               OpDecorate %vs_out Location 0
+      %float = OpTypeFloat 32
+       %vec2 = OpTypeVector %float 2
+       %vec3 = OpTypeVector %float 3
+       %uint = OpTypeInt 32 0
+         %u0 = OpConstant %uint 0
+         %u2 = OpConstant %uint 2
+          %S = OpTypeStruct %float %vec3
+      %S_arr = OpTypeArray %S %u2
+          %B = OpTypeStruct %vec2 %S %S_arr
+      %B_ptr = OpTypePointer Output %B
+   %vec2_ptr = OpTypePointer Output %vec2
+     %vs_out = OpVariable %B_ptr Output
+       %zero = OpConstantNull %vec2
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+         %uv = OpAccessChain %vec2_ptr %vs_out %u0
+               OpStore %uv %zero
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let manifest = &entry_points[0].manifest;
+        let location = |name: &str| manifest.resolve_output(name).map(|x| u32::from(x.location));
+        assert_eq!(location("vs_out.uv"), Some(0));
+        assert_eq!(location("vs_out.s.x"), Some(1));
+        assert_eq!(location("vs_out.s.y"), Some(2));
+        assert_eq!(location("vs_out.arr.0.x"), Some(3));
+        assert_eq!(location("vs_out.arr.1.y"), Some(6));
+        assert!(manifest.get_output(Location::from(2)).is_some_and(Type::is_vec));
+    }
+
+    #[test]
+    fn test_struct_component_masks() {
+        use crate::ty::{ScalarType, VectorType, StructType, StructMember};
+        let float = Type::Scalar(ScalarType::Float(4));
+        let dvec3 = Type::Vector(VectorType::new(ScalarType::Float(8), 3));
+        let mut struct_ty = StructType::default();
+        for (offset, ty) in [(0, float.clone()), (16, dvec3.clone())] {
+            let member = StructMember { name: None, offset, ty };
+            struct_ty.push_member(member).unwrap();
+        }
+        let ty = Type::Struct(struct_ty);
+        assert_eq!(dvec3.component_masks(0), Some(vec![0b1111, 0b0011]));
+        assert_eq!(float.component_masks(2), Some(vec![0b0100]));
+        // Struct members each start at a new location, regardless of the
+        // component the struct starts at.
+        assert_eq!(ty.component_masks(1), Some(vec![0b0001, 0b1111, 0b0011]));
+        assert_eq!(ty.nlocation(), Some(3));
+    }
+
+    #[test]
+    fn test_dual_src_outputs() {
+        let mut spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %color0 %color1
+               OpExecutionMode %main OriginUpperLeft
+               OpName %color0 "color0"
+               OpName %color1 "color1"
+               OpDecorate %color0 Location 0
+               OpDecorate %color0 Index 0
+               OpDecorate %color1 Location 0
+               OpDecorate %color1 Index 1
+      %float = OpTypeFloat 32
+       %vec4 = OpTypeVector %float 4
+       %vec2 = OpTypeVector %float 2
+   %vec4_ptr = OpTypePointer Output %vec4
+   %vec2_ptr = OpTypePointer Output %vec2
+     %color0 = OpVariable %vec4_ptr Output
+     %color1 = OpVariable %vec2_ptr Output
+      %zero4 = OpConstantNull %vec4
+      %zero2 = OpConstantNull %vec2
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+               OpStore %color0 %zero4
+               OpStore %color1 %zero2
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let check = |manifest: &crate::Manifest| {
+            assert!(manifest.is_dual_src_blending());
+            assert!(manifest.get_output(Location::from(0)).is_some_and(|x| x.nbyte() == Some(16)));
+            assert!(manifest.get_dual_src_output(Location::from(0)).is_some_and(|x| x.nbyte() == Some(8)));
+            assert_eq!(manifest.get_output_name(Location::from(0)), Some("color0"));
+            let color1 = manifest.resolve_output("color1").unwrap();
+            assert_eq!(color1.quals.index, 1);
+            assert_eq!(color1.ty.nbyte(), Some(8));
+            assert_eq!(manifest.resolve_output("0").map(|x| x.quals.index), Some(0));
+            assert_eq!(manifest.outputs().count(), 2);
+        };
+        check(&spv.reflect().unwrap()[0].manifest);
+        // Names of second-source outputs survive stripping.
+        let sidecar = spv.strip_debug().unwrap();
+        let sidecar = sidecar.to_string().parse().unwrap();
+        let mut entry_point = spv.reflect().unwrap()[0].clone();
+        assert!(entry_point.resolve_output("color1").is_none());
+        entry_point.attach_names(&sidecar).unwrap();
+        check(&entry_point.manifest);
+    }
+
+    #[test]
+    fn test_block_arrays() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability Geometry
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %vert "vert" %blk
+               OpEntryPoint Geometry %geom "geom" %blk_in
+               OpExecutionMode %geom Triangles
+               OpExecutionMode %geom OutputPoints
+               OpExecutionMode %geom OutputVertices 1
+               OpExecutionMode %geom Invocations 1
+               OpName %B "B"
+               OpMemberName %B 0 "x"
+               OpMemberName %B 1 "y"
+               OpName %blk "blk"
+               OpName %blk_in "blk_in"
+               OpDecorate %B Block
+               OpDecorate %blk Location 0
+               OpDecorate %blk_in Location 0
+      %float = OpTypeFloat 32
+       %vec4 = OpTypeVector %float 4
+       %uint = OpTypeInt 32 0
+         %u0 = OpConstant %uint 0
+         %u2 = OpConstant %uint 2
+         %u3 = OpConstant %uint 3
+          %B = OpTypeStruct %vec4 %vec4
+      %B_arr = OpTypeArray %B %u2
+     %B_arr3 = OpTypeArray %B %u3
+  %B_arr_ptr = OpTypePointer Output %B_arr
+ %B_arr3_ptr = OpTypePointer Input %B_arr3
+   %vec4_ptr = OpTypePointer Output %vec4
+%vec4_in_ptr = OpTypePointer Input %vec4
+         %u1 = OpConstant %uint 1
+        %blk = OpVariable %B_arr_ptr Output
+     %blk_in = OpVariable %B_arr3_ptr Input
+       %zero = OpConstantNull %vec4
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %vert = OpFunction %void None %void_f
+     %entry0 = OpLabel
+          %x = OpAccessChain %vec4_ptr %blk %u0 %u0
+               OpStore %x %zero
+               OpReturn
+               OpFunctionEnd
+       %geom = OpFunction %void None %void_f
+     %entry1 = OpLabel
+          %y = OpAccessChain %vec4_in_ptr %blk_in %u0 %u1
+         %yy = OpLoad %vec4 %y
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        // Elements of arrays of blocks take consecutive locations.
+        let manifest = &entry_points[0].manifest;
+        let location = |name: &str| manifest.resolve_output(name).map(|x| u32::from(x.location));
+        assert_eq!(location("blk.0.x"), Some(0));
+        assert_eq!(location("blk.0.y"), Some(1));
+        assert_eq!(location("blk.1.x"), Some(2));
+        assert_eq!(location("blk.1.y"), Some(3));
+        assert!(manifest.outputs().all(|x| x.nvertex.is_none() && x.ty.is_vec()));
+        // Per-vertex arrays of blocks are reported by the elements.
+        let manifest = &entry_points[1].manifest;
+        let y = manifest.resolve_input("blk_in.y").unwrap();
+        assert_eq!(u32::from(y.location), 1);
+        assert_eq!(y.nvertex, Some(3));
+        assert!(y.ty.is_vec());
+        assert_eq!(manifest.inputs().count(), 2);
+    }
+
+    #[test]
+    fn test_block_without_location() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %vs_out
+               OpDecorate %B Block
+               OpDecorate %vs_out Location 0
+               OpMemberDecorate %B 1 Location 3
+      %float = OpTypeFloat 32
+          %B = OpTypeStruct %float %float
+      %B_ptr = OpTypePointer Output %B
+     %vs_out = OpVariable %B_ptr Output
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        // Blocks located in part are fine.
+        assert!(spv.reflect().is_ok());
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %vs_out
+               OpDecorate %B Block
+               OpMemberDecorate %B 1 Location 3
+      %float = OpTypeFloat 32
+          %B = OpTypeStruct %float %float
+      %B_ptr = OpTypePointer Output %B
+     %vs_out = OpVariable %B_ptr Output
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let err = spv.reflect().unwrap_err();
+        assert_eq!(err.kind(), &Error::LOCATION_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_runtime_array() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpDecorate %arr ArrayStride 4
+               OpMemberDecorate %buf 0 Offset 0
+               OpMemberDecorate %buf 1 Offset 16
+               OpDecorate %buf BufferBlock
+               OpDecorate %var DescriptorSet 1
+               OpDecorate %var Binding 2
+      %float = OpTypeFloat 32
+       %uint = OpTypeInt 32 0
+        %arr = OpTypeRuntimeArray %float
+        %buf = OpTypeStruct %uint %arr
+    %buf_ptr = OpTypePointer Uniform %buf
+        %var = OpVariable %buf_ptr Uniform
+      %int_1 = OpConstant %uint 1
+  %float_ptr = OpTypePointer Uniform %float
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+          %p = OpAccessChain %float_ptr %var %int_1 %int_1
+          %x = OpLoad %float %p
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let desc_ty = entry_points[0].get_desc(DescriptorBinding::desc_bind(1, 2)).unwrap();
+        let ty = match desc_ty {
+            DescriptorType::StorageBuffer(1, ty) => ty,
+            x => panic!("unexpected descriptor type: {:?}", x),
+        };
+        // Only the sized part of the buffer is counted.
+        assert_eq!(ty.nbyte(), Some(16));
+        match desc_ty.resolve("1").unwrap().ty {
+            Type::Array(arr_ty) => assert_eq!(arr_ty.nrepeat(), None),
+            x => panic!("unexpected member type: {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_spec_const() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpName %x "x"
+               OpDecorate %x SpecId 7
+        %int = OpTypeInt 32 1
+          %x = OpSpecConstant %int -3
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+          %y = OpIAdd %int %x %x
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let spec_const = entry_points[0].get_spec_const(7).unwrap();
+        assert_eq!(spec_const.name.as_deref(), Some("x"));
+        assert_eq!(spec_const.default_value, Some(ConstantValue::I32(-3)));
+    }
+
+    #[test]
+    fn test_input_component() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %a %b
+               OpDecorate %a Location 3
+               OpDecorate %b Location 3
+               OpDecorate %b Component 2
+      %float = OpTypeFloat 32
+       %vec2 = OpTypeVector %float 2
+   %vec2_ptr = OpTypePointer Input %vec2
+          %a = OpVariable %vec2_ptr Input
+          %b = OpVariable %vec2_ptr Input
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+         %xa = OpLoad %vec2 %a
+         %xb = OpLoad %vec2 %b
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        let used = entry_points[0].input_locations_used();
+        assert_eq!(used, vec![(Location::from(3), 0b1111)]);
+    }
+
+    #[test]
+    fn test_undecodable_operands_in_function() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpDecorate %x SpecId 7
+       %uint = OpTypeInt 32 0
+          %x = OpSpecConstant %uint 3
+   %uint_ptr = OpTypePointer Function %uint
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+          %v = OpVariable %uint_ptr Function %x
+          %y = OpLoad %uint %v Aligned 4
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        // Drop the alignment literal of `Aligned`. The load can still be
+        // reflected, and the ids before the broken operand are collected.
+        let mut editor = spv.edit().unwrap();
+        let i = editor.position(|x| x.op() == Some(Op::Load)).unwrap();
+        editor.instrs_mut()[i].operand_words_mut().pop();
+        let spv = editor.encode().unwrap();
+        let entry_points = spv.reflect().unwrap();
+        assert!(entry_points[0].get_spec_const(7).is_some());
+    }
+
+    #[test]
+    fn test_per_vertex_nvertex() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability Geometry
+               OpCapability Tessellation
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Geometry %geom "geom" %g_in
+               OpEntryPoint TessellationControl %tesc "tesc" %c_in %c_out %c_patch
+               OpEntryPoint TessellationEvaluation %tese "tese" %e_in
+               OpExecutionMode %geom InputLinesAdjacency
+               OpExecutionMode %geom OutputPoints
+               OpExecutionMode %geom OutputVertices 1
+               OpExecutionMode %geom Invocations 1
+               OpExecutionMode %tesc OutputVertices 3
+               OpExecutionMode %tese Triangles
+               OpName %g_in "g_in"
+               OpName %c_in "c_in"
+               OpName %c_out "c_out"
+               OpName %c_patch "c_patch"
+               OpName %e_in "e_in"
+               OpDecorate %g_in Location 0
+               OpDecorate %c_in Location 0
+               OpDecorate %c_out Location 0
+               OpDecorate %c_patch Location 1
+               OpDecorate %c_patch Patch
+               OpDecorate %e_in Location 0
+      %float = OpTypeFloat 32
+       %vec4 = OpTypeVector %float 4
+       %uint = OpTypeInt 32 0
+         %u2 = OpConstant %uint 2
+         %u3 = OpConstant %uint 3
+         %u4 = OpConstant %uint 4
+        %u32 = OpConstant %uint 32
+     %vec4x2 = OpTypeArray %vec4 %u2
+     %vec4x3 = OpTypeArray %vec4 %u3
+     %vec4x4 = OpTypeArray %vec4 %u4
+    %vec4x32 = OpTypeArray %vec4 %u32
+  %vec4x4_in = OpTypePointer Input %vec4x4
+ %vec4x32_in = OpTypePointer Input %vec4x32
+ %vec4x3_out = OpTypePointer Output %vec4x3
+ %vec4x2_out = OpTypePointer Output %vec4x2
+       %g_in = OpVariable %vec4x4_in Input
+       %c_in = OpVariable %vec4x32_in Input
+      %c_out = OpVariable %vec4x3_out Output
+    %c_patch = OpVariable %vec4x2_out Output
+       %e_in = OpVariable %vec4x32_in Input
+      %null3 = OpConstantNull %vec4x3
+      %null2 = OpConstantNull %vec4x2
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %geom = OpFunction %void None %void_f
+     %entry0 = OpLabel
+          %g = OpLoad %vec4x4 %g_in
+               OpReturn
+               OpFunctionEnd
+       %tesc = OpFunction %void None %void_f
+     %entry1 = OpLabel
+          %c = OpLoad %vec4x32 %c_in
+               OpStore %c_out %null3
+               OpStore %c_patch %null2
+               OpReturn
+               OpFunctionEnd
+       %tese = OpFunction %void None %void_f
+     %entry2 = OpLabel
+          %e = OpLoad %vec4x32 %e_in
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        // Geometry inputs are sized by the input primitive.
+        let g_in = entry_points[0].resolve_input("g_in").unwrap();
+        assert_eq!(g_in.nvertex, Some(4));
+        assert!(g_in.ty.is_vec());
+        // Tessellation control outputs are sized by `OutputVertices`, and the
+        // inputs by `gl_MaxPatchVertices`, i.e., the array size.
+        let tesc = &entry_points[1];
+        let c_in = tesc.resolve_input("c_in").unwrap();
+        assert_eq!(c_in.nvertex, Some(32));
+        assert!(c_in.ty.is_vec());
+        let c_out = tesc.resolve_output("c_out").unwrap();
+        assert_eq!(c_out.nvertex, Some(3));
+        assert!(c_out.ty.is_vec());
+        // `Patch` outputs are not per-vertex and keep their array types.
+        let c_patch = tesc.resolve_output("c_patch").unwrap();
+        assert_eq!(c_patch.nvertex, None);
+        assert!(matches!(c_patch.ty, Type::Array(_)));
+        assert!(c_patch.quals.is_patch);
+        // Tessellation evaluation inputs have no execution mode for the size.
+        let e_in = entry_points[2].resolve_input("e_in").unwrap();
+        assert_eq!(e_in.nvertex, Some(32));
+        assert!(e_in.ty.is_vec());
+    }
+
+    #[test]
+    fn test_builtins() {
+        let spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability Geometry
+               OpCapability ClipDistance
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %vert "vert" %vert_idx %per_vert
+               OpEntryPoint Geometry %geom "geom" %per_vert_in
+               OpExecutionMode %geom Triangles
+               OpExecutionMode %geom OutputPoints
+               OpExecutionMode %geom OutputVertices 1
+               OpExecutionMode %geom Invocations 1
+               OpDecorate %vert_idx BuiltIn VertexIndex
+               OpMemberDecorate %PerVertex 0 BuiltIn Position
+               OpMemberDecorate %PerVertex 1 BuiltIn PointSize
+               OpMemberDecorate %PerVertex 2 BuiltIn ClipDistance
+               OpDecorate %PerVertex Block
+      %float = OpTypeFloat 32
+       %vec4 = OpTypeVector %float 4
+        %int = OpTypeInt 32 1
+       %uint = OpTypeInt 32 0
+         %i0 = OpConstant %int 0
+         %i1 = OpConstant %int 1
+         %u1 = OpConstant %uint 1
+         %u3 = OpConstant %uint 3
+   %float_x1 = OpTypeArray %float %u1
+  %PerVertex = OpTypeStruct %vec4 %float %float_x1
+%PerVertex_x3 = OpTypeArray %PerVertex %u3
+%PerVertex_out = OpTypePointer Output %PerVertex
+%PerVertex_x3_in = OpTypePointer Input %PerVertex_x3
+     %int_in = OpTypePointer Input %int
+   %vec4_out = OpTypePointer Output %vec4
+    %vec4_in = OpTypePointer Input %vec4
+   %vert_idx = OpVariable %int_in Input
+   %per_vert = OpVariable %PerVertex_out Output
+%per_vert_in = OpVariable %PerVertex_x3_in Input
+       %zero = OpConstantNull %vec4
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %vert = OpFunction %void None %void_f
+     %entry0 = OpLabel
+        %idx = OpLoad %int %vert_idx
+        %pos = OpAccessChain %vec4_out %per_vert %i0
+               OpStore %pos %zero
+               OpReturn
+               OpFunctionEnd
+       %geom = OpFunction %void None %void_f
+     %entry1 = OpLabel
+     %pos_in = OpAccessChain %vec4_in %per_vert_in %i1 %i0
+          %p = OpLoad %vec4 %pos_in
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let entry_points = spv.reflect().unwrap();
+        // Only the written members of `gl_PerVertex` are reported.
+        let vert = &entry_points[0];
+        assert!(vert.get_input_builtin(BuiltIn::VertexIndex).unwrap().is_scalar());
+        assert!(vert.get_output_builtin(BuiltIn::Position).unwrap().is_vec());
+        assert!(vert.get_output_builtin(BuiltIn::PointSize).is_none());
+        assert!(vert.get_output_builtin(BuiltIn::ClipDistance).is_none());
+        assert_eq!(vert.outputs_builtin().count(), 1);
+        assert_eq!(vert.outputs().count(), 0);
+        // Members of per-vertex `gl_in` are indexed after the vertex.
+        let geom = &entry_points[1];
+        assert!(geom.get_input_builtin(BuiltIn::Position).unwrap().is_vec());
+        assert!(geom.get_input_builtin(BuiltIn::ClipDistance).is_none());
+        assert_eq!(geom.inputs_builtin().count(), 1);
+        assert_eq!(geom.inputs().count(), 0);
+    }
 }
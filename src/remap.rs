@@ -0,0 +1,304 @@
+//! Resource remapping procedures.
+use std::collections::{HashMap, HashSet};
+use spirv_headers::{Decoration, StorageClass};
+use crate::consts::*;
+use crate::edit::{InstrBuf, ModuleEditor};
+use crate::error::Result;
+use crate::{DescriptorBinding, Location};
+
+/// New descriptor bindings, input attachment indices and input locations to be
+/// written into a module. Resources not mentioned are kept untouched.
+#[derive(Debug, Default, Clone)]
+pub struct Remapping {
+    desc_bind_map: HashMap<DescriptorBinding, (u32, u32)>,
+    desc_name_map: HashMap<String, (u32, u32)>,
+    input_attm_idx_map: HashMap<u32, u32>,
+    input_location_map: HashMap<Location, Location>,
+}
+impl Remapping {
+    /// Move the descriptor at `desc_bind` to set `desc_set` and binding
+    /// `bind_point`.
+    pub fn remap_desc_bind(&mut self, desc_bind: DescriptorBinding, desc_set: u32, bind_point: u32) -> &mut Remapping {
+        self.desc_bind_map.insert(desc_bind, (desc_set, bind_point));
+        self
+    }
+    /// Move the descriptor named `name` to set `desc_set` and binding
+    /// `bind_point`. Name remapping takes precedence over binding remapping.
+    pub fn remap_desc_bind_by_name(&mut self, name: &str, desc_set: u32, bind_point: u32) -> &mut Remapping {
+        self.desc_name_map.insert(name.to_owned(), (desc_set, bind_point));
+        self
+    }
+    /// Change the input attachment index `from` to `to`.
+    pub fn remap_input_attm_idx(&mut self, from: u32, to: u32) -> &mut Remapping {
+        self.input_attm_idx_map.insert(from, to);
+        self
+    }
+    /// Move the input variable, usually a vertex attribute, at location `from`
+    /// to location `to`. Input block members decorated with location `from`
+    /// are moved as well.
+    pub fn remap_input_location(&mut self, from: Location, to: Location) -> &mut Remapping {
+        self.input_location_map.insert(from, to);
+        self
+    }
+}
+
+#[derive(Default)]
+struct VariableDecorations {
+    store_cls: Option<StorageClass>,
+    ty_id: Option<u32>,
+    name: Option<String>,
+    // Indices of the decoration instructions, if present, and the decorated
+    // values.
+    desc_set: Option<(usize, u32)>,
+    bind_point: Option<(usize, u32)>,
+    input_attm_idx: Option<(usize, u32)>,
+    location: Option<(usize, u32)>,
+}
+
+fn set_deco(editor: &mut ModuleEditor, new_decos: &mut Vec<InstrBuf>, var_id: u32, deco: Decoration, old: Option<(usize, u32)>, value: u32) {
+    if let Some((i, _)) = old {
+        editor.instrs_mut()[i].operand_words_mut()[2] = value;
+    } else {
+        let mut instr = InstrBuf::new(spirv_headers::Op::Decorate);
+        instr.push_u32(var_id).push_u32(deco as u32).push_u32(value);
+        new_decos.push(instr);
+    }
+}
+
+pub(crate) fn remap(editor: &mut ModuleEditor, remapping: &Remapping) -> Result<()> {
+    let mut var_map: HashMap<u32, VariableDecorations> = HashMap::new();
+    // Pointee types of pointer types and element types of array types, to
+    // find the blocks of block variables.
+    let mut inner_ty_map: HashMap<u32, u32> = HashMap::new();
+    // Indices of the `Location` decoration instructions of struct members, and
+    // the decorated locations.
+    let mut member_location_map: HashMap<u32, Vec<(usize, u32)>> = HashMap::new();
+    for (i, instr) in editor.instrs().iter().enumerate() {
+        let words = instr.operand_words();
+        match instr.opcode() {
+            OP_NAME => {
+                let mut operands = instr.operands();
+                let target_id = operands.read_u32()?;
+                let name = operands.read_str()?;
+                var_map.entry(target_id).or_default().name = Some(name.to_owned());
+            },
+            OP_DECORATE if words.len() >= 3 => {
+                let var = var_map.entry(words[0]).or_default();
+                let value = Some((i, words[2]));
+                match words[1] {
+                    x if x == Decoration::DescriptorSet as u32 => var.desc_set = value,
+                    x if x == Decoration::Binding as u32 => var.bind_point = value,
+                    x if x == Decoration::InputAttachmentIndex as u32 => var.input_attm_idx = value,
+                    x if x == Decoration::Location as u32 => var.location = value,
+                    _ => {},
+                }
+            },
+            OP_MEMBER_DECORATE if words.len() >= 4 && words[2] == Decoration::Location as u32 => {
+                member_location_map.entry(words[0]).or_default().push((i, words[3]));
+            },
+            OP_TYPE_POINTER if words.len() >= 3 => {
+                inner_ty_map.insert(words[0], words[2]);
+            },
+            OP_TYPE_ARRAY | OP_TYPE_RUNTIME_ARRAY if words.len() >= 2 => {
+                inner_ty_map.insert(words[0], words[1]);
+            },
+            OP_VARIABLE => {
+                let mut operands = instr.operands();
+                let ty_id = operands.read_u32()?;
+                let var_id = operands.read_u32()?;
+                let store_cls = operands.read_enum::<StorageClass>()?;
+                let var = var_map.entry(var_id).or_default();
+                var.store_cls = Some(store_cls);
+                var.ty_id = Some(ty_id);
+            },
+            _ => {},
+        }
+    }
+
+    // Decide everything from the original decorations before patching, so
+    // that swapped bindings don't interfere with each other.
+    let mut new_decos = Vec::new();
+    let mut remapped_member_decos = HashSet::new();
+    let mut var_ids = var_map.keys().copied().collect::<Vec<_>>();
+    var_ids.sort_unstable();
+    for var_id in var_ids {
+        let var = &var_map[&var_id];
+        match var.store_cls {
+            Some(StorageClass::UniformConstant) |
+            Some(StorageClass::Uniform) |
+            Some(StorageClass::StorageBuffer) => {
+                let desc_set = var.desc_set.map_or(0, |x| x.1);
+                let bind_point = var.bind_point.map_or(0, |x| x.1);
+                let desc_bind = DescriptorBinding::desc_bind(desc_set, bind_point);
+                let new_desc_bind = var.name.as_ref()
+                    .and_then(|name| remapping.desc_name_map.get(name))
+                    .or_else(|| remapping.desc_bind_map.get(&desc_bind));
+                if let Some(&(new_desc_set, new_bind_point)) = new_desc_bind {
+                    set_deco(editor, &mut new_decos, var_id, Decoration::DescriptorSet, var.desc_set, new_desc_set);
+                    set_deco(editor, &mut new_decos, var_id, Decoration::Binding, var.bind_point, new_bind_point);
+                }
+                if let Some((i, input_attm_idx)) = var.input_attm_idx {
+                    if let Some(&x) = remapping.input_attm_idx_map.get(&input_attm_idx) {
+                        set_deco(editor, &mut new_decos, var_id, Decoration::InputAttachmentIndex, Some((i, input_attm_idx)), x);
+                    }
+                }
+            },
+            Some(StorageClass::Input) => {
+                if let Some((i, location)) = var.location {
+                    if let Some(&x) = remapping.input_location_map.get(&location.into()) {
+                        set_deco(editor, &mut new_decos, var_id, Decoration::Location, Some((i, location)), x.into());
+                    }
+                }
+                // Members of input blocks can be assigned locations
+                // individually. Arrays of blocks are per-vertex inputs of
+                // tessellation and geometry shaders.
+                let mut ty_id = var.ty_id;
+                while let Some(x) = ty_id {
+                    if let Some(member_locations) = member_location_map.get(&x) {
+                        for &(i, location) in member_locations {
+                            if let Some(&x) = remapping.input_location_map.get(&location.into()) {
+                                // A block type shared by multiple variables
+                                // is only patched once.
+                                if remapped_member_decos.insert(i) {
+                                    editor.instrs_mut()[i].operand_words_mut()[3] = x.into();
+                                }
+                            }
+                        }
+                        break;
+                    }
+                    ty_id = inner_ty_map.get(&x).copied();
+                }
+            },
+            _ => {},
+        }
+    }
+
+    // Missing decorations are appended to the annotation section, which
+    // precedes all type declarations.
+    if !new_decos.is_empty() {
+        let first_def = editor.position(|instr| {
+            let opcode = instr.opcode();
+            TYPE_RANGE.contains(&opcode) || CONST_RANGE.contains(&opcode) ||
+                SPEC_CONST_RANGE.contains(&opcode) || opcode == OP_VARIABLE ||
+                opcode == OP_FUNCTION
+        }).unwrap_or(editor.instrs().len());
+        for (i, instr) in new_decos.into_iter().enumerate() {
+            editor.insert(first_def + i, instr);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SpirvBinary, DescriptorBinding, Location};
+    use crate::ty::DescriptorType;
+    use super::Remapping;
+
+    const FRAG: &str = r#"
+               OpCapability Shader
+               OpCapability InputAttachment
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %color %uv %blk
+               OpExecutionMode %main OriginUpperLeft
+               OpName %a "a"
+               OpName %b "b"
+               OpName %c "c"
+               OpName %color "color"
+               OpName %uv "uv"
+               OpDecorate %a DescriptorSet 0
+               OpDecorate %a Binding 0
+               OpDecorate %b DescriptorSet 0
+               OpDecorate %b Binding 1
+               OpDecorate %c Binding 2
+               OpDecorate %c InputAttachmentIndex 2
+               OpDecorate %color Location 0
+               OpDecorate %uv Location 1
+               OpDecorate %blk_ty Block
+               OpMemberDecorate %blk_ty 0 Location 2
+               OpMemberDecorate %blk_ty 1 Location 3
+      %float = OpTypeFloat 32
+       %vec4 = OpTypeVector %float 4
+     %smp_ty = OpTypeSampler
+ %smp_ptr_ty = OpTypePointer UniformConstant %smp_ty
+          %a = OpVariable %smp_ptr_ty UniformConstant
+          %b = OpVariable %smp_ptr_ty UniformConstant
+    %attm_ty = OpTypeImage %float SubpassData 0 0 0 2 Unknown
+%attm_ptr_ty = OpTypePointer UniformConstant %attm_ty
+          %c = OpVariable %attm_ptr_ty UniformConstant
+   %vec4_ptr = OpTypePointer Input %vec4
+      %color = OpVariable %vec4_ptr Input
+         %uv = OpVariable %vec4_ptr Input
+     %blk_ty = OpTypeStruct %vec4 %vec4
+ %blk_ptr_ty = OpTypePointer Input %blk_ty
+        %blk = OpVariable %blk_ptr_ty Input
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %main = OpFunction %void None %void_f
+      %entry = OpLabel
+         %xa = OpLoad %smp_ty %a
+         %xb = OpLoad %smp_ty %b
+         %xc = OpLoad %attm_ty %c
+     %xcolor = OpLoad %vec4 %color
+        %xuv = OpLoad %vec4 %uv
+       %xblk = OpLoad %blk_ty %blk
+               OpReturn
+               OpFunctionEnd
+"#;
+
+    fn remap(remapping: &Remapping) -> SpirvBinary {
+        let mut spv = SpirvBinary::assemble(FRAG).unwrap();
+        spv.remap(remapping).unwrap();
+        spv
+    }
+
+    #[test]
+    fn test_remap_desc_bind() {
+        // Swapped bindings don't interfere with each other.
+        let spv = remap(Remapping::default()
+            .remap_desc_bind(DescriptorBinding::desc_bind(0, 0), 0, 1)
+            .remap_desc_bind(DescriptorBinding::desc_bind(0, 1), 0, 0));
+        let entry_points = spv.reflect().unwrap();
+        assert_eq!(entry_points[0].get_desc_name(DescriptorBinding::desc_bind(0, 1)), Some("a"));
+        assert_eq!(entry_points[0].get_desc_name(DescriptorBinding::desc_bind(0, 0)), Some("b"));
+    }
+
+    #[test]
+    fn test_remap_desc_bind_by_name() {
+        // Name remapping takes precedence, and the missing `DescriptorSet` of
+        // `c` is added.
+        let spv = remap(Remapping::default()
+            .remap_desc_bind(DescriptorBinding::desc_bind(0, 0), 3, 3)
+            .remap_desc_bind_by_name("a", 1, 4)
+            .remap_desc_bind_by_name("c", 2, 5));
+        let entry_points = spv.reflect().unwrap();
+        assert_eq!(entry_points[0].get_desc_name(DescriptorBinding::desc_bind(1, 4)), Some("a"));
+        assert_eq!(entry_points[0].get_desc_name(DescriptorBinding::desc_bind(2, 5)), Some("c"));
+        assert!(entry_points[0].get_desc(DescriptorBinding::desc_bind(3, 3)).is_none());
+    }
+
+    #[test]
+    fn test_remap_input_attm_idx() {
+        let spv = remap(Remapping::default().remap_input_attm_idx(2, 7));
+        let entry_points = spv.reflect().unwrap();
+        let desc_ty = entry_points[0].get_desc(DescriptorBinding::desc_bind(0, 0));
+        assert!(matches!(desc_ty, Some(DescriptorType::Sampler)));
+        let desc_ty = entry_points[0].resolve_desc("c").unwrap().desc_ty;
+        assert!(matches!(desc_ty, DescriptorType::InputAttachment(7)), "{:?}", desc_ty);
+    }
+
+    #[test]
+    fn test_remap_input_location() {
+        let spv = remap(Remapping::default()
+            .remap_input_location(Location::from(0), Location::from(1))
+            .remap_input_location(Location::from(1), Location::from(0))
+            .remap_input_location(Location::from(3), Location::from(5)));
+        let entry_points = spv.reflect().unwrap();
+        let manifest = &entry_points[0].manifest;
+        assert_eq!(manifest.get_input_name(Location::from(1)), Some("color"));
+        assert_eq!(manifest.get_input_name(Location::from(0)), Some("uv"));
+        assert!(manifest.get_input(Location::from(2)).is_some());
+        assert!(manifest.get_input(Location::from(3)).is_none());
+        assert!(manifest.get_input(Location::from(5)).is_some());
+    }
+}
@@ -0,0 +1,473 @@
+//! Specialization constant types, evaluation and freezing.
+use std::collections::{HashMap, HashSet};
+use num_traits::FromPrimitive;
+use spirv_headers::{Decoration, Op};
+use crate::consts::*;
+use crate::edit::{InstrBuf, ModuleEditor};
+use crate::error::{Error, Result};
+use crate::constant::ConstantValue;
+use crate::ty::{ScalarType, VectorType, Type};
+
+/// Value given to a specialization constant. Only scalar values can be given
+/// to specialization constants with a `SpecId`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum SpecValue {
+    Bool(bool),
+    I32(i32),
+    U32(u32),
+    F32(f32),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    /// Value of `OpSpecConstantComposite`, made of the values of its
+    /// constituents.
+    Composite(Vec<SpecValue>),
+}
+impl SpecValue {
+    /// Convert the value to a constant value of the scalar type. Integers are
+    /// accepted as long as the value is representable in the type.
+    pub(crate) fn cast(&self, scalar_ty: &ScalarType) -> Option<ConstantValue> {
+        ConstantValue::from(self.clone()).cast(scalar_ty)
+    }
+}
+impl From<SpecValue> for ConstantValue {
+    fn from(x: SpecValue) -> ConstantValue {
+        match x {
+            SpecValue::Bool(x) => ConstantValue::Bool(x),
+            SpecValue::I32(x) => ConstantValue::I32(x),
+            SpecValue::U32(x) => ConstantValue::U32(x),
+            SpecValue::F32(x) => ConstantValue::F32(x),
+            SpecValue::I64(x) => ConstantValue::I64(x),
+            SpecValue::U64(x) => ConstantValue::U64(x),
+            SpecValue::F64(x) => ConstantValue::F64(x),
+            SpecValue::Composite(xs) => {
+                ConstantValue::Composite(xs.into_iter().map(ConstantValue::from).collect())
+            },
+        }
+    }
+}
+
+// Operands are interpreted by the instructions regardless of the signedness
+// of their types, so integers are reinterpreted at their declared width
+// `nbit` here. Values of unknown widths are taken at their own widths.
+fn uint(x: &ConstantValue, nbit: u32) -> Option<u64> {
+    let (x, nbit) = match *x {
+        ConstantValue::I32(x) => (x as u32 as u64, if nbit == 0 { 32 } else { nbit }),
+        ConstantValue::U32(x) => (x as u64, if nbit == 0 { 32 } else { nbit }),
+        ConstantValue::I64(x) => (x as u64, 64),
+        ConstantValue::U64(x) => (x, 64),
+        _ => return None,
+    };
+    Some(if nbit < 64 { x & ((1 << nbit) - 1) } else { x })
+}
+fn sint(x: &ConstantValue, nbit: u32) -> Option<i64> {
+    let nbit = match *x {
+        ConstantValue::I32(_) | ConstantValue::U32(_) if nbit != 0 => nbit,
+        ConstantValue::I32(_) | ConstantValue::U32(_) => 32,
+        _ => 64,
+    };
+    let nshift = 64 - nbit;
+    Some(((uint(x, nbit)? << nshift) as i64) >> nshift)
+}
+fn boolean(x: &ConstantValue) -> Option<bool> {
+    if let ConstantValue::Bool(x) = *x { Some(x) } else { None }
+}
+fn map1<F>(a: &ConstantValue, f: &F) -> Option<ConstantValue>
+    where F: Fn(&ConstantValue) -> Option<ConstantValue>
+{
+    match a {
+        ConstantValue::Composite(xs) => xs.iter()
+            .map(|x| map1(x, f))
+            .collect::<Option<Vec<_>>>()
+            .map(ConstantValue::Composite),
+        _ => f(a),
+    }
+}
+fn map2<F>(a: &ConstantValue, b: &ConstantValue, f: &F) -> Option<ConstantValue>
+    where F: Fn(&ConstantValue, &ConstantValue) -> Option<ConstantValue>
+{
+    match (a, b) {
+        (ConstantValue::Composite(xs), ConstantValue::Composite(ys)) if xs.len() == ys.len() => {
+            xs.iter().zip(ys.iter())
+                .map(|(x, y)| map2(x, y, f))
+                .collect::<Option<Vec<_>>>()
+                .map(ConstantValue::Composite)
+        },
+        (ConstantValue::Composite(_), _) | (_, ConstantValue::Composite(_)) => None,
+        _ => f(a, b),
+    }
+}
+
+/// Evaluate an `OpSpecConstantOp` expression of result type `ty`. `get`
+/// gives the values and the types of the constant operands. `None` is
+/// returned if the operation is not supported or the result is undefined,
+/// e.g., division by zero.
+pub(crate) fn eval_spec_op<'b, G>(opcode: u32, ty: Option<&Type>, operands: &[u32], get: G) -> Option<ConstantValue>
+    where G: Fn(u32) -> Option<(&'b ConstantValue, Option<&'b Type>)>
+{
+    fn get_scalar_ty(ty: Option<&Type>) -> Option<&ScalarType> {
+        match ty? {
+            Type::Scalar(x) => Some(x),
+            Type::Vector(x) => Some(&x.scalar_ty),
+            _ => None,
+        }
+    }
+    let arg = |i: usize| operands.get(i).and_then(|&x| get(x)).map(|x| x.0);
+    let scalar_ty = get_scalar_ty(ty);
+    let nbit = scalar_ty.map_or(0, |x| x.nbyte() as u64 * 8);
+    // Integer operands are interpreted at the width of the first operand,
+    // which differs from the result type in conversions and comparisons.
+    let src_nbit = operands.first()
+        .and_then(|&x| get_scalar_ty(get(x)?.1))
+        .or(scalar_ty)
+        .map_or(0, |x| x.nbyte() as u32 * 8);
+    let sint = |x: &ConstantValue| sint(x, src_nbit);
+    let uint = |x: &ConstantValue| uint(x, src_nbit);
+    let int = |x: u64| ConstantValue::from_bits(scalar_ty?, x);
+    let int1 = |f: &dyn Fn(&ConstantValue) -> Option<u64>| {
+        map1(arg(0)?, &|x| int(f(x)?))
+    };
+    let int2 = |f: &dyn Fn(&ConstantValue, &ConstantValue) -> Option<u64>| {
+        map2(arg(0)?, arg(1)?, &|x, y| int(f(x, y)?))
+    };
+    let bool2 = |f: &dyn Fn(&ConstantValue, &ConstantValue) -> Option<bool>| {
+        map2(arg(0)?, arg(1)?, &|x, y| Some(ConstantValue::Bool(f(x, y)?)))
+    };
+    // Shift amounts can have widths of their own.
+    let shift = |x: &ConstantValue| self::uint(x, 0).filter(|&x| x < nbit);
+    match Op::from_u32(opcode)? {
+        Op::SConvert => int1(&|x| Some(sint(x)? as u64)),
+        Op::UConvert => int1(&uint),
+        Op::SNegate => int1(&|x| Some(sint(x)?.wrapping_neg() as u64)),
+        Op::Not => int1(&|x| Some(!uint(x)?)),
+        Op::IAdd => int2(&|x, y| Some(uint(x)?.wrapping_add(uint(y)?))),
+        Op::ISub => int2(&|x, y| Some(uint(x)?.wrapping_sub(uint(y)?))),
+        Op::IMul => int2(&|x, y| Some(uint(x)?.wrapping_mul(uint(y)?))),
+        Op::UDiv => int2(&|x, y| uint(x)?.checked_div(uint(y)?)),
+        Op::SDiv => int2(&|x, y| {
+            let (x, y) = (sint(x)?, sint(y)?);
+            if y == 0 { None } else { Some(x.wrapping_div(y) as u64) }
+        }),
+        Op::UMod => int2(&|x, y| uint(x)?.checked_rem(uint(y)?)),
+        Op::SRem => int2(&|x, y| {
+            let (x, y) = (sint(x)?, sint(y)?);
+            if y == 0 { None } else { Some(x.wrapping_rem(y) as u64) }
+        }),
+        Op::SMod => int2(&|x, y| {
+            // The result takes the sign of the divisor.
+            let (x, y) = (sint(x)?, sint(y)?);
+            if y == 0 { return None; }
+            let r = x.wrapping_rem(y);
+            let r = if r != 0 && (r < 0) != (y < 0) { r + y } else { r };
+            Some(r as u64)
+        }),
+        Op::ShiftRightLogical => int2(&|x, y| Some(uint(x)? >> shift(y)?)),
+        Op::ShiftRightArithmetic => int2(&|x, y| Some((sint(x)? >> shift(y)?) as u64)),
+        Op::ShiftLeftLogical => int2(&|x, y| Some(uint(x)? << shift(y)?)),
+        Op::BitwiseOr => int2(&|x, y| Some(uint(x)? | uint(y)?)),
+        Op::BitwiseXor => int2(&|x, y| Some(uint(x)? ^ uint(y)?)),
+        Op::BitwiseAnd => int2(&|x, y| Some(uint(x)? & uint(y)?)),
+        Op::LogicalNot => map1(arg(0)?, &|x| Some(ConstantValue::Bool(!boolean(x)?))),
+        Op::LogicalOr => bool2(&|x, y| Some(boolean(x)? || boolean(y)?)),
+        Op::LogicalAnd => bool2(&|x, y| Some(boolean(x)? && boolean(y)?)),
+        Op::LogicalEqual => bool2(&|x, y| Some(boolean(x)? == boolean(y)?)),
+        Op::LogicalNotEqual => bool2(&|x, y| Some(boolean(x)? != boolean(y)?)),
+        Op::IEqual => bool2(&|x, y| Some(uint(x)? == uint(y)?)),
+        Op::INotEqual => bool2(&|x, y| Some(uint(x)? != uint(y)?)),
+        Op::ULessThan => bool2(&|x, y| Some(uint(x)? < uint(y)?)),
+        Op::SLessThan => bool2(&|x, y| Some(sint(x)? < sint(y)?)),
+        Op::UGreaterThan => bool2(&|x, y| Some(uint(x)? > uint(y)?)),
+        Op::SGreaterThan => bool2(&|x, y| Some(sint(x)? > sint(y)?)),
+        Op::ULessThanEqual => bool2(&|x, y| Some(uint(x)? <= uint(y)?)),
+        Op::SLessThanEqual => bool2(&|x, y| Some(sint(x)? <= sint(y)?)),
+        Op::UGreaterThanEqual => bool2(&|x, y| Some(uint(x)? >= uint(y)?)),
+        Op::SGreaterThanEqual => bool2(&|x, y| Some(sint(x)? >= sint(y)?)),
+        Op::Select => {
+            let (cond, x, y) = (arg(0)?, arg(1)?, arg(2)?);
+            match (cond, x, y) {
+                // Since SPIR-V 1.4 a scalar condition selects whole objects.
+                (ConstantValue::Bool(cond), _, _) => Some(if *cond { x } else { y }.clone()),
+                (ConstantValue::Composite(conds), ConstantValue::Composite(xs), ConstantValue::Composite(ys)) => {
+                    if conds.len() != xs.len() || conds.len() != ys.len() { return None; }
+                    conds.iter().zip(xs.iter().zip(ys.iter()))
+                        .map(|(cond, (x, y))| Some(if boolean(cond)? { x } else { y }.clone()))
+                        .collect::<Option<Vec<_>>>()
+                        .map(ConstantValue::Composite)
+                },
+                _ => None,
+            }
+        },
+        Op::CompositeExtract => {
+            let mut x = arg(0)?;
+            for &i in operands.get(1..)? {
+                x = if let ConstantValue::Composite(xs) = x { xs.get(i as usize)? } else { return None };
+            }
+            Some(x.clone())
+        },
+        Op::CompositeInsert => {
+            let obj = arg(0)?;
+            let mut composite = arg(1)?.clone();
+            let mut x = &mut composite;
+            for &i in operands.get(2..)? {
+                x = if let ConstantValue::Composite(xs) = x { xs.get_mut(i as usize)? } else { return None };
+            }
+            *x = obj.clone();
+            Some(composite)
+        },
+        Op::VectorShuffle => {
+            let mut xs = Vec::new();
+            for v in [arg(0)?, arg(1)?].iter() {
+                if let ConstantValue::Composite(v) = v { xs.extend(v.iter()); } else { return None; }
+            }
+            // Component 0xFFFFFFFF is undefined.
+            operands.get(2..)?.iter()
+                .map(|&i| xs.get(i as usize).map(|&x| x.clone()))
+                .collect::<Option<Vec<_>>>()
+                .map(ConstantValue::Composite)
+        },
+        _ => None,
+    }
+}
+
+/// Specialization constant declared in a module.
+#[derive(Debug, Clone)]
+pub struct SpecConstant {
+    /// Specialization constant id given by the `SpecId` decoration. Composite
+    /// constants don't have one; they are specialized through their
+    /// constituents.
+    pub spec_id: Option<u32>,
+    /// Name of the constant, if debug information is available.
+    pub name: Option<String>,
+    /// Type of the constant, a scalar type for specialization constants with
+    /// a `SpecId`.
+    pub ty: Type,
+    /// Default value used when the constant is not specialized. `None` if the
+    /// value can't be decoded, e.g., of a type or an operation SPIR-Q doesn't
+    /// support.
+    pub default_value: Option<ConstantValue>,
+}
+
+/// Replace the specialization constants whose `SpecId`s are in `spec_values`
+/// with regular constants of the given values, and remove their `SpecId`
+/// decorations. Composite specialization constants and `OpSpecConstantOp`
+/// expressions that no longer depend on any specialization constant are folded
+/// into regular constants too. Expressions of composite results or of
+/// operations SPIR-Q can't evaluate are kept; they remain valid with constant
+/// operands.
+pub(crate) fn freeze_spec_consts(editor: &mut ModuleEditor, spec_values: &HashMap<u32, SpecValue>) -> Result<()> {
+    // Scalar and vector types, enough to evaluate the expressions.
+    let mut ty_map = HashMap::new();
+    // Indices of the `SpecId` decorations and the decorated ids.
+    let mut spec_id_map = HashMap::new();
+    for (i, instr) in editor.instrs().iter().enumerate() {
+        let words = instr.operand_words();
+        match instr.opcode() {
+            OP_TYPE_BOOL if !words.is_empty() => {
+                ty_map.insert(words[0], Type::Scalar(ScalarType::boolean()));
+            },
+            OP_TYPE_INT if words.len() >= 3 => {
+                let scalar_ty = ScalarType::int(words[1] >> 3, words[2] != 0);
+                ty_map.insert(words[0], Type::Scalar(scalar_ty));
+            },
+            OP_TYPE_FLOAT if words.len() >= 2 => {
+                ty_map.insert(words[0], Type::Scalar(ScalarType::float(words[1] >> 3)));
+            },
+            OP_TYPE_VECTOR if words.len() >= 3 => {
+                if let Some(Type::Scalar(scalar_ty)) = ty_map.get(&words[1]) {
+                    let ty = Type::Vector(VectorType::new(scalar_ty.clone(), words[2]));
+                    ty_map.insert(words[0], ty);
+                }
+            },
+            OP_DECORATE if words.len() >= 3 && words[1] == Decoration::SpecId as u32 &&
+                spec_values.contains_key(&words[2]) => {
+                spec_id_map.insert(words[0], (i, words[2]));
+            },
+            _ => {},
+        }
+    }
+
+    // Values and type ids of the constants known so far, and the ids of the
+    // constants that remain specializable.
+    let mut values = HashMap::<u32, (ConstantValue, u32)>::new();
+    let mut spec_ids = HashSet::new();
+    let mut frozen_decos = HashSet::new();
+    for i in 0..editor.instrs().len() {
+        let instr = &editor.instrs()[i];
+        let opcode = instr.opcode();
+        match opcode {
+            OP_CONSTANT_TRUE | OP_CONSTANT_FALSE | OP_CONSTANT | OP_CONSTANT_COMPOSITE |
+            OP_SPEC_CONSTANT_TRUE | OP_SPEC_CONSTANT_FALSE | OP_SPEC_CONSTANT |
+            OP_SPEC_CONSTANT_COMPOSITE | OP_SPEC_CONSTANT_OP => {},
+            _ => continue,
+        }
+        let (ty_id, const_id) = match (instr.result_type_id(), instr.result_id()) {
+            (Some(ty_id), Some(const_id)) => (ty_id, const_id),
+            _ => return Err(Error::INSTR_TOO_SHORT),
+        };
+        let words = &instr.operand_words()[2..];
+        let scalar_ty = match ty_map.get(&ty_id) {
+            Some(Type::Scalar(x)) => Some(x),
+            _ => None,
+        };
+        let compose = |constituents: &[u32]| {
+            constituents.iter()
+                .map(|x| values.get(x).map(|(value, _)| value.clone()))
+                .collect::<Option<Vec<_>>>()
+                .map(ConstantValue::Composite)
+        };
+        let value = match opcode {
+            OP_CONSTANT_TRUE => Some(ConstantValue::Bool(true)),
+            OP_CONSTANT_FALSE => Some(ConstantValue::Bool(false)),
+            OP_CONSTANT => scalar_ty.and_then(|x| ConstantValue::from_words(x, words)),
+            OP_CONSTANT_COMPOSITE => compose(words),
+            _ => None,
+        };
+        let value = match opcode {
+            OP_SPEC_CONSTANT_TRUE | OP_SPEC_CONSTANT_FALSE | OP_SPEC_CONSTANT => {
+                let (deco_idx, spec_id) = if let Some(&x) = spec_id_map.get(&const_id) { x } else {
+                    spec_ids.insert(const_id);
+                    continue;
+                };
+                let value = scalar_ty
+                    .and_then(|scalar_ty| spec_values[&spec_id].cast(scalar_ty))
+                    .ok_or(Error::MismatchedSpecValue(spec_id))?;
+                let new_instr = constant_instr(ty_id, const_id, &value, scalar_ty.unwrap());
+                editor.replace(i, new_instr);
+                frozen_decos.insert(deco_idx);
+                value
+            },
+            OP_SPEC_CONSTANT_COMPOSITE => {
+                if words.iter().any(|x| spec_ids.contains(x)) {
+                    spec_ids.insert(const_id);
+                    continue;
+                }
+                let value = compose(words);
+                let mut new_instr = InstrBuf::new(Op::ConstantComposite);
+                new_instr.push_u32(ty_id).push_u32(const_id).push_list(words);
+                editor.replace(i, new_instr);
+                if let Some(value) = value { value } else { continue }
+            },
+            OP_SPEC_CONSTANT_OP => {
+                let get = |x| values.get(&x).map(|(value, ty_id)| (value, ty_map.get(ty_id)));
+                let value = words.split_first()
+                    .and_then(|(&op, operands)| eval_spec_op(op, ty_map.get(&ty_id), operands, get));
+                let value = if let Some(value) = value { value } else {
+                    spec_ids.insert(const_id);
+                    continue;
+                };
+                // Composite results would need new constants for their
+                // constituents, so the expressions are kept.
+                if let Some(scalar_ty) = scalar_ty {
+                    let new_instr = constant_instr(ty_id, const_id, &value, scalar_ty);
+                    editor.replace(i, new_instr);
+                }
+                value
+            },
+            _ => if let Some(value) = value { value } else { continue },
+        };
+        values.insert(const_id, (value, ty_id));
+    }
+    let mut i = 0;
+    editor.retain(|_| {
+        i += 1;
+        !frozen_decos.contains(&(i - 1))
+    });
+    Ok(())
+}
+fn constant_instr(ty_id: u32, const_id: u32, value: &ConstantValue, scalar_ty: &ScalarType) -> InstrBuf {
+    let mut instr = match value {
+        ConstantValue::Bool(true) => InstrBuf::new(Op::ConstantTrue),
+        ConstantValue::Bool(false) => InstrBuf::new(Op::ConstantFalse),
+        _ => InstrBuf::new(Op::Constant),
+    };
+    instr.push_u32(ty_id).push_u32(const_id);
+    if instr.opcode() == OP_CONSTANT {
+        instr.push_list(&value.to_words(scalar_ty).unwrap());
+    }
+    instr
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use spirv_headers::Op;
+    use crate::SpirvBinary;
+    use crate::constant::ConstantValue;
+    use crate::ty::{ScalarType, Type};
+    use super::{eval_spec_op, SpecValue};
+
+    #[test]
+    fn test_narrow_int_ops() {
+        let u8_ty = Type::Scalar(ScalarType::int(1, false));
+        let i32_ty = Type::Scalar(ScalarType::int(4, true));
+        let bool_ty = Type::Scalar(ScalarType::boolean());
+        let consts = [
+            (1, (ConstantValue::U32(0xFF), Some(&u8_ty))),
+            (2, (ConstantValue::U32(2), Some(&u8_ty))),
+            (3, (ConstantValue::I32(-1), Some(&i32_ty))),
+        ].iter().cloned().collect::<HashMap<_, _>>();
+        let eval = |op: Op, ty: &Type, operands: &[u32]| {
+            eval_spec_op(op as u32, Some(ty), operands, |x| {
+                consts.get(&x).map(|(value, ty)| (value, *ty))
+            })
+        };
+        // 0xFF is -1 when interpreted as a signed 8-bit integer.
+        assert_eq!(eval(Op::SConvert, &i32_ty, &[1]), Some(ConstantValue::I32(-1)));
+        assert_eq!(eval(Op::SLessThan, &bool_ty, &[1, 2]), Some(ConstantValue::Bool(true)));
+        assert_eq!(eval(Op::ULessThan, &bool_ty, &[1, 2]), Some(ConstantValue::Bool(false)));
+        assert_eq!(eval(Op::SDiv, &u8_ty, &[1, 2]), Some(ConstantValue::U32(0)));
+        assert_eq!(eval(Op::ShiftRightArithmetic, &u8_ty, &[1, 2]), Some(ConstantValue::U32(0xFF)));
+        assert_eq!(eval(Op::UConvert, &u8_ty, &[3]), Some(ConstantValue::U32(0xFF)));
+    }
+
+    #[test]
+    fn test_freeze_spec_consts() {
+        let mut spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpCapability Float16
+               OpMemoryModel Logical GLSL450
+               OpName %int "int"
+               OpName %half "half"
+               OpName %v2int "v2int"
+               OpName %a "a"
+               OpName %h "h"
+               OpName %c "c"
+               OpName %sum "sum"
+               OpName %vec "vec"
+               OpName %dep "dep"
+               OpName %vec_dep "vec_dep"
+               OpDecorate %a SpecId 0
+               OpDecorate %h SpecId 1
+               OpDecorate %c SpecId 2
+        %int = OpTypeInt 32 1
+       %half = OpTypeFloat 16
+      %v2int = OpTypeVector %int 2
+          %a = OpSpecConstant %int 1
+          %h = OpSpecConstant %half 0
+          %c = OpSpecConstant %int 3
+        %sum = OpSpecConstantOp %int IAdd %a %a
+        %vec = OpSpecConstantComposite %v2int %a %sum
+        %dep = OpSpecConstantOp %int IAdd %a %c
+    %vec_dep = OpSpecConstantComposite %v2int %a %c
+"#).unwrap();
+        let spec_values = [
+            (0, SpecValue::I32(5)),
+            (1, SpecValue::F32(0.1)),
+        ].iter().cloned().collect::<HashMap<_, _>>();
+        spv.freeze_spec_consts(&spec_values).unwrap();
+        let dis = spv.disassemble().unwrap();
+        let lines = dis.lines().map(str::trim).collect::<Vec<_>>();
+        assert!(lines.contains(&"%a = OpConstant %int 5"));
+        // 0.1 is rounded to the nearest half float.
+        assert!(lines.contains(&"%h = OpConstant %half 0x2e66"));
+        assert!(lines.contains(&"%sum = OpConstant %int 10"));
+        assert!(lines.contains(&"%vec = OpConstantComposite %v2int %a %sum"));
+        // Constants depending on specializable ones are kept.
+        assert!(lines.contains(&"%c = OpSpecConstant %int 3"));
+        assert!(lines.contains(&"%dep = OpSpecConstantOp %int IAdd %a %c"));
+        assert!(lines.contains(&"%vec_dep = OpSpecConstantComposite %v2int %a %c"));
+        assert!(lines.contains(&"OpDecorate %c SpecId 2"));
+        assert!(!lines.contains(&"OpDecorate %a SpecId 0"));
+        assert!(!lines.contains(&"OpDecorate %h SpecId 1"));
+    }
+}
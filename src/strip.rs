@@ -0,0 +1,387 @@
+//! Debug information stripping procedures.
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use num_traits::FromPrimitive;
+use spirv_headers::{ExecutionModel, Op};
+use crate::consts::*;
+use crate::edit::ModuleEditor;
+use crate::error::{Error, Result};
+use crate::grammar::{self, OperandKind};
+use crate::ty::{DescriptorType, Type};
+use crate::{DescriptorBinding, EntryPoint, ResourceLocator};
+
+/// Names of resources and struct members stripped from a module.
+///
+/// The sidecar is collected from the reflection of the original module, and
+/// can be attached to the manifests reflected from the stripped module to
+/// bring back symbolic lookups. It can be stored as text with `to_string` and
+/// loaded back with `parse`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NameSidecar {
+    // Resource names are kept for each entry point, by the execution model and
+    // the name of the entry point, because stages can give the same name to
+    // different resources, like a vertex shader output and a fragment shader
+    // input both called `color`.
+    var_name_map: BTreeMap<(ExecutionModel, String), HashMap<String, ResourceLocator>>,
+    // Member names are keyed by the offsets of members from the outermost
+    // struct to the named member. Arrays are transparent. Offsets are used
+    // rather than indices because each stage can declare a different subset
+    // of push constant members.
+    member_name_map: HashMap<DescriptorBinding, BTreeMap<Vec<usize>, String>>,
+}
+impl NameSidecar {
+    fn collect_member_names(ty: &Type, path: &mut Vec<usize>, out: &mut Vec<(Vec<usize>, String)>) {
+        match ty {
+            Type::Struct(struct_ty) => {
+                for i in 0..struct_ty.nmember() {
+                    let member = struct_ty.get_member(i).unwrap();
+                    path.push(member.offset);
+                    if let Some(name) = member.name.as_ref() {
+                        out.push((path.clone(), name.to_owned()));
+                    }
+                    Self::collect_member_names(&member.ty, path, out);
+                    path.pop();
+                }
+            },
+            Type::Array(arr_ty) => Self::collect_member_names(arr_ty.proto_ty(), path, out),
+            _ => {},
+        }
+    }
+    /// Collect the names from the reflection of all entry points.
+    pub(crate) fn from_entry_points(entry_points: &[EntryPoint]) -> Result<NameSidecar> {
+        let mut sidecar = NameSidecar::default();
+        for entry_point in entry_points {
+            let manifest = &entry_point.manifest;
+            let key = (entry_point.exec_model, entry_point.name.to_owned());
+            if sidecar.var_name_map.insert(key, manifest.var_name_map.clone()).is_some() {
+                return Err(Error::NAME_COLLISION);
+            }
+            for (desc_bind, desc_ty) in manifest.desc_map.iter() {
+                let ty = match desc_ty {
+                    DescriptorType::PushConstant(ty) => ty,
+                    DescriptorType::UniformBuffer(_, ty) => ty,
+                    DescriptorType::StorageBuffer(_, ty) => ty,
+                    _ => continue,
+                };
+                let mut member_names = Vec::new();
+                Self::collect_member_names(ty, &mut Vec::new(), &mut member_names);
+                if !member_names.is_empty() {
+                    // Push constant blocks can be partial in each stage so
+                    // member names are accumulated.
+                    sidecar.member_name_map.entry(*desc_bind)
+                        .or_default()
+                        .extend(member_names);
+                }
+            }
+        }
+        Ok(sidecar)
+    }
+    fn attach_member_name(ty: &mut Type, path: &[usize], name: &str) -> Result<()> {
+        let mut ty = ty;
+        for (depth, &offset) in path.iter().enumerate() {
+            while let Type::Array(arr_ty) = ty { ty = &mut *arr_ty.proto_ty; }
+            let struct_ty = if let Type::Struct(x) = ty { x } else { return Ok(()) };
+            // Members from another stage might be absent in this manifest.
+            let i = (0..struct_ty.nmember())
+                .find(|&i| struct_ty.get_member(i).unwrap().offset == offset);
+            let i = if let Some(i) = i { i } else { return Ok(()) };
+            if depth + 1 == path.len() {
+                return struct_ty.set_member_name(i, name);
+            }
+            ty = &mut struct_ty.get_member_mut(i).unwrap().ty;
+        }
+        Ok(())
+    }
+    /// Attach the names to an entry point reflected from the stripped module.
+    /// Names of resources absent in the manifest are ignored.
+    pub(crate) fn attach(&self, entry_point: &mut EntryPoint) -> Result<()> {
+        let key = (entry_point.exec_model, entry_point.name.to_owned());
+        let manifest = &mut entry_point.manifest;
+        let var_names = self.var_name_map.get(&key).into_iter().flatten();
+        for (name, locator) in var_names {
+            let exists = match locator {
+                ResourceLocator::Input(location, component) =>
+                    manifest.input_map.contains_key(&(*location, *component)),
+                ResourceLocator::Output(location, component, index) =>
+                    manifest.output_map.contains_key(&(*location, *component, *index)),
+                ResourceLocator::Descriptor(desc_bind) =>
+                    manifest.desc_map.contains_key(desc_bind),
+            };
+            if !exists { continue; }
+            let old_locator = manifest.var_name_map.entry(name.to_owned())
+                .or_insert(*locator);
+            if old_locator != locator { return Err(Error::NAME_COLLISION); }
+        }
+        for (desc_bind, member_names) in self.member_name_map.iter() {
+            let ty = match manifest.desc_map.get_mut(desc_bind) {
+                Some(DescriptorType::PushConstant(ty)) => ty,
+                Some(DescriptorType::UniformBuffer(_, ty)) => ty,
+                Some(DescriptorType::StorageBuffer(_, ty)) => ty,
+                _ => continue,
+            };
+            for (path, name) in member_names.iter() {
+                Self::attach_member_name(ty, path, name)?;
+            }
+        }
+        Ok(())
+    }
+    /// Parse a sidecar stored as text.
+    pub fn parse(text: &str) -> Result<NameSidecar> { text.parse() }
+}
+
+fn fmt_desc_bind(desc_bind: &DescriptorBinding) -> String {
+    if let Some((desc_set, bind_point)) = desc_bind.into_inner() {
+        format!("{} {}", desc_set, bind_point)
+    } else {
+        "push".to_owned()
+    }
+}
+fn fmt_exec_model(exec_model: ExecutionModel) -> String {
+    grammar::enumerants(OperandKind::ExecutionModel)
+        .and_then(|x| x.iter().find(|e| e.value == exec_model as u32))
+        .map_or_else(|| (exec_model as u32).to_string(), |e| e.name.to_owned())
+}
+impl fmt::Display for NameSidecar {
+    /// One name per line, as one of:
+    ///
+    /// ```text
+    /// e <execution model> <entry point name>
+    /// i <location> <component> <name>
+    /// o <location> <component> <index> <name>
+    /// d <set> <binding> <name>
+    /// d push <name>
+    /// m <set> <binding> <member offset path> <name>
+    /// m push <member offset path> <name>
+    /// ```
+    ///
+    /// where `i`, `o` and `d` records belong to the entry point of the last
+    /// `e` record, execution models are named like `Vertex`, and member offset
+    /// paths are the dot-separated offsets of the members in each level of
+    /// structs, like `16.4`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut lines = Vec::new();
+        for (desc_bind, member_names) in self.member_name_map.iter() {
+            for (path, name) in member_names.iter() {
+                let path = path.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(".");
+                lines.push(format!("m {} {} {}", fmt_desc_bind(desc_bind), path, name));
+            }
+        }
+        // Keep the output stable for version control.
+        lines.sort();
+        for ((exec_model, entry_point_name), var_names) in self.var_name_map.iter() {
+            let mut var_lines = Vec::new();
+            for (name, locator) in var_names.iter() {
+                let line = match locator {
+                    ResourceLocator::Input(location, component) =>
+                        format!("i {} {} {}", location, component, name),
+                    ResourceLocator::Output(location, component, index) =>
+                        format!("o {} {} {} {}", location, component, index, name),
+                    ResourceLocator::Descriptor(desc_bind) =>
+                        format!("d {} {}", fmt_desc_bind(desc_bind), name),
+                };
+                var_lines.push(line);
+            }
+            var_lines.sort();
+            lines.push(format!("e {} {}", fmt_exec_model(*exec_model), entry_point_name));
+            lines.extend(var_lines);
+        }
+        for line in lines {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for NameSidecar {
+    type Err = Error;
+    fn from_str(text: &str) -> Result<NameSidecar> {
+        type EntryPointKey = (ExecutionModel, String);
+        fn parse_line(line: &str, sidecar: &mut NameSidecar, entry_point: &mut Option<EntryPointKey>) -> std::result::Result<(), &'static str> {
+            fn num(x: Option<&str>) -> std::result::Result<u32, &'static str> {
+                x.and_then(|x| u32::from_str(x).ok()).ok_or("expected a number")
+            }
+            fn desc_bind<'a, I: Iterator<Item=&'a str>>(segs: &mut I) -> std::result::Result<DescriptorBinding, &'static str> {
+                match segs.next() {
+                    Some("push") => Ok(DescriptorBinding::push_const()),
+                    x => Ok(DescriptorBinding::desc_bind(num(x)?, num(segs.next())?)),
+                }
+            }
+            fn name<'a, I: Iterator<Item=&'a str>>(segs: I) -> String {
+                // Names take the rest of the line.
+                segs.collect::<Vec<_>>().join(" ")
+            }
+            fn exec_model(x: Option<&str>) -> std::result::Result<ExecutionModel, &'static str> {
+                let x = x.ok_or("missing execution model")?;
+                let value = grammar::enumerants(OperandKind::ExecutionModel)
+                    .and_then(|enumerants| enumerants.iter().find(|e| e.name == x))
+                    .map(|e| e.value)
+                    .or_else(|| u32::from_str(x).ok())
+                    .ok_or("unknown execution model")?;
+                ExecutionModel::from_u32(value).ok_or("unknown execution model")
+            }
+            let mut segs = line.split(' ');
+            let kind = segs.next();
+            if kind == Some("e") {
+                let key = (exec_model(segs.next())?, name(segs));
+                sidecar.var_name_map.entry(key.clone()).or_default();
+                *entry_point = Some(key);
+                return Ok(());
+            }
+            let locator = match kind {
+                Some("i") => {
+                    let location = num(segs.next())?.into();
+                    let component = num(segs.next())?.into();
+                    ResourceLocator::Input(location, component)
+                },
+                Some("o") => {
+                    let location = num(segs.next())?.into();
+                    let component = num(segs.next())?.into();
+                    let index = num(segs.next())?.into();
+                    ResourceLocator::Output(location, component, index)
+                },
+                Some("d") => ResourceLocator::Descriptor(desc_bind(&mut segs)?),
+                Some("m") => {
+                    let desc_bind = desc_bind(&mut segs)?;
+                    let path = segs.next().ok_or("missing member offset path")?
+                        .split('.')
+                        .map(|x| usize::from_str(x).map_err(|_| "invalid member offset"))
+                        .collect::<std::result::Result<Vec<_>, _>>()?;
+                    sidecar.member_name_map.entry(desc_bind)
+                        .or_default()
+                        .insert(path, name(segs));
+                    return Ok(());
+                },
+                _ => return Err("unknown record kind"),
+            };
+            // Resource names belong to the last entry point.
+            let key = entry_point.as_ref().ok_or("missing entry point record")?;
+            sidecar.var_name_map.entry(key.clone())
+                .or_default()
+                .insert(name(segs), locator);
+            Ok(())
+        }
+        let mut sidecar = NameSidecar::default();
+        let mut entry_point = None;
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() { continue; }
+            parse_line(line, &mut sidecar, &mut entry_point)
+                .map_err(|msg| Error::InvalidSidecar(i + 1, msg.to_owned()))?;
+        }
+        Ok(sidecar)
+    }
+}
+
+/// Remove debug instructions, including `OpName`, `OpMemberName`,
+/// `OpSource*`, `OpString`, `OpLine`, `OpNoLine`, `OpModuleProcessed` and all
+/// the non-semantic extended instructions.
+pub(crate) fn strip_debug(editor: &mut ModuleEditor) -> Result<()> {
+    const NON_SEMANTIC_EXT: &str = "SPV_KHR_non_semantic_info";
+    let mut non_semantic_sets = HashSet::new();
+    for instr in editor.instrs() {
+        if instr.opcode() == OP_EXT_INST_IMPORT {
+            let mut operands = instr.operands();
+            let set_id = operands.read_u32()?;
+            if operands.read_str()?.starts_with("NonSemantic.") {
+                non_semantic_sets.insert(set_id);
+            }
+        }
+    }
+    let mut res = Ok(());
+    editor.retain(|instr| {
+        let opcode = instr.opcode();
+        if DEBUG_OPS.contains(&opcode) ||
+            opcode == Op::Line as u32 ||
+            opcode == Op::NoLine as u32 {
+            return false;
+        }
+        match opcode {
+            OP_EXT_INST_IMPORT => {
+                let set_id = instr.operand_words().first();
+                !set_id.is_some_and(|x| non_semantic_sets.contains(x))
+            },
+            OP_EXT_INST => {
+                let set_id = instr.operand_words().get(2);
+                !set_id.is_some_and(|x| non_semantic_sets.contains(x))
+            },
+            OP_EXTENSION if !non_semantic_sets.is_empty() => {
+                match instr.operands().read_str() {
+                    Ok(name) => name != NON_SEMANTIC_EXT,
+                    Err(e) => { res = Err(e); true },
+                }
+            },
+            _ => true,
+        }
+    });
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SpirvBinary, Error, NameSidecar};
+
+    #[test]
+    fn test_strip_unreflectable() {
+        // Reflection fails on the conflicting decorations.
+        let mut spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpName %int "int"
+               OpDecorate %int Location 0
+               OpDecorate %int Location 1
+        %int = OpTypeInt 32 1
+"#).unwrap();
+        assert!(spv.reflect().is_err());
+        assert!(spv.strip_debug().is_err());
+        assert_eq!(spv.instrs().count(), 6);
+        spv.strip_debug_unchecked().unwrap();
+        assert_eq!(spv.instrs().count(), 5);
+    }
+
+    #[test]
+    fn test_strip_names_per_entry_point() {
+        let mut spv = SpirvBinary::assemble(r#"
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %vert "main" %x
+               OpEntryPoint Fragment %frag "main" %y
+               OpExecutionMode %frag OriginUpperLeft
+               OpName %x "color"
+               OpName %y "color"
+               OpDecorate %x Location 0
+               OpDecorate %y Location 1
+      %float = OpTypeFloat 32
+  %float_ptr = OpTypePointer Input %float
+          %x = OpVariable %float_ptr Input
+          %y = OpVariable %float_ptr Input
+       %void = OpTypeVoid
+     %void_f = OpTypeFunction %void
+       %vert = OpFunction %void None %void_f
+     %entry0 = OpLabel
+         %xx = OpLoad %float %x
+               OpReturn
+               OpFunctionEnd
+       %frag = OpFunction %void None %void_f
+     %entry1 = OpLabel
+         %xy = OpLoad %float %y
+               OpReturn
+               OpFunctionEnd
+"#).unwrap();
+        let sidecar = spv.strip_debug().unwrap();
+        let text = sidecar.to_string();
+        assert_eq!(text, "e Vertex main\ni 0 0 color\ne Fragment main\ni 1 0 color\n");
+        assert_eq!(text.parse::<NameSidecar>().unwrap(), sidecar);
+        let mut entry_points = spv.reflect().unwrap();
+        for entry_point in entry_points.iter_mut() {
+            assert!(entry_point.resolve_input("color").is_none());
+            entry_point.attach_names(&sidecar).unwrap();
+        }
+        let location = |i: usize| entry_points[i].resolve_input("color").map(|x| u32::from(x.location));
+        assert_eq!(location(0), Some(0));
+        assert_eq!(location(1), Some(1));
+        let err = "i 0 0 color".parse::<NameSidecar>().unwrap_err();
+        assert!(matches!(err, Error::InvalidSidecar(1, _)), "{:?}", err);
+    }
+}
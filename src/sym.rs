@@ -43,8 +43,8 @@ impl Symbol {
     }
     pub fn pop(&mut self) -> Option<Symbol> {
         fn split_sym(literal: &str) -> Option<(Symbol, Symbol)> {
-            if literal.len() == 0 { return None; }
-            let end = literal.bytes().rposition(|c| c == '.' as u8).unwrap_or(0);
+            if literal.is_empty() { return None; }
+            let end = literal.bytes().rposition(|c| c == b'.').unwrap_or(0);
             let rv = literal[end + 1..].to_owned().into();
             let new_inner = literal[..end].to_owned().into();
             Some((rv, new_inner))
@@ -53,6 +53,23 @@ impl Symbol {
         self.0 = new_inner.0;
         Some(rv)
     }
+    /// Whether the symbol refers to `other` or a member of it. An empty symbol
+    /// covers everything.
+    pub(crate) fn covers(&self, other: &Symbol) -> bool {
+        let (x, y): (&str, &str) = ((**self).as_ref(), (**other).as_ref());
+        x.is_empty() || x == y ||
+            (y.starts_with(x) && y.as_bytes()[x.len()] == b'.')
+    }
+    /// Sort the symbols of used buffer members, and remove those covered by
+    /// other symbols.
+    pub(crate) fn prune(mut syms: Vec<Symbol>) -> Vec<Symbol> {
+        syms.sort_by_key(ToString::to_string);
+        let mut pruned: Vec<Symbol> = Vec::with_capacity(syms.len());
+        for sym in syms {
+            if !pruned.iter().any(|x| x.covers(&sym)) { pruned.push(sym); }
+        }
+        pruned
+    }
 }
 impl Default for Symbol {
     fn default() -> Symbol { Symbol::new("") }
@@ -107,13 +124,13 @@ impl<'a> Seg<'a> {
     pub fn empty() -> Self { Seg::Empty }
 
     pub fn is_index(&self) -> bool {
-        if let Seg::Index(_) = self { true } else { false }
+        matches!(self, Seg::Index(_))
     }
     pub fn is_name(&self) -> bool {
-        if let Seg::Name(_) = self { true } else { false }
+        matches!(self, Seg::Name(_))
     }
     pub fn is_empty(&self) -> bool {
-        if let Seg::Empty = self { true } else { false }
+        matches!(self, Seg::Empty)
     }
 }
 impl<'a> fmt::Display for Seg<'a> {
@@ -137,13 +154,13 @@ impl<'a> Iterator for Segs<'a> {
         if self.0.is_empty() {
             return if self.1 { None } else { Some(Seg::Empty) };
         }
-        let txt = if let Some(pos) = self.0.bytes().position(|c| c == '.' as u8) {
+        let txt = if let Some(pos) = self.0.bytes().position(|c| c == b'.') {
             let txt = &self.0[..pos];
             self.0 = &self.0[(pos + 1)..];
             txt
         } else {
-            let txt = &self.0[..];
-            self.0 = &"";
+            let txt = self.0;
+            self.0 = "";
             self.1 = true;
             txt
         };
@@ -153,6 +170,6 @@ impl<'a> Iterator for Segs<'a> {
         } else {
             Seg::Name(txt)
         };
-        return Some(seg);
+        Some(seg)
     }
 }
@@ -47,16 +47,16 @@ impl ScalarType {
     }
 
     pub fn is_boolean(&self) -> bool {
-        if let Self::Boolean = self { true } else { false }
+        matches!(self, Self::Boolean)
     }
     pub fn is_sint(&self) -> bool {
-        if let Self::Signed(_) = self { true } else { false }
+        matches!(self, Self::Signed(_))
     }
     pub fn is_uint(&self) -> bool {
-        if let Self::Unsigned(_) = self { true } else { false }
+        matches!(self, Self::Unsigned(_))
     }
     pub fn is_float(&self) -> bool {
-        if let Self::Float(_) = self { true } else { false }
+        matches!(self, Self::Float(_))
     }
 }
 impl fmt::Debug for ScalarType {
@@ -78,7 +78,7 @@ pub struct VectorType {
 }
 impl VectorType {
     pub fn new(scalar_ty: ScalarType, nscalar: u32) -> VectorType {
-        VectorType { scalar_ty: scalar_ty, nscalar: nscalar }
+        VectorType { scalar_ty, nscalar }
     }
     pub fn nbyte(&self) -> usize { self.nscalar as usize * self.scalar_ty.nbyte() }
 }
@@ -89,14 +89,12 @@ impl fmt::Debug for VectorType {
 }
 
 
-#[derive(Hash, Clone, Copy)]
+#[derive(Hash, Clone, Copy, Default)]
 pub enum MatrixAxisOrder {
+    #[default]
     ColumnMajor,
     RowMajor,
 }
-impl Default for MatrixAxisOrder {
-    fn default() -> MatrixAxisOrder { MatrixAxisOrder::ColumnMajor }
-}
 
 
 #[derive(Hash, Clone)]
@@ -110,8 +108,8 @@ impl MatrixType {
     pub fn new(vec_ty: VectorType, nvec: u32) -> MatrixType {
         MatrixType {
             stride: vec_ty.nbyte(),
-            vec_ty: vec_ty,
-            nvec: nvec,
+            vec_ty,
+            nvec,
             major: MatrixAxisOrder::default(),
         }
     }
@@ -228,7 +226,7 @@ impl fmt::Debug for ImageType {
             (Image1DArray, Depth) => f.write_str("sampler1DArrayShadow"),
             (Image2DArray, Depth) => f.write_str("sampler2DArrayShadow"),
             (CubeMapArray, Depth) => f.write_str("samplerCubeShadowArray"),
-            _ => Err(fmt::Error::default()),
+            _ => Err(fmt::Error),
         }
     }
 }
@@ -279,7 +277,7 @@ impl ArrayType {
         self.stride.unwrap()
     }
     pub fn nrepeat(&self) -> Option<u32> {
-        self.nrepeat.clone()
+        self.nrepeat
     }
 }
 impl fmt::Debug for ArrayType {
@@ -321,8 +319,9 @@ impl StructType {
         self.name_map.iter()
             .find_map(|(name, &j)| if i == j { Some(name.as_ref()) } else { None })
     }
-    /// Merge another structure type's member into this structure type.
-    pub(crate) fn merge(&mut self, src_struct_ty: &StructType) -> Result<()> {
+    /// Merge another structure type's member into this structure type. The
+    /// name of the first conflicting member is returned on failure.
+    pub(crate) fn merge(&mut self, src_struct_ty: &StructType) -> std::result::Result<(), String> {
         use crate::hash;
         let dst_struct_ty = self;
         let member_offset = dst_struct_ty.members.len();
@@ -333,7 +332,7 @@ impl StructType {
                 let old_hash = hash(&dst_struct_ty.members[old_member_idx]);
                 let new_hash = hash(&src_struct_ty.members[member_idx]);
                 if old_hash != new_hash {
-                    return Err(Error::MismatchedManifest);
+                    return Err(name.to_owned());
                 }
             } else {
                 dst_struct_ty.name_map
@@ -354,6 +353,24 @@ impl StructType {
         self.members.push(member);
         Ok(())
     }
+    pub(crate) fn get_member_mut(&mut self, i: usize) -> Option<&'_ mut StructMember> {
+        self.members.get_mut(i)
+    }
+    /// Name the `i`-th member, which must exist. Note that the method can fail
+    /// when the name has already been registered for another member.
+    pub(crate) fn set_member_name(&mut self, i: usize, name: &str) -> Result<()> {
+        let member = &mut self.members[i];
+        match self.name_map.get(name) {
+            Some(&j) if j == i => return Ok(()),
+            Some(_) => return Err(Error::NAME_COLLISION),
+            None => {},
+        }
+        if let Some(old_name) = member.name.replace(name.to_owned()) {
+            self.name_map.remove(&old_name);
+        }
+        self.name_map.insert(name.to_owned(), i);
+        Ok(())
+    }
 }
 impl Hash for StructType {
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -407,6 +424,68 @@ impl Type {
             Struct(struct_ty) => Some(struct_ty.nbyte()),
         }
     }
+    /// Get the number of locations an input/output variable of the type
+    /// consumes. 64-bit vectors of more than 2 components take 2 locations;
+    /// matrices and arrays take one slot per column or element, and structs
+    /// take the locations of all their members. `None` is returned for types
+    /// that cannot be passed between stages.
+    pub fn nlocation(&self) -> Option<u32> {
+        self.component_masks(0).map(|x| x.len() as u32)
+    }
+    /// Get the masks of components taken by an input/output variable of the
+    /// type in each location it takes, if it starts at `component` of the
+    /// first location. Bit `i` of a mask stands for component `i`; 64-bit
+    /// components take two 32-bit components each. `None` is returned for
+    /// types that cannot be passed between stages.
+    pub fn component_masks(&self, component: u32) -> Option<Vec<u8>> {
+        fn vec_masks(nscalar: u32, scalar_nbyte: usize, component: u32) -> Vec<u8> {
+            let mut ncomp = if scalar_nbyte > 4 { nscalar * 2 } else { nscalar };
+            let mut first = component;
+            let mut masks = Vec::new();
+            while ncomp > 0 && first < 4 {
+                let n = ncomp.min(4 - first);
+                masks.push((((1u32 << n) - 1) << first) as u8);
+                ncomp -= n;
+                first = 0;
+            }
+            masks
+        }
+        use Type::*;
+        let masks = match self {
+            Scalar(scalar_ty) => vec_masks(1, scalar_ty.nbyte(), component),
+            Vector(vec_ty) => vec_masks(vec_ty.nscalar, vec_ty.scalar_ty.nbyte(), component),
+            Matrix(mat_ty) => {
+                let vec_ty = &mat_ty.vec_ty;
+                vec_masks(vec_ty.nscalar, vec_ty.scalar_ty.nbyte(), component)
+                    .repeat(mat_ty.nvec as usize)
+            },
+            Array(arr_ty) => {
+                arr_ty.proto_ty().component_masks(component)?
+                    .repeat(arr_ty.nrepeat()? as usize)
+            },
+            // Each member starts at a new location.
+            Struct(struct_ty) => {
+                let mut masks = Vec::new();
+                for member in struct_ty.members.iter() {
+                    masks.extend(member.ty.component_masks(0)?);
+                }
+                masks
+            },
+            _ => return None,
+        };
+        Some(masks)
+    }
+    /// Whether the size of the type is only known at runtime, i.e., it's a
+    /// runtime array or a struct ending with one.
+    pub fn is_unsized(&self) -> bool {
+        match self {
+            Type::Array(arr_ty) => arr_ty.nrepeat().is_none(),
+            Type::Struct(struct_ty) => {
+                struct_ty.members.last().is_some_and(|x| x.ty.is_unsized())
+            },
+            _ => false,
+        }
+    }
     pub fn resolve<S: AsRef<Sym>>(&self, sym: S) -> Option<MemberVariableResolution<'_>> {
         let mut ty = self;
         let mut offset = 0;
@@ -431,7 +510,7 @@ impl Type {
                             }
                         }
                         offset += arr_ty.stride() * idx;
-                        ty = &*arr_ty.proto_ty();
+                        ty = arr_ty.proto_ty();
                     } else { return None; }
                 },
                 _ => return None,
@@ -442,13 +521,13 @@ impl Type {
     }
     // Iterate over all entries in the type tree.
     pub fn walk<'a>(&'a self) -> Walk<'a> { Walk::new(self) }
-    pub fn is_scalar(&self) -> bool { match self { Type::Scalar(_) => true, _ => false } }
-    pub fn is_vec(&self) -> bool { match self { Type::Vector(_) => true, _ => false } }
-    pub fn is_mat(&self) -> bool { match self { Type::Matrix(_) => true, _ => false } }
-    pub fn is_img(&self) -> bool { match self { Type::Image(_) => true, _ => false } }
-    pub fn is_subpass_data(&self) -> bool { match self { Type::SubpassData => true, _ => false } }
-    pub fn is_arr(&self) -> bool { match self { Type::Array(_) => true, _ => false } }
-    pub fn is_struct(&self) -> bool { match self { Type::Struct(_) => true, _ => false } }
+    pub fn is_scalar(&self) -> bool { matches!(self, Type::Scalar(_)) }
+    pub fn is_vec(&self) -> bool { matches!(self, Type::Vector(_)) }
+    pub fn is_mat(&self) -> bool { matches!(self, Type::Matrix(_)) }
+    pub fn is_img(&self) -> bool { matches!(self, Type::Image(_)) }
+    pub fn is_subpass_data(&self) -> bool { matches!(self, Type::SubpassData) }
+    pub fn is_arr(&self) -> bool { matches!(self, Type::Array(_)) }
+    pub fn is_struct(&self) -> bool { matches!(self, Type::Struct(_)) }
 }
 impl fmt::Debug for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -514,11 +593,11 @@ impl DescriptorType {
         };
         Walk::new(ty)
     }
-    pub fn is_push_const(&self) -> bool { match self { DescriptorType::PushConstant(_) => true, _ => false } }
-    pub fn is_uniform_buf(&self) -> bool { match self { DescriptorType::UniformBuffer(_,_) => true, _ => false } }
-    pub fn is_storage_buf(&self) -> bool { match self { DescriptorType::StorageBuffer(_,_) => true, _ => false } }
-    pub fn is_img(&self) -> bool { match self { DescriptorType::Image(_) => true, _ => false } }
-    pub fn is_input_attm(&self) -> bool { match self { DescriptorType::InputAttachment(_) => true, _ => false } }
+    pub fn is_push_const(&self) -> bool { matches!(self, DescriptorType::PushConstant(_)) }
+    pub fn is_uniform_buf(&self) -> bool { matches!(self, DescriptorType::UniformBuffer(_,_)) }
+    pub fn is_storage_buf(&self) -> bool { matches!(self, DescriptorType::StorageBuffer(_,_)) }
+    pub fn is_img(&self) -> bool { matches!(self, DescriptorType::Image(_)) }
+    pub fn is_input_attm(&self) -> bool { matches!(self, DescriptorType::InputAttachment(_)) }
 }
 impl fmt::Debug for DescriptorType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -554,7 +633,7 @@ impl<'a> Walk<'a> {
         let frame = WalkFrame {
             sym_stem: None,
             base_offset: 0,
-            ty: ty,
+            ty,
             i: 0,
         };
         Walk { inner: vec![frame] }
@@ -0,0 +1,101 @@
+//! Section-by-section traversal of SPIR-V modules.
+//!
+//! SPIR-V modules are laid out in sections in a fixed order (see _2.4 Logical
+//! Layout of a Module_ of the SPIR-V specification). A [`Visitor`] receives
+//! every instruction in the module through the callback of the section the
+//! instruction belongs to.
+//!
+//! [`Visitor`]: trait.Visitor.html
+use crate::parse::{Instrs, Instr};
+use crate::consts::*;
+use crate::error::{Error, Result};
+
+/// Callbacks invoked for each instruction in a module. All callbacks do
+/// nothing by default, so implementors only need to override the sections
+/// they are interested in. An error returned by any callback stops the
+/// traversal.
+#[allow(unused_variables)]
+pub trait Visitor<'a> {
+    /// `OpCapability`.
+    fn visit_capability(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// `OpExtension`.
+    fn visit_extension(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// `OpExtInstImport`.
+    fn visit_ext_inst_import(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// `OpMemoryModel`.
+    fn visit_memory_model(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// `OpEntryPoint`.
+    fn visit_entry_point(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// `OpExecutionMode` and `OpExecutionModeId`.
+    fn visit_execution_mode(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// Debug instructions, including `OpString`, `OpSource*`, `OpName`,
+    /// `OpMemberName` and `OpModuleProcessed`.
+    fn visit_debug(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// Annotations, i.e., all kinds of decoration instructions.
+    fn visit_annotation(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// Type declarations, constants, global variables and other instructions
+    /// allowed among them, like `OpUndef` and `OpLine`.
+    fn visit_definition(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// Instructions of functions without a body, from `OpFunction` to
+    /// `OpFunctionEnd`.
+    fn visit_function_declaration(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+    /// Instructions of functions with a body, from `OpFunction` to
+    /// `OpFunctionEnd`.
+    fn visit_function_definition(&mut self, instr: &Instr<'a>) -> Result<()> { Ok(()) }
+}
+
+fn peek<'a>(instrs: &Instrs<'a>) -> Option<Instr<'a>> { instrs.clone().next() }
+
+fn visit_function<'a, V: Visitor<'a>>(instrs: &mut Instrs<'a>, visitor: &mut V) -> Result<()> {
+    // Whether the function has a body is only known after all the parameters,
+    // so the function header is buffered.
+    let mut header = Vec::new();
+    while let Some(instr) = peek(instrs) {
+        let opcode = instr.opcode();
+        if !header.is_empty() && opcode != OP_FUNCTION_PARAMETER { break; }
+        header.push(instr);
+        instrs.next();
+    }
+    let is_def = peek(instrs).map(|x| x.opcode() != OP_FUNCTION_END).unwrap_or(false);
+    let mut callback = |instr: &Instr<'a>| if is_def {
+        visitor.visit_function_definition(instr)
+    } else {
+        visitor.visit_function_declaration(instr)
+    };
+    for instr in header.iter() {
+        callback(instr)?;
+    }
+    for instr in instrs.by_ref() {
+        callback(&instr)?;
+        if instr.opcode() == OP_FUNCTION_END { return Ok(()); }
+    }
+    Err(Error::INSTR_TOO_SHORT)
+}
+
+pub(crate) fn visit<'a, V: Visitor<'a>>(mut instrs: Instrs<'a>, visitor: &mut V) -> Result<()> {
+    while let Some(instr) = peek(&instrs) {
+        let instr = &instr;
+        match instr.opcode() {
+            OP_CAPABILITY => visitor.visit_capability(instr)?,
+            OP_EXTENSION => visitor.visit_extension(instr)?,
+            OP_EXT_INST_IMPORT => visitor.visit_ext_inst_import(instr)?,
+            OP_MEMORY_MODEL => visitor.visit_memory_model(instr)?,
+            OP_ENTRY_POINT => visitor.visit_entry_point(instr)?,
+            OP_EXECUTION_MODE | OP_EXECUTION_MODE_ID => visitor.visit_execution_mode(instr)?,
+            x if DEBUG_OPS.contains(&x) => visitor.visit_debug(instr)?,
+            x if ANNOTATION_OPS.contains(&x) => visitor.visit_annotation(instr)?,
+            OP_FUNCTION => {
+                visit_function(&mut instrs, visitor)?;
+                continue;
+            },
+            _ => visitor.visit_definition(instr)?,
+        }
+        instrs.next();
+    }
+    // Instructions with invalid word counts stop the iteration early.
+    if instrs.remaining().is_empty() {
+        Ok(())
+    } else {
+        Err(Error::INSTR_TOO_SHORT)
+    }
+}
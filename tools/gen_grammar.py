@@ -0,0 +1,188 @@
+#!/usr/bin/env python3
+"""Generate `src/grammar.rs` from the SPIR-V machine-readable core grammar.
+
+Usage:
+
+    python3 tools/gen_grammar.py <spirv.core.grammar.json> <autogen_spirv.rs> > src/grammar.rs
+
+`spirv.core.grammar.json` is the core grammar shipped with SPIRV-Headers
+(`include/spirv/unified1`). `autogen_spirv.rs` is the source of the
+`spirv_headers` crate pinned in `Cargo.toml`, e.g.
+`~/.cargo/registry/src/*/spirv_headers-1.5.0/autogen_spirv.rs`.
+
+Only the instructions and enumerants that the pinned `spirv_headers` defines
+are emitted, so the tables always agree with `spirv_headers::Op` and the
+other enumerations even if the grammar is newer. Instruction names are not
+emitted at all; they are derived from `spirv_headers::Op`.
+"""
+import json
+import re
+import sys
+
+
+def parse_spirv_headers(path):
+    """Collect the values of all enumerations and bit flags in spirv_headers,
+    and the SPIR-V version it is generated for."""
+    with open(path) as f:
+        src = f.read()
+    enums = {}
+    for m in re.finditer(r'pub enum (\w+) \{(.*?)\n\}', src, re.S):
+        enums[m.group(1)] = {int(x) for x in re.findall(r'= (\d+)u32', m.group(2))}
+    for m in re.finditer(r'pub struct (\w+) : u32 \{(.*?)\}', src, re.S):
+        enums[m.group(1)] = {int(x) for x in re.findall(r'= (\d+)u32', m.group(2))}
+    version = tuple(int(re.search(r'pub const {}: u8 = (\d+)u8;'.format(x), src).group(1))
+        for x in ('MAJOR_VERSION', 'MINOR_VERSION', 'REVISION'))
+    return enums, version
+
+
+def to_const_name(kind):
+    return re.sub(r'(?<=[a-z0-9])(?=[A-Z])', '_', kind).upper()
+
+
+QUANTIFIERS = {None: 'One', '?': 'Optional', '*': 'Variadic'}
+
+
+def fmt_operands(operands):
+    items = ('({}, {})'.format(x['kind'], QUANTIFIERS[x.get('quantifier')]) for x in operands)
+    return '&[' + ', '.join(items) + ']'
+
+
+def main(grammar_path, headers_path):
+    with open(grammar_path) as f:
+        grammar = json.load(f)
+    headers, version = parse_spirv_headers(headers_path)
+    opcodes = headers['Op']
+
+    instrs = []
+    seen_opcodes = set()
+    for instr in grammar['instructions']:
+        opcode = instr['opcode']
+        # Aliases share opcodes with their canonical instructions.
+        if opcode not in opcodes or opcode in seen_opcodes:
+            continue
+        seen_opcodes.add(opcode)
+        instrs.append((opcode, instr.get('operands', [])))
+    instrs.sort(key=lambda x: x[0])
+
+    # Enumerations spirv_headers doesn't define are only kept if an emitted
+    # instruction refers to them.
+    used_kinds = {x['kind'] for _, operands in instrs for x in operands}
+    kinds = []
+    enums = []
+    for kind in grammar['operand_kinds']:
+        name = kind['kind']
+        category = kind['category']
+        is_enum = category in ('BitEnum', 'ValueEnum')
+        if is_enum and name not in headers and name not in used_kinds:
+            continue
+        kinds.append(name)
+        if not is_enum:
+            continue
+        enumerants = []
+        for e in kind['enumerants']:
+            value = e['value']
+            value = int(value, 16) if isinstance(value, str) else value
+            if name in headers and value not in headers[name]:
+                continue
+            enumerants.append((e['enumerant'], value, e.get('parameters', [])))
+        enums.append((name, enumerants, category))
+
+    out = []
+    w = out.append
+    w('//! SPIR-V instruction grammar used to encode and decode instructions in their')
+    w('//! textual form.')
+    w('//!')
+    w('//! GENERATED by `tools/gen_grammar.py` from `spirv.core.grammar.json` of')
+    w('//! SPIR-V {}.{} revision {}, restricted to the instructions and enumerants'.format(
+        grammar['major_version'], grammar['minor_version'], grammar['revision']))
+    w('//! defined by `spirv_headers` (SPIR-V {}.{} revision {}). DO NOT EDIT.'.format(
+        *version))
+    w('use num_traits::FromPrimitive;')
+    w('use spirv_headers::Op;')
+    w('')
+    w('// Some kinds, like `Scope`, are only used as values of id operands.')
+    w('#[allow(dead_code)]')
+    w('#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]')
+    w('pub(crate) enum OperandKind {')
+    for kind in kinds:
+        w('    {},'.format(kind))
+    w('}')
+    w('')
+    w('#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]')
+    w('pub(crate) enum Quantifier {')
+    w('    One,')
+    w('    Optional,')
+    w('    Variadic,')
+    w('}')
+    w('')
+    w('/// Kind and quantifier of an operand.')
+    w('pub(crate) type OperandGrammar = (OperandKind, Quantifier);')
+    w('')
+    w('pub(crate) struct InstrGrammar {')
+    w('    pub opcode: u32,')
+    w('    pub operands: &\'static [OperandGrammar],')
+    w('}')
+    w('impl InstrGrammar {')
+    w('    /// Name of the instruction from `spirv_headers`, e.g. `OpTypeInt`.')
+    w('    pub fn name(&self) -> String { op_name(self.opcode) }')
+    w('}')
+    w('pub(crate) struct Enumerant {')
+    w('    pub name: &\'static str,')
+    w('    pub value: u32,')
+    w('    pub params: &\'static [OperandGrammar],')
+    w('}')
+    w('')
+    w('use OperandKind::*;')
+    w('use Quantifier::*;')
+    w('')
+    w('const INSTRS: &[InstrGrammar] = &[')
+    for opcode, operands in instrs:
+        w('    InstrGrammar {{ opcode: {}, operands: {} }},'.format(opcode, fmt_operands(operands)))
+    w('];')
+    w('')
+    for name, enumerants, category in enums:
+        w('const {}: &[Enumerant] = &['.format(to_const_name(name)))
+        for ename, value, params in enumerants:
+            value = '0x{:04X}'.format(value) if category == 'BitEnum' else str(value)
+            w('    Enumerant {{ name: "{}", value: {}, params: {} }},'.format(ename, value, fmt_operands(params)))
+        w('];')
+    w('')
+    w('/// Name of an opcode as in `spirv_headers::Op` with the `Op` prefix, e.g.')
+    w('/// `OpTypeInt`. Opcodes unknown to `spirv_headers` are named by value, like')
+    w('/// `Op12345`.')
+    w('pub(crate) fn op_name(opcode: u32) -> String {')
+    w('    match Op::from_u32(opcode) {')
+    w('        Some(op) => format!("Op{:?}", op),')
+    w('        None => format!("Op{}", opcode),')
+    w('    }')
+    w('}')
+    w('/// Get the grammar of an instruction by its opcode.')
+    w('pub(crate) fn instr_by_opcode(opcode: u32) -> Option<&\'static InstrGrammar> {')
+    w('    let i = INSTRS.binary_search_by_key(&opcode, |x| x.opcode).ok()?;')
+    w('    Some(&INSTRS[i])')
+    w('}')
+    w('/// Get the grammar of an instruction by its name, e.g. `OpTypeInt`.')
+    w('pub(crate) fn instr_by_name(name: &str) -> Option<&\'static InstrGrammar> {')
+    w('    INSTRS.iter().find(|x| x.name() == name)')
+    w('}')
+    w('/// Get all enumerants of an enumeration operand kind. `None` is returned if')
+    w('/// the kind is not an enumeration.')
+    w('pub(crate) fn enumerants(kind: OperandKind) -> Option<&\'static [Enumerant]> {')
+    w('    let rv = match kind {')
+    for name, _, _ in enums:
+        w('        {} => {},'.format(name, to_const_name(name)))
+    w('        _ => return None,')
+    w('    };')
+    w('    Some(rv)')
+    w('}')
+    w('/// Whether the operand kind is a bit mask whose enumerants can be combined.')
+    w('pub(crate) fn is_bit_enum(kind: OperandKind) -> bool {')
+    w('    matches!(kind, {})'.format(' | '.join(x[0] for x in enums if x[2] == 'BitEnum')))
+    w('}')
+    print('\n'.join(out))
+
+
+if __name__ == '__main__':
+    if len(sys.argv) != 3:
+        sys.exit(__doc__)
+    main(sys.argv[1], sys.argv[2])